@@ -0,0 +1,498 @@
+//! Scriptable command line interface for the optimizer, without the GUI
+
+#[cfg(target_os = "windows")]
+use clap::{Parser, Subcommand, ValueEnum};
+#[cfg(target_os = "windows")]
+use serde::Serialize;
+#[cfg(target_os = "windows")]
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+#[cfg(target_os = "windows")]
+use tencent_ace_tools_lib::config::{self, Settings};
+#[cfg(target_os = "windows")]
+use tencent_ace_tools_lib::windows::{
+    ace_tools::{AceProcessController, ProcessInfo},
+    affinity::AffinityStrategy,
+    priority::TargetPriority,
+    saved_state, scheduled_task,
+    screenshot::{ScreenshotCapture, WindowInfo},
+};
+
+#[cfg(target_os = "windows")]
+#[derive(Parser)]
+#[command(
+    name = "ace-tools",
+    version,
+    about = "Throttle the ACE anti-cheat processes"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log level written to stderr, e.g. `info` or `debug`
+    #[arg(long, global = true, default_value = "warn")]
+    log_level: String,
+    /// Format of the results written to stdout
+    #[arg(long, global = true, value_enum, default_value_t = Output::Text)]
+    output: Output,
+}
+
+#[cfg(target_os = "windows")]
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Output {
+    Text,
+    /// The same data as the GUI receives, for scripts and task schedulers
+    Json,
+}
+
+#[cfg(target_os = "windows")]
+#[derive(Subcommand)]
+enum Command {
+    /// Lower the priority and restrict the affinity of all ACE processes
+    Optimize {
+        /// Defaults to the priority in config.toml
+        #[arg(long, value_enum)]
+        priority: Option<Priority>,
+        /// `last`, `last-two`, `e-cores`, `smallest-cache` or a hex affinity mask like `0xC0`,
+        /// defaults to the strategy in config.toml
+        #[arg(long, value_parser = parse_cores)]
+        cores: Option<AffinityStrategy>,
+        /// Do not ask for confirmation
+        #[arg(long, short)]
+        yes: bool,
+        /// Keep running and optimize ACE processes as they appear or reset their settings
+        #[arg(long)]
+        watch: bool,
+        /// Seconds between two scans in watch mode, defaults to the interval in config.toml
+        #[arg(long, requires = "watch")]
+        interval: Option<u64>,
+        /// Revert all modified processes when watch mode is stopped with Ctrl+C
+        #[arg(long, requires = "watch")]
+        revert_on_exit: bool,
+    },
+    /// Put all modified ACE processes back to their original settings
+    Revert {
+        /// Do not ask for confirmation
+        #[arg(long, short)]
+        yes: bool,
+    },
+    /// Run `optimize --watch --yes` elevated at every logon through the Task Scheduler
+    InstallTask,
+    /// Remove the logon task again
+    UninstallTask,
+    /// List the visible windows that can be captured
+    Windows {
+        /// Shorthand for `--output json`
+        #[arg(long)]
+        json: bool,
+    },
+    /// Save a screenshot of a window, or of the whole display without a selector
+    Capture {
+        /// Exact window title, e.g. `Delta Force`
+        #[arg(long, conflicts_with_all = ["pattern", "id"])]
+        window: Option<String>,
+        /// Regular expression matched against the window titles
+        #[arg(long, conflicts_with = "id")]
+        pattern: Option<String>,
+        /// Window id as listed by the `windows` command
+        #[arg(long)]
+        id: Option<u32>,
+        /// File the screenshot is written to, PNG unless the extension is `.jpg` or `.webp`
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Show the ACE processes and their current settings
+    Status {
+        /// Shorthand for `--output json`
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[cfg(target_os = "windows")]
+#[derive(Clone, Copy, ValueEnum)]
+enum Priority {
+    Idle,
+    BelowNormal,
+    Normal,
+}
+
+#[cfg(target_os = "windows")]
+impl From<Priority> for TargetPriority {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::Idle => TargetPriority::Idle,
+            Priority::BelowNormal => TargetPriority::BelowNormal,
+            Priority::Normal => TargetPriority::Normal,
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn parse_cores(value: &str) -> Result<AffinityStrategy, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "last" => Ok(AffinityStrategy::LastCore),
+        "last-two" => Ok(AffinityStrategy::LastTwoCores),
+        "e-cores" => Ok(AffinityStrategy::ECoresOnly),
+        "smallest-cache" => Ok(AffinityStrategy::SmallestCacheCcd),
+        mask => {
+            let digits = mask.trim_start_matches("0x");
+            u64::from_str_radix(digits, 16)
+                .ok()
+                .filter(|mask| *mask != 0)
+                .map(AffinityStrategy::CustomMask)
+                .ok_or_else(|| format!("Invalid cores value: {}", value))
+        }
+    }
+}
+
+/// Outcome of a command as printed with `--output json`
+#[cfg(target_os = "windows")]
+#[derive(Serialize)]
+struct CommandResult {
+    success: bool,
+    message: String,
+    processes: Vec<ProcessInfo>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    windows: Vec<WindowInfo>,
+}
+
+/// Set to a non-empty value other than `0` to answer every confirmation with yes
+#[cfg(target_os = "windows")]
+const ASSUME_YES_ENV: &str = "ACE_TOOLS_YES";
+
+#[cfg(target_os = "windows")]
+fn confirm(question: &str) -> bool {
+    use std::io::{IsTerminal, Write};
+
+    if std::env::var(ASSUME_YES_ENV).is_ok_and(|value| !value.is_empty() && value != "0") {
+        return true;
+    }
+
+    // nobody can answer under the Task Scheduler or with redirected input
+    if !std::io::stdin().is_terminal() {
+        tracing::info!(
+            "stdin is not a terminal, skipping confirmation: {}",
+            question
+        );
+        return true;
+    }
+
+    // stdout is reserved for the results
+    eprint!("{} [y/N] ", question);
+    let _ = std::io::stderr().flush();
+
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+#[cfg(target_os = "windows")]
+fn print_processes(processes: &[ProcessInfo]) {
+    println!(
+        "{:>8}  {:<24} {:<14} {:<20} {}",
+        "PID", "Name", "Priority", "Affinity", "Optimized"
+    );
+    for p in processes {
+        println!(
+            "{:>8}  {:<24} {:<14} {:<20} {}",
+            p.process_id, p.process_name, p.current_priority, p.current_affinity, p.is_optimized
+        );
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn new_controller(settings: &Settings) -> AceProcessController {
+    let mut controller = AceProcessController::new();
+    controller.set_target_priority(settings.priority);
+    controller.set_affinity_strategy(settings.affinity_strategy);
+    controller.set_throttle_mode(settings.throttle_mode);
+    if let Err(e) = controller.set_target_patterns(settings.target_patterns.clone()) {
+        tracing::warn!("{}", e);
+    }
+    // shared with the GUI, so either one can revert what the other changed
+    if let Some(path) = saved_state::default_path() {
+        controller.set_state_file(path);
+    }
+    controller
+}
+
+#[cfg(target_os = "windows")]
+fn report(output: Output, result: CommandResult) {
+    match output {
+        // one object per line so the stream can be consumed while running
+        Output::Json => match serde_json::to_string(&result) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Failed to serialize result: {}", e),
+        },
+        Output::Text => println!("{}", result.message),
+    }
+}
+
+/// Rescan until Ctrl+C, optimizing new processes and the ones that undid the optimization
+#[cfg(target_os = "windows")]
+fn watch(
+    controller: &mut AceProcessController,
+    interval: Duration,
+    revert_on_exit: bool,
+    output: Output,
+) -> Result<String, String> {
+    let running = Arc::new(AtomicBool::new(true));
+    let handler_flag = running.clone();
+    ctrlc::set_handler(move || handler_flag.store(false, Ordering::SeqCst))
+        .map_err(|e| format!("Failed to install Ctrl+C handler: {}", e))?;
+
+    let mut known: HashSet<u32> = HashSet::new();
+    // a process that refused once would otherwise be retried on every scan
+    let mut failed: HashSet<u32> = HashSet::new();
+
+    while running.load(Ordering::SeqCst) {
+        let _ = controller.scan_ace_guard_processes();
+        let processes = controller.get_processes().to_vec();
+        let current: HashSet<u32> = processes.iter().map(|p| p.process_id).collect();
+
+        for process_id in known.difference(&current) {
+            tracing::info!("ACE process {} exited", process_id);
+            failed.remove(process_id);
+        }
+
+        for process in processes
+            .iter()
+            .filter(|p| !p.is_optimized && !failed.contains(&p.process_id))
+        {
+            if known.contains(&process.process_id) {
+                tracing::info!(
+                    "{} (PID: {}) reset its settings, reapplying",
+                    process.process_name,
+                    process.process_id
+                );
+            } else {
+                tracing::info!(
+                    "{} (PID: {}) appeared",
+                    process.process_name,
+                    process.process_id
+                );
+            }
+
+            let result = controller.optimize_single_process(process.process_id);
+            let (success, message) = match result {
+                Ok(message) => (true, message),
+                Err(e) => {
+                    failed.insert(process.process_id);
+                    (false, e)
+                }
+            };
+
+            report(
+                output,
+                CommandResult {
+                    success,
+                    message,
+                    processes: vec![process.clone()],
+                    windows: Vec::new(),
+                },
+            );
+        }
+
+        known = current;
+
+        let deadline = Instant::now() + interval;
+        while running.load(Ordering::SeqCst) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    if revert_on_exit {
+        return controller.restore_all_processes();
+    }
+
+    Ok("Stopped watching".to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn run(command: Command, output: Output) -> Result<CommandResult, String> {
+    let settings = config::load_or_default();
+    let mut controller = new_controller(&settings);
+
+    let mut windows = Vec::new();
+
+    let message = match command {
+        Command::Optimize {
+            priority,
+            cores,
+            yes,
+            watch: true,
+            interval,
+            revert_on_exit,
+        } => {
+            if let Some(priority) = priority {
+                controller.set_target_priority(priority.into());
+            }
+            if let Some(cores) = cores {
+                controller.set_affinity_strategy(cores);
+            }
+
+            if !yes && !confirm("Optimize ACE processes until stopped with Ctrl+C?") {
+                return Err("Cancelled".to_string());
+            }
+
+            watch(
+                &mut controller,
+                Duration::from_secs(interval.unwrap_or(settings.watch_interval_secs).max(1)),
+                revert_on_exit,
+                output,
+            )?
+        }
+        Command::Optimize {
+            priority,
+            cores,
+            yes,
+            ..
+        } => {
+            if let Some(priority) = priority {
+                controller.set_target_priority(priority.into());
+            }
+            if let Some(cores) = cores {
+                controller.set_affinity_strategy(cores);
+            }
+
+            let processes = controller.scan_ace_guard_processes()?;
+            if !yes && !confirm(&format!("Optimize {} ACE processes?", processes.len())) {
+                return Err("Cancelled".to_string());
+            }
+
+            let show_progress = output == Output::Text;
+            tauri::async_runtime::block_on(controller.optimize_all_processes_with_progress(
+                move |progress| {
+                    if !show_progress {
+                        return;
+                    }
+                    let outcome = if progress.success {
+                        "optimized"
+                    } else {
+                        "failed"
+                    };
+                    println!(
+                        "[{}/{}] {} (PID: {}) {}",
+                        progress.completed,
+                        progress.total,
+                        progress.process_name,
+                        progress.process_id,
+                        outcome
+                    );
+                },
+            ))?
+        }
+        Command::Revert { yes } => {
+            // no processes left is fine, saved states are matched during the scan
+            let _ = controller.scan_ace_guard_processes();
+            if !yes && !confirm("Revert all modified ACE processes?") {
+                return Err("Cancelled".to_string());
+            }
+
+            controller.restore_all_processes()?
+        }
+        Command::InstallTask => {
+            scheduled_task::install_logon_task()?;
+            format!(
+                "Installed scheduled task {}",
+                scheduled_task::LOGON_TASK_NAME
+            )
+        }
+        Command::UninstallTask => {
+            scheduled_task::uninstall_logon_task()?;
+            format!("Removed scheduled task {}", scheduled_task::LOGON_TASK_NAME)
+        }
+        Command::Windows { .. } => {
+            windows = ScreenshotCapture::get_all_windows()?;
+            if output == Output::Text {
+                for window in &windows {
+                    println!("{:>10}  {}", window.process_id, window.title);
+                }
+            }
+            format!("Found {} windows", windows.len())
+        }
+        Command::Capture {
+            window,
+            pattern,
+            id,
+            out,
+        } => {
+            let screenshot = match (window, pattern, id) {
+                (Some(name), _, _) => ScreenshotCapture::capture_by_window_name(&name)?,
+                (_, Some(pattern), _) => ScreenshotCapture::capture_by_window_pattern(&pattern)?,
+                (_, _, Some(id)) => ScreenshotCapture::capture_by_window_id(id)?,
+                _ => ScreenshotCapture::capture_display()?,
+            };
+
+            screenshot.save(&out)?;
+            format!(
+                "Saved {}x{} screenshot to {}",
+                screenshot.width,
+                screenshot.height,
+                out.display()
+            )
+        }
+        Command::Status { .. } => {
+            let processes = controller.scan_ace_guard_processes().unwrap_or_default();
+            if output == Output::Text && !processes.is_empty() {
+                print_processes(&processes);
+            }
+            format!("Found {} ACE Guard processes", processes.len())
+        }
+    };
+
+    Ok(CommandResult {
+        success: true,
+        message,
+        processes: controller.get_processes().to_vec(),
+        windows,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn main() {
+    let cli = Cli::parse();
+
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::new(&cli.log_level))
+        .with_writer(std::io::stderr)
+        .init();
+
+    let output = match &cli.command {
+        Command::Status { json: true } | Command::Windows { json: true } => Output::Json,
+        _ => cli.output,
+    };
+
+    match run(cli.command, output) {
+        Ok(result) => report(output, result),
+        Err(e) if output == Output::Json => {
+            report(
+                output,
+                CommandResult {
+                    success: false,
+                    message: e,
+                    processes: Vec::new(),
+                    windows: Vec::new(),
+                },
+            );
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn main() {
+    eprintln!("ace-cli only runs on Windows");
+    std::process::exit(1);
+}
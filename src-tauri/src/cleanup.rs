@@ -0,0 +1,117 @@
+use crate::{
+    history::{HistoryState, HISTORY_DATABASE_FILE},
+    windows::{
+        elevation::HANDOVER_FILE_PREFIX, game_boost::GameBoostState, AceProcessControllerState,
+    },
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct CleanupReport {
+    /// ACE processes put back to normal priority and full affinity
+    pub restored_processes: u32,
+    pub removed_paths: Vec<String>,
+    /// Steps that failed, cleanup continues past them
+    pub errors: Vec<String>,
+}
+
+impl CleanupReport {
+    fn remove_file(&mut self, path: &Path) {
+        if !path.exists() {
+            return;
+        }
+
+        match std::fs::remove_file(path) {
+            Ok(()) => self.removed_paths.push(path.display().to_string()),
+            Err(e) => self
+                .errors
+                .push(format!("Failed to remove {}: {}", path.display(), e)),
+        }
+    }
+
+    fn remove_dir(&mut self, path: &Path) {
+        if !path.exists() {
+            return;
+        }
+
+        match std::fs::remove_dir_all(path) {
+            Ok(()) => self.removed_paths.push(path.display().to_string()),
+            Err(e) => self
+                .errors
+                .push(format!("Failed to remove {}: {}", path.display(), e)),
+        }
+    }
+}
+
+/// Revert everything the tool changed: live process settings, saved state and leftovers
+pub fn cleanup(app: &AppHandle) -> CleanupReport {
+    let mut report = CleanupReport::default();
+
+    match app.state::<AceProcessControllerState>().0.lock() {
+        Ok(mut controller) => {
+            // pick up processes optimized by an earlier instance as well
+            let _ = controller.scan_ace_guard_processes();
+            match controller.reset_all_processes() {
+                Ok(count) => report.restored_processes = count as u32,
+                Err(e) => report.errors.push(e),
+            }
+        }
+        Err(e) => report
+            .errors
+            .push(format!("Failed to acquire controller lock: {}", e)),
+    }
+
+    if let Ok(mut boost) = app.state::<GameBoostState>().0.lock() {
+        if let Err(e) = boost.revert() {
+            report.errors.push(e);
+        }
+    }
+
+    // close the database before deleting it
+    if let Ok(mut history) = app.state::<HistoryState>().0.lock() {
+        history.take();
+    }
+
+    match app.path().app_data_dir() {
+        Ok(dir) => {
+            report.remove_file(&dir.join(HISTORY_DATABASE_FILE));
+            report.remove_dir(&dir);
+        }
+        Err(e) => report
+            .errors
+            .push(format!("Failed to resolve app data directory: {}", e)),
+    }
+
+    if let Ok(dir) = app.path().app_config_dir() {
+        report.remove_dir(&dir);
+    }
+
+    if let Ok(dir) = app.path().app_local_data_dir() {
+        report.remove_dir(&dir);
+    }
+
+    // handover files are left behind when the elevated instance never started
+    if let Ok(entries) = std::fs::read_dir(std::env::temp_dir()) {
+        for entry in entries.flatten() {
+            if entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(HANDOVER_FILE_PREFIX)
+            {
+                report.remove_file(&entry.path());
+            }
+        }
+    }
+
+    tracing::info!(
+        "Cleanup finished: {} processes restored, {} paths removed, {} errors",
+        report.restored_processes,
+        report.removed_paths.len(),
+        report.errors.len()
+    );
+
+    report
+}
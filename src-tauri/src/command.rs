@@ -1,10 +1,53 @@
+use crate::cleanup::CleanupReport;
+use crate::config::{self, Settings, Theme};
+use crate::history::{self, HistoryEntry, HistoryKind, HistoryQuery, HistoryState};
+use crate::hotkeys::{HotkeyBinding, HotkeyState};
+use crate::hud::{HudPosition, HudState, HudStatus};
+use crate::logging::{LogEvent, LogLevel, LogQuery};
+use crate::notifications::NotificationState;
+use crate::region_picker::RegionCapture;
+use crate::report::{ProcessReport, ReportFormat};
+use crate::session_report::{SessionReport, SessionReportFormat};
+use crate::status_summary::StatusSummary;
+use crate::updates::{AppInfo, UpdateStatus};
 use crate::windows::{
-    ace_tools::ProcessInfo,
-    screenshot::{ScreenShot, ScreenshotCapture, WindowInfo},
+    ace_tools::{AceInstanceSettings, ProcessInfo, ProcessRestoreResult},
+    affinity::AffinityStrategy,
+    anti_cheat::{AntiCheatFamily, DetectedAntiCheat},
+    background_mode::ThrottleMode,
+    background_pause::{self, BackgroundPauseState, BackgroundPauseStatus},
+    auto_optimize::{AutoOptimizeConfig, AutoOptimizeState},
+    screenshot::{
+        CaptureOptions, ScreenShot, ScreenshotCapture, ScreenshotSource, WindowInfo,
+        WindowThumbnail,
+    },
+    cpu_alert::{CpuAlertConfig, CpuAlertEvent, CpuAlertState},
+    game_boost::{BoostedGame, GameBoostConfig, GameBoostState},
+    game_mode::GameModeStatus,
+    installation::AceInstallationInfo,
+    monitor::{ProcessUsageHistory, UsageMonitorState},
     ocr::{OcrResponse, OcrRegion},
+    power::PowerDiagnostics,
+    priority::TargetPriority,
+    system_info::SystemInfo,
+    process_list::{benchmark_scan, ScanBenchmark},
+    process_matcher::ProcessNamePattern,
+    process_tree::AceProcessNode,
+    saved_state::SavedProcessState,
+    scheduler::{ScheduleRule, SchedulerState},
+    services::AceServiceStatus,
+    status_events::{ProcessStatusConfig, ProcessStatusState},
+    timer_resolution::{TimerResolutionState, TimerResolutionStatus},
+    topology::CpuTopology,
+    usage::{ProcessUsage, ProcessUsageSamplerState},
+    watchdog::{WatchdogConfig, WatchdogState},
     AceProcessControllerState,
 };
-use tauri::State;
+use chrono::{DateTime, Local, Utc};
+use std::collections::HashMap;
+use tauri::{ipc::Response, AppHandle, Manager, State};
+use tauri_plugin_dialog::DialogExt;
+use tauri_specta::Event;
 
 #[tauri::command]
 #[specta::specta]
@@ -33,6 +76,33 @@ pub fn is_running_as_admin() -> Result<bool, String> {
     }
 }
 
+/// Relaunch the app elevated through the UAC prompt, handing over the current settings,
+/// returns false when the user declined the prompt
+#[tauri::command]
+#[specta::specta]
+pub fn restart_as_admin(
+    app: AppHandle,
+    state: State<'_, AceProcessControllerState>,
+) -> Result<bool, String> {
+    use crate::windows::elevation::{relaunch_elevated, ElevationHandover, ElevationOutcome};
+
+    let handover = {
+        let controller = state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+        ElevationHandover::from_controller(&controller)
+    };
+
+    match relaunch_elevated(Some(&handover))? {
+        ElevationOutcome::Relaunched => {
+            app.exit(0);
+            Ok(true)
+        }
+        ElevationOutcome::Cancelled => Ok(false),
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_all_ace_guard_processes(
@@ -44,36 +114,131 @@ pub fn get_all_ace_guard_processes(
         .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
 
     let result = guard.scan_ace_guard_processes();
-    
+
+    match &result {
+        Ok(processes) => history::record(
+            HistoryKind::Scan,
+            true,
+            &format!("Found {} ACE Guard processes", processes.len()),
+            serde_json::to_string(processes).ok().as_deref(),
+        ),
+        Err(e) => history::record(HistoryKind::Scan, false, e, None),
+    }
+
     result
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn get_ace_process_tree(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<Vec<AceProcessNode>, String> {
+    let mut guard = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    guard.scan_ace_guard_processes()?;
+
+    Ok(guard.get_process_tree())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn detect_anti_cheats(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<Vec<DetectedAntiCheat>, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.detect_anti_cheats()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_optimized_anti_cheats(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<Vec<AntiCheatFamily>, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_optimized_anti_cheats())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_anti_cheat_optimized(
+    state: State<'_, AceProcessControllerState>,
+    family: AntiCheatFamily,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_anti_cheat_optimized(family, enabled);
+
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn optimize_all_ace_guard_processes(
+    app: AppHandle,
     state: State<'_, AceProcessControllerState>,
+    game_boost: State<'_, GameBoostState>,
 ) -> Result<String, String> {
-    // Clone the controller to avoid holding the lock across await
-    let mut controller = {
-        let guard = state
+    let batch = {
+        let mut controller = state
             .0
             .lock()
             .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
-        (*guard).clone()
+        controller
+            .scan_ace_guard_processes()
+            .and_then(|_| controller.prepare_optimization())
+    };
+
+    // the lock is released while the workers run, only their processes are merged back
+    // so changes made meanwhile survive. Every process is reported as it is done
+    let result = match batch {
+        Ok(batch) => batch
+            .run(move |progress| {
+                let _ = progress.emit(&app);
+            })
+            .await
+            .and_then(|batch| {
+                state
+                    .0
+                    .lock()
+                    .map_err(|e| format!("Failed to acquire controller lock: {}", e))?
+                    .finish_optimization(batch)
+            }),
+        Err(e) => Err(e),
     };
 
-    let result = controller.optimize_ace_guard_processes().await;
-    
-    // Update the global state with the modified controller
-    {
-        let mut guard = state
-            .0
-            .lock()
-            .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
-        *guard = controller;
-    }
-    
     tracing::debug!("Optimization result: {:?}", result);
+
+    // tune the game side in the same click when enabled
+    if result.is_ok() {
+        if let Ok(mut boost) = game_boost.0.lock() {
+            if boost.config().enabled {
+                if let Err(e) = boost.boost_games() {
+                    tracing::warn!("Game boost skipped: {}", e);
+                }
+            }
+        }
+    }
+
+    match &result {
+        Ok(message) => history::record(HistoryKind::Optimization, true, message, None),
+        Err(e) => history::record(HistoryKind::Optimization, false, e, None),
+    }
+
     result
 }
 
@@ -96,12 +261,128 @@ pub fn get_all_windows() -> Result<Vec<WindowInfo>, String> {
     ScreenshotCapture::get_all_windows()
 }
 
+/// Windows with a JPEG preview for the window picker, capturing them takes a moment
+#[tauri::command]
+#[specta::specta]
+pub async fn get_all_windows_with_thumbnails(
+    max_width: u32,
+) -> Result<Vec<WindowThumbnail>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        ScreenshotCapture::get_all_windows_with_thumbnails(max_width)
+    })
+    .await
+    .map_err(|e| format!("Window thumbnail task failed: {}", e))?
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn try_capture_image_by_window_id(window_id: u32) -> Result<ScreenShot, String> {
     ScreenshotCapture::capture_by_window_id(window_id)
 }
 
+/// Capture the window whose title is exactly `window_name`
+#[tauri::command]
+#[specta::specta]
+pub fn capture_by_window_name(window_name: String) -> Result<ScreenShot, String> {
+    ScreenshotCapture::capture_by_window_name(&window_name)
+}
+
+/// Capture the first window whose title matches the regular expression, e.g. `Delta.*Force`
+#[tauri::command]
+#[specta::specta]
+pub fn capture_by_window_pattern(pattern: String) -> Result<ScreenShot, String> {
+    ScreenshotCapture::capture_by_window_pattern(&pattern)
+}
+
+/// Capture a window with BitBlt, client area only or cropped, e.g. when the default
+/// capture of a DirectX window comes out black
+#[tauri::command]
+#[specta::specta]
+pub fn capture_window_advanced(
+    window_id: u32,
+    options: CaptureOptions,
+) -> Result<ScreenShot, String> {
+    ScreenshotCapture::capture_window_with_options(window_id, options)
+}
+
+/// Capture the whole desktop
+#[tauri::command]
+#[specta::specta]
+pub fn capture_full_screen() -> Result<ScreenShot, String> {
+    ScreenshotCapture::capture_display()
+}
+
+/// Capture and write the screenshot straight to disk, the extension of the path picks PNG,
+/// JPEG or WebP. Without a path a save dialog asks for one, `None` when it was cancelled
+#[tauri::command]
+#[specta::specta]
+pub async fn save_screenshot_to_file(
+    app: AppHandle,
+    source: ScreenshotSource,
+    path: Option<String>,
+) -> Result<Option<String>, String> {
+    let path = match path {
+        Some(path) => std::path::PathBuf::from(path),
+        None => {
+            let file_name = format!("screenshot-{}.png", Local::now().format("%Y%m%d-%H%M%S"));
+            let picked = app
+                .dialog()
+                .file()
+                .set_file_name(file_name)
+                .add_filter("PNG", &["png"])
+                .add_filter("JPEG", &["jpg", "jpeg"])
+                .add_filter("WebP", &["webp"])
+                .blocking_save_file();
+            let Some(picked) = picked else {
+                return Ok(None);
+            };
+            picked
+                .into_path()
+                .map_err(|e| format!("Failed to resolve the picked path: {}", e))?
+        }
+    };
+
+    ScreenshotCapture::capture_source(source)?.save(&path)?;
+
+    tracing::info!("Saved {:?} screenshot to {}", source, path.display());
+    Ok(Some(path.to_string_lossy().into_owned()))
+}
+
+/// Commands answering with the raw PNG bytes, which the frontend receives as an `ArrayBuffer`.
+/// The capture commands above stay for compatibility, but their `ScreenShot` goes through
+/// JSON with every byte as a number. `tauri::ipc::Response` has no TypeScript type, so these
+/// are left out of the bindings and dispatched by name next to the typed commands
+pub const BINARY_COMMANDS: &[&str] = &[
+    "capture_window_png",
+    "capture_window_png_by_name",
+    "capture_window_png_by_pattern",
+    "capture_full_screen_png",
+];
+
+fn png_response(screenshot: ScreenShot) -> Response {
+    Response::new(screenshot.image_data)
+}
+
+#[tauri::command]
+pub fn capture_window_png(window_id: u32) -> Result<Response, String> {
+    ScreenshotCapture::capture_by_window_id(window_id).map(png_response)
+}
+
+#[tauri::command]
+pub fn capture_window_png_by_name(window_name: String) -> Result<Response, String> {
+    ScreenshotCapture::capture_by_window_name(&window_name).map(png_response)
+}
+
+#[tauri::command]
+pub fn capture_window_png_by_pattern(pattern: String) -> Result<Response, String> {
+    ScreenshotCapture::capture_by_window_pattern(&pattern).map(png_response)
+}
+
+#[tauri::command]
+pub fn capture_full_screen_png() -> Result<Response, String> {
+    ScreenshotCapture::capture_display().map(png_response)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn ocr_screen_region(region: OcrRegion) -> Result<OcrResponse, String> {
@@ -114,8 +395,1371 @@ pub fn ocr_image_region(image_data: Vec<u8>, region: OcrRegion) -> Result<OcrRes
     crate::windows::ocr::ocr_image_region(&image_data, region)
 }
 
+/// Capture a window and run OCR on it in one call, without sending the image to the frontend
+#[tauri::command]
+#[specta::specta]
+pub fn ocr_window(
+    window_id: u32,
+    region: Option<OcrRegion>,
+    options: Option<CaptureOptions>,
+) -> Result<OcrResponse, String> {
+    crate::windows::ocr::ocr_window(window_id, region, options.unwrap_or_default())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_screenshot_window() -> Result<Option<String>, String> {
+    Ok(config::load()?.screenshot_window)
+}
+
+/// Remember the window picked for screenshots, `None` captures the whole display
+#[tauri::command]
+#[specta::specta]
+pub fn set_screenshot_window(title: Option<String>) -> Result<(), String> {
+    tracing::debug!("Screenshot window set to {:?}", title);
+    config::update(|settings| {
+        settings.screenshot_window = title;
+        Ok(())
+    })
+}
+
+/// Capture the window picked for screenshots
+#[tauri::command]
+#[specta::specta]
+pub fn capture_screenshot_window() -> Result<ScreenShot, String> {
+    match config::load()?.screenshot_window {
+        Some(title) => ScreenshotCapture::capture_by_window_name(&title),
+        None => ScreenshotCapture::capture_display(),
+    }
+}
+
+/// Open a full-screen overlay to drag a region, then capture it and optionally run OCR on it
+#[tauri::command]
+#[specta::specta]
+pub async fn pick_screen_region(
+    app: AppHandle,
+    ocr: bool,
+) -> Result<Option<RegionCapture>, String> {
+    crate::region_picker::pick_region(&app, ocr).await
+}
+
+/// Called by the overlay with the dragged region, `None` cancels the selection
+#[tauri::command]
+#[specta::specta]
+pub fn submit_screen_region(app: AppHandle, region: Option<OcrRegion>) -> Result<(), String> {
+    crate::region_picker::submit_region(&app, region)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn ocr_full_screen() -> Result<OcrResponse, String> {
     crate::windows::ocr::ocr_full_screen()
 }
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_hotkey_bindings(state: State<'_, HotkeyState>) -> Result<Vec<HotkeyBinding>, String> {
+    let bindings = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire hotkey lock: {}", e))?;
+
+    Ok(bindings.clone())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_hotkey_bindings(app: AppHandle, bindings: Vec<HotkeyBinding>) -> Result<(), String> {
+    crate::hotkeys::replace_bindings(&app, &bindings)?;
+
+    tracing::debug!("Hotkey bindings updated");
+
+    config::update(|settings| {
+        settings.hotkeys = bindings;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn show_status_hud(
+    app: AppHandle,
+    position: HudPosition,
+    monitor: Option<u32>,
+) -> Result<(), String> {
+    crate::hud::show_hud(&app, position, monitor)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn hide_status_hud(app: AppHandle) -> Result<(), String> {
+    crate::hud::hide_hud(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_hud_status(
+    controller_state: State<'_, AceProcessControllerState>,
+    hud_state: State<'_, HudState>,
+) -> Result<HudStatus, String> {
+    crate::hud::get_hud_status(&controller_state, &hud_state)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_status_summary(
+    controller_state: State<'_, AceProcessControllerState>,
+    watchdog_state: State<'_, WatchdogState>,
+    history_state: State<'_, HistoryState>,
+    pause_state: State<'_, BackgroundPauseState>,
+) -> Result<StatusSummary, String> {
+    crate::status_summary::get_status_summary(
+        &controller_state,
+        &watchdog_state,
+        &history_state,
+        &pause_state,
+    )
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_timer_resolution_status(
+    state: State<'_, TimerResolutionState>,
+) -> Result<TimerResolutionStatus, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire timer resolution lock: {}", e))?;
+
+    Ok(controller.status())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_timer_resolution_enabled(
+    state: State<'_, TimerResolutionState>,
+    enabled: bool,
+) -> Result<TimerResolutionStatus, String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire timer resolution lock: {}", e))?;
+
+    controller.set_enabled(enabled);
+    tracing::debug!("Timer resolution management enabled: {}", enabled);
+
+    Ok(controller.status())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_cpu_alert_config(state: State<'_, CpuAlertState>) -> Result<CpuAlertConfig, String> {
+    let monitor = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire cpu alert lock: {}", e))?;
+
+    Ok(monitor.config().clone())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_cpu_alert_config(
+    state: State<'_, CpuAlertState>,
+    config: CpuAlertConfig,
+) -> Result<(), String> {
+    let mut monitor = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire cpu alert lock: {}", e))?;
+
+    tracing::debug!("CPU alert config updated: {:?}", config);
+    monitor.set_config(config.clone());
+
+    config::update(|settings| {
+        settings.cpu_alert = config;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_cpu_alert_history(
+    state: State<'_, CpuAlertState>,
+) -> Result<Vec<CpuAlertEvent>, String> {
+    let monitor = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire cpu alert lock: {}", e))?;
+
+    Ok(monitor.history())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_ace_guard_usage_history(
+    state: State<'_, UsageMonitorState>,
+    process_id: Option<u32>,
+) -> Result<Vec<ProcessUsageHistory>, String> {
+    let monitor = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire usage monitor lock: {}", e))?;
+
+    Ok(monitor.get_history(process_id))
+}
+
+/// Usage of any process, e.g. the game. CPU usage is measured since the previous call
+/// for the same process, so poll this for live charts
+#[tauri::command]
+#[specta::specta]
+pub fn get_process_usage(
+    state: State<'_, ProcessUsageSamplerState>,
+    process_id: u32,
+) -> Result<ProcessUsage, String> {
+    let mut sampler = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire usage sampler lock: {}", e))?;
+
+    crate::windows::usage::get_process_usage(process_id, &mut sampler)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_ace_instance_settings(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<HashMap<String, AceInstanceSettings>, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_instance_settings())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_ace_instance_settings(
+    state: State<'_, AceProcessControllerState>,
+    game: String,
+    settings: AceInstanceSettings,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    tracing::debug!("ACE instance settings for {} updated: {:?}", game, settings);
+    controller.set_instance_settings(game, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_affinity_strategy(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<AffinityStrategy, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_affinity_strategy())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_affinity_strategy(
+    state: State<'_, AceProcessControllerState>,
+    strategy: AffinityStrategy,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    // a mask the config cannot hold is rejected before it is applied
+    config::update(|settings| {
+        settings.affinity_strategy = strategy;
+        Ok(())
+    })?;
+    controller.set_affinity_strategy(strategy);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_affinity_processors(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<Vec<u32>, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.get_affinity_processors()
+}
+
+/// Pin the processes to the logical processors picked in the core picker
+#[tauri::command]
+#[specta::specta]
+pub fn set_affinity_processors(
+    state: State<'_, AceProcessControllerState>,
+    processors: Vec<u32>,
+) -> Result<(), String> {
+    let strategy = crate::windows::affinity::strategy_from_processors(&processors)?;
+
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    // a mask the config cannot hold is rejected before it is applied
+    config::update(|settings| {
+        settings.affinity_strategy = strategy;
+        Ok(())
+    })?;
+    controller.set_affinity_strategy(strategy);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_notifications_enabled(state: State<'_, NotificationState>) -> bool {
+    state.is_enabled()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_notifications_enabled(state: State<'_, NotificationState>, enabled: bool) {
+    tracing::debug!("Desktop notifications enabled: {}", enabled);
+    state.set_enabled(enabled);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn query_history(
+    state: State<'_, HistoryState>,
+    query: HistoryQuery,
+) -> Result<Vec<HistoryEntry>, String> {
+    let guard = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire history lock: {}", e))?;
+
+    guard
+        .as_ref()
+        .ok_or_else(|| "History database is not available".to_string())?
+        .query(&query)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_average_cpu_usage(
+    state: State<'_, HistoryState>,
+    since: DateTime<Utc>,
+) -> Result<Option<f64>, String> {
+    let guard = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire history lock: {}", e))?;
+
+    guard
+        .as_ref()
+        .ok_or_else(|| "History database is not available".to_string())?
+        .average_cpu_usage(since)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn export_process_report(
+    app: AppHandle,
+    state: State<'_, AceProcessControllerState>,
+    format: ReportFormat,
+    path: String,
+) -> Result<(), String> {
+    let report = ProcessReport::collect(&state, app.package_info().version.to_string())?;
+    report.export(format, std::path::Path::new(&path))?;
+
+    tracing::info!("Exported {:?} process report to {}", format, path);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn export_session_report(
+    app: AppHandle,
+    state: State<'_, AceProcessControllerState>,
+    usage: State<'_, UsageMonitorState>,
+    path: String,
+) -> Result<(), String> {
+    let path = std::path::PathBuf::from(path);
+    let format = SessionReportFormat::from_path(&path);
+
+    let report = SessionReport::collect(&state, &usage, app.package_info().version.to_string())?;
+    report.export(format, &path)?;
+
+    tracing::info!("Exported {:?} session report to {}", format, path.display());
+    Ok(())
+}
+
+/// The newest buffered log events, oldest first, to fill a log view mounted after startup
+#[tauri::command]
+#[specta::specta]
+pub fn get_log_history(limit: Option<u32>, min_level: Option<LogLevel>) -> Vec<LogEvent> {
+    crate::logging::log_history(limit.map(|limit| limit as usize), min_level)
+}
+
+/// Search the in-memory log buffer by substring or regular expression
+#[tauri::command]
+#[specta::specta]
+pub fn query_logs(query: LogQuery) -> Result<Vec<LogEvent>, String> {
+    crate::logging::query_logs(&query)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn copy_to_clipboard(text: String) -> Result<(), String> {
+    crate::windows::clipboard::set_text(&text)
+}
+
+/// Copy log events as plain text in the export layout, the whole in-memory buffer
+/// without `logs`, returns the number of copied events
+#[tauri::command]
+#[specta::specta]
+pub fn copy_logs_to_clipboard(logs: Option<Vec<LogEvent>>) -> Result<u32, String> {
+    let logs = logs.unwrap_or_else(crate::logging::buffered_logs);
+    let text: Vec<String> = logs.iter().map(LogEvent::to_line).collect();
+
+    crate::windows::clipboard::set_text(&text.join("\n"))?;
+    Ok(logs.len() as u32)
+}
+
+/// Capture a window, or the whole display without one, and put the image on the clipboard
+#[tauri::command]
+#[specta::specta]
+pub fn copy_screenshot_to_clipboard(window_id: Option<u32>) -> Result<(), String> {
+    let screenshot = match window_id {
+        Some(window_id) => ScreenshotCapture::capture_by_window_id(window_id)?,
+        None => ScreenshotCapture::capture_display()?,
+    };
+
+    crate::windows::clipboard::set_image(&screenshot)
+}
+
+/// Write the in-memory log buffer to `path`, as JSON lines for a `.jsonl` file
+#[tauri::command]
+#[specta::specta]
+pub fn export_logs(path: String) -> Result<u32, String> {
+    let path = std::path::PathBuf::from(path);
+    let count = crate::logging::export_logs(&path)?;
+
+    tracing::info!("Exported {} log events to {}", count, path.display());
+    Ok(count as u32)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn benchmark_process_scan(iterations: u32) -> Result<ScanBenchmark, String> {
+    benchmark_scan(iterations)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_target_patterns(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<Vec<ProcessNamePattern>, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_target_patterns())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_target_patterns(
+    state: State<'_, AceProcessControllerState>,
+    patterns: Vec<ProcessNamePattern>,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_target_patterns(patterns.clone())?;
+
+    // the list lives in the config shared with the CLI, so it survives a restart
+    config::update(|settings| {
+        settings.target_patterns = patterns;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_game_mode_status() -> GameModeStatus {
+    crate::windows::game_mode::get_game_mode_status()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_cpu_topology() -> Result<CpuTopology, String> {
+    crate::windows::topology::get_cpu_topology()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_ace_service_status() -> Result<Vec<AceServiceStatus>, String> {
+    crate::windows::services::get_ace_service_status()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_ace_installation_info() -> AceInstallationInfo {
+    crate::windows::installation::get_ace_installation_info()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn install_logon_task() -> Result<(), String> {
+    crate::windows::scheduled_task::install_logon_task()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn uninstall_logon_task() -> Result<(), String> {
+    crate::windows::scheduled_task::uninstall_logon_task()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn is_logon_task_installed() -> bool {
+    crate::windows::scheduled_task::is_logon_task_installed()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_autostart_enabled() -> bool {
+    crate::windows::autostart::is_autostart_enabled()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_autostart_enabled(enabled: bool) -> Result<(), String> {
+    crate::windows::autostart::set_autostart_enabled(enabled)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_power_diagnostics() -> PowerDiagnostics {
+    crate::windows::power::get_power_diagnostics()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_system_info() -> SystemInfo {
+    crate::windows::system_info::get_system_info()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_app_info() -> AppInfo {
+    crate::updates::app_info()
+}
+
+/// Compare the running version with the newest GitHub release
+#[tauri::command]
+#[specta::specta]
+pub async fn check_for_updates() -> Result<UpdateStatus, String> {
+    tauri::async_runtime::spawn_blocking(crate::updates::check_for_updates)
+        .await
+        .map_err(|e| format!("Update check task failed: {}", e))?
+}
+
+/// Download the installer of the newest release, start it and exit so it can replace the app
+#[tauri::command]
+#[specta::specta]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    let handle = app.clone();
+    let version = tauri::async_runtime::spawn_blocking(move || {
+        crate::updates::download_and_run_installer(&handle)
+    })
+    .await
+    .map_err(|e| format!("Update task failed: {}", e))??;
+
+    tracing::info!("Exiting to update to {}", version);
+    app.exit(0);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn cleanup_installation(app: AppHandle) -> CleanupReport {
+    crate::cleanup::cleanup(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn restore_all_ace_guard_processes(
+    state: State<'_, AceProcessControllerState>,
+    game_boost: State<'_, GameBoostState>,
+) -> Result<String, String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    let result = controller.restore_all_processes();
+
+    if let Ok(mut boost) = game_boost.0.lock() {
+        match boost.revert() {
+            Ok(count) if count > 0 => tracing::info!("Reverted boost of {} games", count),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("{}", e),
+        }
+    }
+
+    match &result {
+        Ok(message) => history::record(HistoryKind::Revert, true, message, None),
+        Err(e) => history::record(HistoryKind::Revert, false, e, None),
+    }
+
+    result
+}
+
+/// Revert all modified processes with the outcome of each one, backs the Undo button
+#[tauri::command]
+#[specta::specta]
+pub fn revert_all_ace_guard_processes(
+    state: State<'_, AceProcessControllerState>,
+    game_boost: State<'_, GameBoostState>,
+) -> Result<Vec<ProcessRestoreResult>, String> {
+    let results = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?
+        .restore_processes();
+
+    if let Ok(mut boost) = game_boost.0.lock() {
+        match boost.revert() {
+            Ok(count) if count > 0 => tracing::info!("Reverted boost of {} games", count),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("{}", e),
+        }
+    }
+
+    if !results.is_empty() {
+        let restored = results.iter().filter(|r| r.success).count();
+        let summary = format!("Reverted {} of {} processes", restored, results.len());
+        let details = serde_json::to_string(&results).ok();
+        history::record(HistoryKind::Revert, restored > 0, &summary, details.as_deref());
+    }
+
+    Ok(results)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn optimize_ace_guard_process(
+    state: State<'_, AceProcessControllerState>,
+    process_id: u32,
+) -> Result<String, String> {
+    let result = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?
+        .optimize_single_process(process_id);
+
+    match &result {
+        Ok(message) => history::record(HistoryKind::Optimization, true, message, None),
+        Err(e) => history::record(HistoryKind::Optimization, false, e, None),
+    }
+
+    result
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn restore_ace_guard_process(
+    state: State<'_, AceProcessControllerState>,
+    process_id: u32,
+) -> Result<String, String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    let result = controller.restore_single_process(process_id);
+
+    match &result {
+        Ok(message) => history::record(HistoryKind::Revert, true, message, None),
+        Err(e) => history::record(HistoryKind::Revert, false, e, None),
+    }
+
+    result
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn suspend_ace_guard_process(
+    state: State<'_, AceProcessControllerState>,
+    process_id: u32,
+) -> Result<String, String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.suspend_process(process_id)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn resume_ace_guard_process(
+    state: State<'_, AceProcessControllerState>,
+    process_id: u32,
+) -> Result<String, String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.resume_process(process_id)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_game_boost_config(state: State<'_, GameBoostState>) -> Result<GameBoostConfig, String> {
+    let boost = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire game boost lock: {}", e))?;
+
+    Ok(boost.config().clone())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_game_boost_config(
+    state: State<'_, GameBoostState>,
+    config: GameBoostConfig,
+) -> Result<(), String> {
+    let mut boost = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire game boost lock: {}", e))?;
+
+    tracing::debug!("Game boost config set to {:?}", config);
+    boost.set_config(config.clone());
+
+    config::update(|settings| {
+        settings.game_boost = config;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn boost_game_processes(state: State<'_, GameBoostState>) -> Result<Vec<BoostedGame>, String> {
+    let mut boost = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire game boost lock: {}", e))?;
+
+    boost.boost_games()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn revert_game_boost(state: State<'_, GameBoostState>) -> Result<u32, String> {
+    let mut boost = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire game boost lock: {}", e))?;
+
+    boost.revert().map(|count| count as u32)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_saved_process_states(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<Vec<SavedProcessState>, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_saved_states())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_target_priority(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<TargetPriority, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_target_priority())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_target_priority(
+    state: State<'_, AceProcessControllerState>,
+    priority: TargetPriority,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_target_priority(priority);
+
+    config::update(|settings| {
+        settings.priority = priority;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_throttle_mode(state: State<'_, AceProcessControllerState>) -> Result<ThrottleMode, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_throttle_mode())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_throttle_mode(
+    state: State<'_, AceProcessControllerState>,
+    mode: ThrottleMode,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_throttle_mode(mode);
+
+    config::update(|settings| {
+        settings.throttle_mode = mode;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_lower_io_priority(state: State<'_, AceProcessControllerState>) -> Result<bool, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_lower_io_priority())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_lower_io_priority(
+    state: State<'_, AceProcessControllerState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_lower_io_priority(enabled);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_lower_memory_priority(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<bool, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_lower_memory_priority())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_lower_memory_priority(
+    state: State<'_, AceProcessControllerState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_lower_memory_priority(enabled);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_lower_gpu_priority(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<bool, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_lower_gpu_priority())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_lower_gpu_priority(
+    state: State<'_, AceProcessControllerState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_lower_gpu_priority(enabled);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_efficiency_mode(state: State<'_, AceProcessControllerState>) -> Result<bool, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_efficiency_mode())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_efficiency_mode(
+    state: State<'_, AceProcessControllerState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_efficiency_mode(enabled)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn is_efficiency_mode_supported() -> bool {
+    crate::windows::efficiency_mode::is_efficiency_mode_supported()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_trim_working_set(state: State<'_, AceProcessControllerState>) -> Result<bool, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_trim_working_set())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_trim_working_set(
+    state: State<'_, AceProcessControllerState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_trim_working_set(enabled);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_cpu_rate_limit(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<Option<u32>, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_cpu_rate_limit())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_cpu_rate_limit(
+    state: State<'_, AceProcessControllerState>,
+    percent: Option<u32>,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_cpu_rate_limit(percent)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_lower_thread_priority(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<bool, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_lower_thread_priority())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_lower_thread_priority(
+    state: State<'_, AceProcessControllerState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_lower_thread_priority(enabled);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_require_trusted_signature(
+    state: State<'_, AceProcessControllerState>,
+) -> Result<bool, String> {
+    let controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    Ok(controller.get_require_trusted_signature())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_require_trusted_signature(
+    state: State<'_, AceProcessControllerState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_require_trusted_signature(enabled);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_auto_optimize_config(
+    state: State<'_, AutoOptimizeState>,
+) -> Result<AutoOptimizeConfig, String> {
+    let optimizer = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire auto optimize lock: {}", e))?;
+
+    Ok(optimizer.config().clone())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_auto_optimize_config(
+    state: State<'_, AutoOptimizeState>,
+    config: AutoOptimizeConfig,
+) -> Result<(), String> {
+    let mut optimizer = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire auto optimize lock: {}", e))?;
+
+    tracing::debug!("Auto optimize config set to {:?}", config);
+    optimizer.set_config(config);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_process_status_config(
+    state: State<'_, ProcessStatusState>,
+) -> Result<ProcessStatusConfig, String> {
+    let watcher = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire process status lock: {}", e))?;
+
+    Ok(watcher.config().clone())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_process_status_config(
+    state: State<'_, ProcessStatusState>,
+    config: ProcessStatusConfig,
+) -> Result<(), String> {
+    let mut watcher = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire process status lock: {}", e))?;
+
+    tracing::debug!("Process status config set to {:?}", config);
+    watcher.set_config(config.clone());
+
+    // keep the config file in sync so the interval survives a restart
+    config::update(|settings| {
+        settings.process_status = config;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_watchdog_config(state: State<'_, WatchdogState>) -> Result<WatchdogConfig, String> {
+    let watchdog = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire watchdog lock: {}", e))?;
+
+    Ok(watchdog.config().clone())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_watchdog_config(
+    state: State<'_, WatchdogState>,
+    config: WatchdogConfig,
+) -> Result<(), String> {
+    let mut watchdog = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire watchdog lock: {}", e))?;
+
+    tracing::debug!("Watchdog config set to {:?}", config);
+    watchdog.set_config(config.clone());
+
+    config::update(|settings| {
+        settings.watchdog = config;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_settings() -> Result<Settings, String> {
+    config::load()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_settings(
+    app: AppHandle,
+    state: State<'_, AceProcessControllerState>,
+    status_state: State<'_, ProcessStatusState>,
+    scheduler_state: State<'_, SchedulerState>,
+    settings: Settings,
+) -> Result<(), String> {
+    settings.validate()?;
+
+    crate::hotkeys::replace_bindings(&app, &settings.hotkeys)?;
+
+    scheduler_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire scheduler lock: {}", e))?
+        .set_rules(settings.schedule.clone());
+
+    status_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire process status lock: {}", e))?
+        .set_config(settings.process_status.clone());
+
+    app.state::<WatchdogState>()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire watchdog lock: {}", e))?
+        .set_config(settings.watchdog.clone());
+
+    app.state::<GameBoostState>()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire game boost lock: {}", e))?
+        .set_config(settings.game_boost.clone());
+
+    app.state::<CpuAlertState>()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire cpu alert lock: {}", e))?
+        .set_config(settings.cpu_alert.clone());
+
+    let mut controller = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+    controller.set_target_priority(settings.priority);
+    controller.set_affinity_strategy(settings.affinity_strategy);
+    controller.set_throttle_mode(settings.throttle_mode);
+    controller.set_target_patterns(settings.target_patterns.clone())?;
+    crate::i18n::set_language(settings.language());
+    crate::logging::set_log_buffer_capacity(settings.log_buffer_capacity as usize);
+    crate::tray::set_close_to_tray(settings.close_to_tray);
+    tracing::debug!("Settings set to {:?}", settings);
+
+    config::update(|current| {
+        *current = settings;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_theme() -> Result<Theme, String> {
+    Ok(config::load()?.theme)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_theme(app: AppHandle, theme: Theme) -> Result<(), String> {
+    tracing::debug!("Theme set to {:?}", theme);
+
+    if let Some(window) = app.get_webview_window(crate::window_state::MAIN_WINDOW_LABEL) {
+        window
+            .set_theme(theme.window_theme())
+            .map_err(|e| format!("Failed to set window theme: {}", e))?;
+    }
+    config::update(|settings| {
+        settings.theme = theme;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_background_pause_status(
+    state: State<'_, BackgroundPauseState>,
+) -> Result<BackgroundPauseStatus, String> {
+    let pause = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire background pause lock: {}", e))?;
+
+    Ok(pause.status())
+}
+
+/// Pause the background rescan and the watchdog, for `minutes` or until resumed
+#[tauri::command]
+#[specta::specta]
+pub fn pause_background_tasks(
+    app: AppHandle,
+    state: State<'_, BackgroundPauseState>,
+    minutes: Option<u32>,
+) -> Result<BackgroundPauseStatus, String> {
+    let status = {
+        let mut pause = state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire background pause lock: {}", e))?;
+        pause.pause(Utc::now(), minutes.map(|m| chrono::Duration::minutes(m as i64)));
+        pause.status()
+    };
+
+    tracing::info!("Background tasks paused until {:?}", status.resume_at);
+    background_pause::emit_status(&app, status.clone());
+    Ok(status)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn resume_background_tasks(
+    app: AppHandle,
+    state: State<'_, BackgroundPauseState>,
+) -> Result<BackgroundPauseStatus, String> {
+    let status = {
+        let mut pause = state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire background pause lock: {}", e))?;
+        pause.resume();
+        pause.status()
+    };
+
+    tracing::info!("Background tasks resumed");
+    background_pause::emit_status(&app, status.clone());
+    Ok(status)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_schedule_rules(state: State<'_, SchedulerState>) -> Result<Vec<ScheduleRule>, String> {
+    let scheduler = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire scheduler lock: {}", e))?;
+
+    Ok(scheduler.rules().to_vec())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_schedule_rules(
+    state: State<'_, SchedulerState>,
+    rules: Vec<ScheduleRule>,
+) -> Result<(), String> {
+    for rule in &rules {
+        rule.validate()?;
+    }
+
+    state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire scheduler lock: {}", e))?
+        .set_rules(rules.clone());
+    tracing::debug!("Schedule rules set to {:?}", rules);
+
+    config::update(|settings| {
+        settings.schedule = rules;
+        Ok(())
+    })
+}
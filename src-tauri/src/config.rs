@@ -0,0 +1,419 @@
+use crate::{
+    hotkeys::{self, HotkeyBinding},
+    i18n::{self, Language},
+    logging,
+    windows::{
+        affinity::AffinityStrategy,
+        background_mode::ThrottleMode,
+        cpu_alert::CpuAlertConfig,
+        game_boost::GameBoostConfig,
+        priority::TargetPriority,
+        process_matcher::{ProcessMatcher, ProcessNamePattern},
+        scheduler::ScheduleRule,
+        status_events::ProcessStatusConfig,
+        watchdog::WatchdogConfig,
+    },
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Folder in the roaming app data directory holding the config
+pub const CONFIG_DIR_NAME: &str = "tencent-ace-tools";
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// User settings shared by the GUI and the CLI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct Settings {
+    pub priority: TargetPriority,
+    pub affinity_strategy: AffinityStrategy,
+    pub throttle_mode: ThrottleMode,
+    /// Seconds between two scans of the watch mode
+    pub watch_interval_secs: u64,
+    pub target_patterns: Vec<ProcessNamePattern>,
+    /// Background rescan that keeps the displayed process status current
+    pub process_status: ProcessStatusConfig,
+    /// UI language as a locale tag, `zh-cn` or `en`, follows the system locale by default
+    pub language: String,
+    pub theme: Theme,
+    /// Global shortcuts, they work while a game has focus
+    pub hotkeys: Vec<HotkeyBinding>,
+    /// Number of log events kept in memory for the log viewer and exports
+    pub log_buffer_capacity: u32,
+    /// Hide the main window to the tray when it is closed instead of exiting
+    pub close_to_tray: bool,
+    /// Rules run by the scheduler on logon, game starts and game exits
+    pub schedule: Vec<ScheduleRule>,
+    /// Title of the window picked for screenshots, the whole display without one
+    pub screenshot_window: Option<String>,
+    /// Main window geometry of the last session, the window opens centered without it
+    pub window: Option<WindowGeometry>,
+    pub watchdog: WatchdogConfig,
+    pub game_boost: GameBoostConfig,
+    pub cpu_alert: CpuAlertConfig,
+}
+
+/// Color scheme of the UI, `System` follows the Windows app mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl Theme {
+    /// Theme of the native window frame, `None` follows the system
+    pub fn window_theme(self) -> Option<tauri::Theme> {
+        match self {
+            Theme::System => None,
+            Theme::Light => Some(tauri::Theme::Light),
+            Theme::Dark => Some(tauri::Theme::Dark),
+        }
+    }
+}
+
+/// Position and size of a window in physical pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            priority: TargetPriority::default(),
+            affinity_strategy: AffinityStrategy::default(),
+            throttle_mode: ThrottleMode::default(),
+            watch_interval_secs: 3,
+            target_patterns: ProcessMatcher::default().patterns().to_vec(),
+            process_status: ProcessStatusConfig::default(),
+            language: i18n::system_language().tag().to_string(),
+            theme: Theme::default(),
+            hotkeys: hotkeys::default_bindings(),
+            log_buffer_capacity: logging::LOG_BUFFER_CAPACITY as u32,
+            close_to_tray: true,
+            schedule: Vec::new(),
+            screenshot_window: None,
+            window: None,
+            watchdog: WatchdogConfig::default(),
+            game_boost: GameBoostConfig::default(),
+            cpu_alert: CpuAlertConfig::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Read the settings, writing the defaults first when the file does not exist yet
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            let settings = Self::default();
+            settings.save(path)?;
+            return Ok(settings);
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let settings: Self = toml::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+
+        // a crash mid-write leaves the temp file behind instead of a truncated config
+        let temp = path.with_extension("toml.tmp");
+        std::fs::write(&temp, content)
+            .map_err(|e| format!("Failed to write {}: {}", temp.display(), e))?;
+        std::fs::rename(&temp, path)
+            .map_err(|e| format!("Failed to replace {}: {}", path.display(), e))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if Language::from_tag(&self.language).is_none() {
+            return Err(format!("Unsupported language: {}", self.language));
+        }
+
+        // TOML integers are signed, a mask using the 64th processor cannot be written
+        if let AffinityStrategy::CustomMask(mask) = self.affinity_strategy {
+            if i64::try_from(mask).is_err() {
+                return Err(
+                    "Custom affinity masks can only use the first 63 processors".to_string()
+                );
+            }
+        }
+
+        if self.watch_interval_secs == 0 {
+            return Err("Watch interval must be at least one second".to_string());
+        }
+
+        let capacity = self.log_buffer_capacity as usize;
+        if !(1..=logging::MAX_LOG_BUFFER_CAPACITY).contains(&capacity) {
+            return Err(format!(
+                "Log buffer capacity must be between 1 and {}",
+                logging::MAX_LOG_BUFFER_CAPACITY
+            ));
+        }
+
+        for rule in &self.schedule {
+            rule.validate()?;
+        }
+
+        let conflicts = hotkeys::find_conflicts(&self.hotkeys);
+        if !conflicts.is_empty() {
+            return Err(conflicts.join("; "));
+        }
+
+        // reject the list as a whole instead of silently dropping entries
+        ProcessMatcher::new(self.target_patterns.clone()).map(|_| ())
+    }
+
+    pub fn language(&self) -> Language {
+        Language::from_tag(&self.language).unwrap_or_else(i18n::system_language)
+    }
+}
+
+/// Location of the config in the roaming app data directory
+pub fn config_path() -> Result<PathBuf, String> {
+    std::env::var_os("APPDATA")
+        .map(|dir| PathBuf::from(dir).join(CONFIG_DIR_NAME).join(CONFIG_FILE))
+        .ok_or_else(|| "APPDATA is not set".to_string())
+}
+
+/// Serializes every access to the config file, the GUI commands, the window state and
+/// the tray all load, modify and save the same file
+static CONFIG_LOCK: Mutex<()> = Mutex::new(());
+
+/// Load the settings from the default location
+pub fn load() -> Result<Settings, String> {
+    let path = config_path()?;
+    let _guard = CONFIG_LOCK
+        .lock()
+        .map_err(|e| format!("Failed to acquire config lock: {}", e))?;
+
+    Settings::load(&path)
+}
+
+/// Load the settings from the default location, let `f` modify them and save the
+/// result, without another writer slipping in between
+pub fn update<T>(f: impl FnOnce(&mut Settings) -> Result<T, String>) -> Result<T, String> {
+    update_at(&config_path()?, f)
+}
+
+/// `update` for the config at `path`
+pub(crate) fn update_at<T>(
+    path: &Path,
+    f: impl FnOnce(&mut Settings) -> Result<T, String>,
+) -> Result<T, String> {
+    let _guard = CONFIG_LOCK
+        .lock()
+        .map_err(|e| format!("Failed to acquire config lock: {}", e))?;
+
+    let mut settings = Settings::load(path)?;
+    let result = f(&mut settings)?;
+    settings.validate()?;
+    settings.save(path)?;
+    Ok(result)
+}
+
+/// Load the settings from the default location, falling back to the defaults on errors
+pub fn load_or_default() -> Settings {
+    match load() {
+        Ok(settings) => settings,
+        Err(e) => {
+            tracing::warn!("Failed to load settings, using defaults: {}", e);
+            Settings::default()
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::windows::{
+        game_boost::GamePriority,
+        scheduler::{ScheduleAction, ScheduleTrigger},
+    };
+
+    /// Empty per-test directory in the temp dir, for tests reading and writing config files
+    pub(crate) fn temp_config_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ace-tools-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_load_writes_defaults_and_reads_edits() {
+        let dir = temp_config_dir("config");
+        let path = dir.join(CONFIG_FILE);
+
+        assert_eq!(Settings::load(&path).unwrap(), Settings::default());
+        assert!(path.exists());
+
+        let edited = Settings {
+            priority: TargetPriority::BelowNormal,
+            affinity_strategy: AffinityStrategy::CustomMask(0xF0),
+            throttle_mode: ThrottleMode::Background,
+            watch_interval_secs: 10,
+            target_patterns: vec![ProcessNamePattern::Glob("SGuard*".to_string())],
+            process_status: ProcessStatusConfig {
+                enabled: false,
+                rescan_interval_secs: 30,
+            },
+            language: "en".to_string(),
+            theme: Theme::Light,
+            hotkeys: vec![HotkeyBinding {
+                action: hotkeys::HotkeyAction::Screenshot,
+                shortcut: "Ctrl+Shift+S".to_string(),
+                enabled: true,
+            }],
+            log_buffer_capacity: 5000,
+            close_to_tray: false,
+            schedule: vec![ScheduleRule {
+                trigger: ScheduleTrigger::GameExit("UAGame.exe".to_string()),
+                action: ScheduleAction::Revert,
+                enabled: true,
+            }],
+            screenshot_window: Some("Delta Force".to_string()),
+            window: Some(WindowGeometry {
+                x: -1200,
+                y: 80,
+                width: 1024,
+                height: 768,
+                maximized: false,
+            }),
+            watchdog: WatchdogConfig {
+                enabled: true,
+                poll_interval_secs: 5,
+            },
+            game_boost: GameBoostConfig {
+                enabled: true,
+                priority: GamePriority::AboveNormal,
+            },
+            cpu_alert: CpuAlertConfig {
+                enabled: true,
+                threshold_percent: 25.5,
+                duration_secs: 30,
+                auto_reapply: true,
+            },
+        };
+        edited.save(&path).unwrap();
+        assert_eq!(Settings::load(&path).unwrap(), edited);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_missing_keys_use_defaults() {
+        let settings: Settings = toml::from_str("language = \"en\"").unwrap();
+        assert_eq!(settings.language, "en");
+        assert_eq!(settings.watch_interval_secs, 3);
+        assert_eq!(settings.theme, Theme::System);
+    }
+
+    #[test]
+    fn test_partial_tables_use_defaults() {
+        let settings: Settings = toml::from_str("[process_status]\nenabled = false").unwrap();
+        assert!(!settings.process_status.enabled);
+        assert_eq!(
+            settings.process_status.rescan_interval_secs,
+            ProcessStatusConfig::default().rescan_interval_secs
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys_are_ignored() {
+        // keys of newer or older versions must not make the whole file unreadable
+        let settings: Settings = toml::from_str(
+            "removed_option = true\nwatch_interval_secs = 7\n[future_table]\nkey = 1",
+        )
+        .unwrap();
+        assert_eq!(settings.watch_interval_secs, 7);
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_values() {
+        let dir = temp_config_dir("config-invalid");
+        let path = dir.join(CONFIG_FILE);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        std::fs::write(&path, "target_patterns = [{ Exact = \"csrss.exe\" }]").unwrap();
+        assert!(Settings::load(&path).is_err());
+
+        std::fs::write(&path, "watch_interval_secs = 0").unwrap();
+        assert!(Settings::load(&path).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_concurrent_updates_are_not_lost() {
+        let dir = temp_config_dir("config-update");
+        let path = dir.join(CONFIG_FILE);
+
+        let writers: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    update_at(&path, |settings| {
+                        let pattern = ProcessNamePattern::Exact(format!("Game{}.exe", i));
+                        settings.target_patterns.push(pattern);
+                        Ok(())
+                    })
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap().unwrap();
+        }
+
+        let settings = Settings::load(&path).unwrap();
+        for i in 0..8 {
+            let pattern = ProcessNamePattern::Exact(format!("Game{}.exe", i));
+            assert!(settings.target_patterns.contains(&pattern));
+        }
+        assert!(!path.with_extension("toml.tmp").exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_update_keeps_the_file_on_invalid_edits() {
+        let dir = temp_config_dir("config-update-invalid");
+        let path = dir.join(CONFIG_FILE);
+
+        let result = update_at(&path, |settings| {
+            settings.affinity_strategy = AffinityStrategy::CustomMask(1 << 63);
+            Ok(())
+        });
+        assert!(result.is_err());
+        assert_eq!(Settings::load(&path).unwrap(), Settings::default());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_theme_uses_lowercase_names() {
+        let settings: Settings = toml::from_str("theme = \"dark\"").unwrap();
+        assert_eq!(settings.theme, Theme::Dark);
+        assert!(toml::from_str::<Settings>("theme = \"Dark\"").is_err());
+    }
+}
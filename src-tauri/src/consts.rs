@@ -1,10 +1,41 @@
+use chrono::{DateTime, Utc};
 use std::sync::OnceLock;
 use tauri::AppHandle;
 
 pub static TAURI_APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
 
+/// Same as `identifier` in tauri.conf.json, used to find the app directories without an `AppHandle`
+pub const APP_IDENTIFIER: &str = "moe.elaina.tencent-ace-tools";
+
+/// When the app was started, the beginning of the session report
+pub static SESSION_STARTED_AT: OnceLock<DateTime<Utc>> = OnceLock::new();
+
 // pub const ACE_ANTI_CHEAT_EXPERT_PATH: &str = "C:\\Program Files\\AntiCheatExpert";
 // pub const ACE_GUARD_64_SUBPATH: &str = "SGuard\\x64";
 pub const ACE_GUARD_64_PROCESS_NAME: &str = "SGuard64.exe";
 
+/// Folder name every ACE installation lives in, globally or inside a game directory
+pub const ACE_INSTALL_DIR_NAME: &str = "AntiCheatExpert";
+
+/// Process names the optimizer is ever allowed to modify
+pub const ACE_ALLOWED_PROCESS_NAMES: &[&str] = &[ACE_GUARD_64_PROCESS_NAME, "SGuardSvc64.exe"];
+
+/// Subject names of the certificates ACE binaries are signed with, compared exactly
+pub const ACE_TRUSTED_SIGNERS: &[&str] = &[
+    "Tencent Technology (Shenzhen) Company Limited",
+    "Shenzhen Tencent Computer Systems Company Limited",
+];
+
 pub const DELTA_FORCE_PROCESS_NAME: &str = "DeltaForceClient-Win64-Shipping.exe";
+
+/// Known ACE protected games as (display name, executable name)
+pub const KNOWN_GAMES: &[(&str, &str)] = &[
+    ("Delta Force", DELTA_FORCE_PROCESS_NAME),
+    ("Arena Breakout: Infinite", "UAGame.exe"),
+];
+
+/// Known game launchers as (display name, executable name)
+pub const KNOWN_LAUNCHERS: &[(&str, &str)] = &[
+    ("WeGame", "wegame.exe"),
+    ("Delta Force Launcher", "delta_force_launcher.exe"),
+];
@@ -0,0 +1,316 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{path::Path, sync::Mutex};
+use tauri::Manager;
+
+use crate::consts::TAURI_APP_HANDLE;
+
+pub const HISTORY_DATABASE_FILE: &str = "history.sqlite3";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum HistoryKind {
+    Scan,
+    Optimization,
+    Revert,
+    Benchmark,
+}
+
+impl HistoryKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            HistoryKind::Scan => "scan",
+            HistoryKind::Optimization => "optimization",
+            HistoryKind::Revert => "revert",
+            HistoryKind::Benchmark => "benchmark",
+        }
+    }
+
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "scan" => Some(HistoryKind::Scan),
+            "optimization" => Some(HistoryKind::Optimization),
+            "revert" => Some(HistoryKind::Revert),
+            "benchmark" => Some(HistoryKind::Benchmark),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub timestamp: DateTime<Utc>,
+    pub kind: HistoryKind,
+    pub success: bool,
+    pub summary: String,
+    /// Arbitrary JSON payload, e.g. the affected processes
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct HistoryQuery {
+    pub kind: Option<HistoryKind>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: Option<u32>,
+}
+
+/// Local SQLite store of scans, optimizations, reverts, benchmarks and usage samples
+pub struct HistoryStore {
+    connection: Connection,
+}
+
+impl HistoryStore {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create history directory: {}", e))?;
+        }
+
+        let connection = Connection::open(path)
+            .map_err(|e| format!("Failed to open history database: {}", e))?;
+
+        Self::from_connection(connection)
+    }
+
+    pub fn open_in_memory() -> Result<Self, String> {
+        let connection = Connection::open_in_memory()
+            .map_err(|e| format!("Failed to open history database: {}", e))?;
+
+        Self::from_connection(connection)
+    }
+
+    fn from_connection(connection: Connection) -> Result<Self, String> {
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS history_entries (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    timestamp TEXT NOT NULL,
+                    kind TEXT NOT NULL,
+                    success INTEGER NOT NULL,
+                    summary TEXT NOT NULL,
+                    details TEXT
+                );
+                CREATE INDEX IF NOT EXISTS history_entries_timestamp
+                    ON history_entries (timestamp);
+                CREATE TABLE IF NOT EXISTS usage_samples (
+                    timestamp TEXT NOT NULL,
+                    process_id INTEGER NOT NULL,
+                    process_name TEXT NOT NULL,
+                    cpu_usage REAL,
+                    working_set INTEGER NOT NULL,
+                    private_bytes INTEGER NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS usage_samples_timestamp
+                    ON usage_samples (timestamp);",
+            )
+            .map_err(|e| format!("Failed to initialize history database: {}", e))?;
+
+        Ok(Self { connection })
+    }
+
+    pub fn record(
+        &self,
+        kind: HistoryKind,
+        success: bool,
+        summary: &str,
+        details: Option<&str>,
+    ) -> Result<i64, String> {
+        self.connection
+            .execute(
+                "INSERT INTO history_entries (timestamp, kind, success, summary, details)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    Utc::now().to_rfc3339(),
+                    kind.as_str(),
+                    success,
+                    summary,
+                    details
+                ],
+            )
+            .map_err(|e| format!("Failed to record history entry: {}", e))?;
+
+        Ok(self.connection.last_insert_rowid())
+    }
+
+    pub fn record_usage_sample(
+        &self,
+        timestamp: DateTime<Utc>,
+        process_id: u32,
+        process_name: &str,
+        cpu_usage: Option<f32>,
+        working_set: u64,
+        private_bytes: u64,
+    ) -> Result<(), String> {
+        self.connection
+            .execute(
+                "INSERT INTO usage_samples
+                 (timestamp, process_id, process_name, cpu_usage, working_set, private_bytes)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    timestamp.to_rfc3339(),
+                    process_id,
+                    process_name,
+                    cpu_usage,
+                    working_set as i64,
+                    private_bytes as i64
+                ],
+            )
+            .map_err(|e| format!("Failed to record usage sample: {}", e))?;
+
+        Ok(())
+    }
+
+    pub fn query(&self, query: &HistoryQuery) -> Result<Vec<HistoryEntry>, String> {
+        let mut statement = self
+            .connection
+            .prepare(
+                "SELECT id, timestamp, kind, success, summary, details FROM history_entries
+                 WHERE (?1 IS NULL OR kind = ?1)
+                   AND (?2 IS NULL OR timestamp >= ?2)
+                   AND (?3 IS NULL OR timestamp <= ?3)
+                 ORDER BY timestamp DESC
+                 LIMIT ?4",
+            )
+            .map_err(|e| format!("Failed to prepare history query: {}", e))?;
+
+        let rows = statement
+            .query_map(
+                params![
+                    query.kind.map(|k| k.as_str()),
+                    query.since.map(|t| t.to_rfc3339()),
+                    query.until.map(|t| t.to_rfc3339()),
+                    query.limit.map(|l| l as i64).unwrap_or(-1)
+                ],
+                |row| {
+                    let timestamp: String = row.get(1)?;
+                    let kind: String = row.get(2)?;
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        timestamp,
+                        kind,
+                        row.get::<_, bool>(3)?,
+                        row.get::<_, String>(4)?,
+                        row.get::<_, Option<String>>(5)?,
+                    ))
+                },
+            )
+            .map_err(|e| format!("Failed to query history: {}", e))?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            let (id, timestamp, kind, success, summary, details) =
+                row.map_err(|e| format!("Failed to read history entry: {}", e))?;
+
+            let (Ok(timestamp), Some(kind)) = (
+                DateTime::parse_from_rfc3339(&timestamp),
+                HistoryKind::from_str(&kind),
+            ) else {
+                continue;
+            };
+
+            entries.push(HistoryEntry {
+                id,
+                timestamp: timestamp.with_timezone(&Utc),
+                kind,
+                success,
+                summary,
+                details,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    /// Average CPU usage of all recorded ACE processes since the given time
+    pub fn average_cpu_usage(&self, since: DateTime<Utc>) -> Result<Option<f64>, String> {
+        self.connection
+            .query_row(
+                "SELECT AVG(cpu_usage) FROM usage_samples
+                 WHERE cpu_usage IS NOT NULL AND timestamp >= ?1",
+                params![since.to_rfc3339()],
+                |row| row.get::<_, Option<f64>>(0),
+            )
+            .optional()
+            .map(|value| value.flatten())
+            .map_err(|e| format!("Failed to query average cpu usage: {}", e))
+    }
+}
+
+// State wrapper for HistoryStore, empty until the database was opened during setup
+#[derive(Default)]
+pub struct HistoryState(pub Mutex<Option<HistoryStore>>);
+
+/// Record a history entry through the global app handle, failures are only logged
+pub fn record(kind: HistoryKind, success: bool, summary: &str, details: Option<&str>) {
+    let Some(app_handle) = TAURI_APP_HANDLE.get() else {
+        return;
+    };
+
+    let state = app_handle.state::<HistoryState>();
+    let Ok(guard) = state.0.lock() else {
+        return;
+    };
+
+    if let Some(store) = guard.as_ref() {
+        if let Err(e) = store.record(kind, success, summary, details) {
+            tracing::warn!("{}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_query_history() {
+        let store = HistoryStore::open_in_memory().unwrap();
+        store
+            .record(HistoryKind::Scan, true, "Found 1 process", None)
+            .unwrap();
+        store
+            .record(
+                HistoryKind::Optimization,
+                false,
+                "Access denied",
+                Some("[]"),
+            )
+            .unwrap();
+
+        let all = store.query(&HistoryQuery::default()).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let optimizations = store
+            .query(&HistoryQuery {
+                kind: Some(HistoryKind::Optimization),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(optimizations.len(), 1);
+        assert!(!optimizations[0].success);
+        assert_eq!(optimizations[0].details.as_deref(), Some("[]"));
+    }
+
+    #[test]
+    fn test_average_cpu_usage() {
+        let store = HistoryStore::open_in_memory().unwrap();
+        let now = Utc::now();
+        store
+            .record_usage_sample(now, 1, "SGuard64.exe", Some(2.0), 0, 0)
+            .unwrap();
+        store
+            .record_usage_sample(now, 1, "SGuard64.exe", Some(4.0), 0, 0)
+            .unwrap();
+        store
+            .record_usage_sample(now, 1, "SGuard64.exe", None, 0, 0)
+            .unwrap();
+
+        let average = store
+            .average_cpu_usage(now - chrono::Duration::days(30))
+            .unwrap();
+        assert_eq!(average, Some(3.0));
+    }
+}
@@ -0,0 +1,364 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{collections::HashMap, path::PathBuf, str::FromStr, sync::Mutex};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+use tauri_specta::Event;
+
+use crate::notifications::{notify, NotificationKind};
+use crate::windows::watchdog::WatchdogState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+pub enum HotkeyAction {
+    Optimize,
+    Restore,
+    Screenshot,
+    ToggleWatchdog,
+    /// Drag a region on screen and recognize its text
+    OcrRegion,
+}
+
+/// Folder in the user's pictures directory receiving hotkey screenshots
+pub const SCREENSHOT_DIR_NAME: &str = "Tencent ACE Tools";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HotkeyBinding {
+    pub action: HotkeyAction,
+    pub shortcut: String,
+    pub enabled: bool,
+}
+
+// Emitted whenever a registered global shortcut is pressed
+#[derive(Debug, Clone, Serialize, Type, Event)]
+pub struct HotkeyEvent {
+    pub action: HotkeyAction,
+    pub shortcut: String,
+}
+
+// Emitted once the capture started by a screenshot or OCR hotkey is done
+#[derive(Debug, Clone, Serialize, Type, Event)]
+pub struct HotkeyCaptureEvent {
+    pub action: HotkeyAction,
+    /// File the screenshot was saved to
+    pub path: Option<String>,
+    /// Text recognized in the picked region
+    pub text: Option<String>,
+}
+
+// State wrapper for the currently active hotkey bindings
+pub struct HotkeyState(pub Mutex<Vec<HotkeyBinding>>);
+
+impl Default for HotkeyState {
+    fn default() -> Self {
+        Self(Mutex::new(default_bindings()))
+    }
+}
+
+/// Default bindings used on first start
+pub fn default_bindings() -> Vec<HotkeyBinding> {
+    [
+        (HotkeyAction::Optimize, "Ctrl+Alt+O"),
+        (HotkeyAction::Restore, "Ctrl+Alt+R"),
+        (HotkeyAction::Screenshot, "Ctrl+Alt+S"),
+        (HotkeyAction::ToggleWatchdog, "Ctrl+Alt+W"),
+        (HotkeyAction::OcrRegion, "Ctrl+Alt+T"),
+    ]
+    .into_iter()
+    .map(|(action, shortcut)| HotkeyBinding {
+        action,
+        shortcut: shortcut.to_string(),
+        enabled: true,
+    })
+    .collect()
+}
+
+/// Append the default binding of every action missing from `bindings`,
+/// so actions added after the config was written still get a shortcut.
+/// A default whose key combination the user already took is added disabled
+pub fn with_defaults(mut bindings: Vec<HotkeyBinding>) -> Vec<HotkeyBinding> {
+    let shortcut_id = |shortcut: &str| Shortcut::from_str(shortcut).ok().map(|s| s.id());
+
+    for mut default in default_bindings() {
+        if bindings.iter().any(|b| b.action == default.action) {
+            continue;
+        }
+
+        let id = shortcut_id(&default.shortcut);
+        if bindings
+            .iter()
+            .any(|b| b.enabled && shortcut_id(&b.shortcut) == id)
+        {
+            tracing::warn!(
+                "Default shortcut {} for {:?} is already bound, leaving it disabled",
+                default.shortcut,
+                default.action
+            );
+            default.enabled = false;
+        }
+        bindings.push(default);
+    }
+    bindings
+}
+
+fn screenshot_path(dir: PathBuf, time: DateTime<Local>) -> PathBuf {
+    dir.join(format!("screenshot-{}.png", time.format("%Y%m%d-%H%M%S")))
+}
+
+fn save_screenshot(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .picture_dir()
+        .map_err(|e| format!("Failed to resolve pictures directory: {}", e))?
+        .join(SCREENSHOT_DIR_NAME);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let path = screenshot_path(dir, Local::now());
+    crate::command::capture_screenshot_window()?.save(&path)?;
+    Ok(path)
+}
+
+/// Check the enabled bindings for invalid shortcuts and duplicated key combinations
+pub fn find_conflicts(bindings: &[HotkeyBinding]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    let mut seen: HashMap<u32, HotkeyAction> = HashMap::new();
+
+    for binding in bindings.iter().filter(|b| b.enabled) {
+        match Shortcut::from_str(&binding.shortcut) {
+            Ok(shortcut) => {
+                if let Some(other) = seen.insert(shortcut.id(), binding.action) {
+                    conflicts.push(format!(
+                        "Shortcut '{}' is bound to both {:?} and {:?}",
+                        binding.shortcut, other, binding.action
+                    ));
+                }
+            }
+            Err(e) => conflicts.push(format!(
+                "Invalid shortcut '{}' for {:?}: {}",
+                binding.shortcut, binding.action, e
+            )),
+        }
+    }
+
+    conflicts
+}
+
+/// Replace all registered global shortcuts with the given bindings
+pub fn apply_bindings(app: &AppHandle, bindings: &[HotkeyBinding]) -> Result<(), String> {
+    let conflicts = find_conflicts(bindings);
+    if !conflicts.is_empty() {
+        return Err(conflicts.join("; "));
+    }
+
+    let global_shortcut = app.global_shortcut();
+    global_shortcut
+        .unregister_all()
+        .map_err(|e| format!("Failed to unregister shortcuts: {}", e))?;
+
+    // Registration fails when another application already owns the key combination
+    let mut failures = Vec::new();
+    for binding in bindings.iter().filter(|b| b.enabled) {
+        if let Err(e) = global_shortcut.register(binding.shortcut.as_str()) {
+            tracing::warn!("Failed to register shortcut {}: {}", binding.shortcut, e);
+            failures.push(format!(
+                "Shortcut '{}' for {:?} is already in use: {}",
+                binding.shortcut, binding.action, e
+            ));
+        } else {
+            tracing::debug!(
+                "Registered shortcut {} for {:?}",
+                binding.shortcut,
+                binding.action
+            );
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("; "))
+    }
+}
+
+/// Serializes `replace_bindings` calls, `HotkeyState` itself is only locked briefly
+/// because the shortcut handler reads it on the event loop
+static REGISTRATION_LOCK: Mutex<()> = Mutex::new(());
+
+/// Register the given bindings and keep them in `HotkeyState`. When one of them cannot
+/// be registered the previous bindings are registered again, so the shortcuts always
+/// match the state the handler looks them up in
+pub fn replace_bindings(app: &AppHandle, bindings: &[HotkeyBinding]) -> Result<(), String> {
+    let _registration = REGISTRATION_LOCK
+        .lock()
+        .map_err(|e| format!("Failed to acquire hotkey registration lock: {}", e))?;
+
+    let state = app.state::<HotkeyState>();
+    let current = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire hotkey lock: {}", e))?
+        .clone();
+
+    if let Err(e) = apply_bindings(app, bindings) {
+        if let Err(rollback) = apply_bindings(app, &current) {
+            tracing::warn!("Failed to restore the previous shortcuts: {}", rollback);
+        }
+        return Err(e);
+    }
+
+    *state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire hotkey lock: {}", e))? = bindings.to_vec();
+    Ok(())
+}
+
+/// Global shortcut handler registered with the plugin
+pub fn handle_shortcut(app: &AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.state() != ShortcutState::Pressed {
+        return;
+    }
+
+    let binding = {
+        let state = app.state::<HotkeyState>();
+        let Ok(bindings) = state.0.lock() else {
+            return;
+        };
+
+        bindings
+            .iter()
+            .filter(|b| b.enabled)
+            .find(|b| {
+                Shortcut::from_str(&b.shortcut)
+                    .map(|s| s.id() == shortcut.id())
+                    .unwrap_or(false)
+            })
+            .cloned()
+    };
+
+    let Some(binding) = binding else {
+        return;
+    };
+
+    tracing::info!("Hotkey {} pressed: {:?}", binding.shortcut, binding.action);
+
+    match binding.action {
+        HotkeyAction::Optimize => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let result = crate::command::optimize_all_ace_guard_processes(
+                    app.clone(),
+                    app.state(),
+                    app.state(),
+                )
+                .await;
+                // the hotkey is meant to be used from a fullscreen game
+                match result {
+                    Ok(message) => notify(&app, NotificationKind::OptimizationApplied, &message),
+                    Err(e) => {
+                        tracing::warn!("Hotkey optimization failed: {}", e);
+                        notify(&app, NotificationKind::OptimizationFailed, &e);
+                    }
+                }
+            });
+        }
+        HotkeyAction::Restore => {
+            if let Err(e) =
+                crate::command::restore_all_ace_guard_processes(app.state(), app.state())
+            {
+                tracing::warn!("Hotkey restore failed: {}", e);
+            }
+        }
+        HotkeyAction::ToggleWatchdog => {
+            if let Ok(mut watchdog) = app.state::<WatchdogState>().0.lock() {
+                let enabled = watchdog.toggle();
+                tracing::info!("Watchdog {}", if enabled { "enabled" } else { "disabled" });
+            }
+        }
+        HotkeyAction::Screenshot => {
+            let app = app.clone();
+            tauri::async_runtime::spawn_blocking(move || match save_screenshot(&app) {
+                Ok(path) => {
+                    let path = path.display().to_string();
+                    tracing::info!("Hotkey screenshot saved to {}", path);
+                    notify(&app, NotificationKind::ScreenshotSaved, &path);
+                    let _ = HotkeyCaptureEvent {
+                        action: HotkeyAction::Screenshot,
+                        path: Some(path),
+                        text: None,
+                    }
+                    .emit(&app);
+                }
+                Err(e) => tracing::warn!("Hotkey screenshot failed: {}", e),
+            });
+        }
+        HotkeyAction::OcrRegion => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                match crate::region_picker::pick_region(&app, true).await {
+                    Ok(Some(capture)) => {
+                        let text = capture.ocr.map(|ocr| ocr.full_text).unwrap_or_default();
+                        notify(&app, NotificationKind::TextRecognized, &text);
+                        let _ = HotkeyCaptureEvent {
+                            action: HotkeyAction::OcrRegion,
+                            path: None,
+                            text: Some(text),
+                        }
+                        .emit(&app);
+                    }
+                    Ok(None) => {}
+                    Err(e) => tracing::warn!("Hotkey OCR failed: {}", e),
+                }
+            });
+        }
+    }
+
+    // Let the frontend react to every action, including the ones handled above
+    let _ = HotkeyEvent {
+        action: binding.action,
+        shortcut: binding.shortcut,
+    }
+    .emit(app);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_defaults_adds_missing_actions() {
+        let bindings = with_defaults(vec![HotkeyBinding {
+            action: HotkeyAction::Optimize,
+            shortcut: "Ctrl+Shift+F1".to_string(),
+            enabled: false,
+        }]);
+
+        assert_eq!(bindings.len(), default_bindings().len());
+        assert_eq!(bindings[0].shortcut, "Ctrl+Shift+F1");
+        assert!(!bindings[0].enabled);
+        assert!(bindings.iter().any(|b| b.action == HotkeyAction::OcrRegion));
+        assert!(find_conflicts(&bindings).is_empty());
+    }
+
+    #[test]
+    fn test_with_defaults_disables_taken_shortcuts() {
+        let bindings = with_defaults(vec![HotkeyBinding {
+            action: HotkeyAction::Screenshot,
+            shortcut: "Ctrl+Alt+T".to_string(),
+            enabled: true,
+        }]);
+
+        let ocr = bindings
+            .iter()
+            .find(|b| b.action == HotkeyAction::OcrRegion)
+            .unwrap();
+        assert_eq!(ocr.shortcut, "Ctrl+Alt+T");
+        assert!(!ocr.enabled);
+        assert!(bindings
+            .iter()
+            .filter(|b| b.action != HotkeyAction::OcrRegion)
+            .all(|b| b.enabled));
+        assert!(find_conflicts(&bindings).is_empty());
+    }
+}
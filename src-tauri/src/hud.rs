@@ -0,0 +1,142 @@
+use crate::windows::{usage::CpuUsageSampler, AceProcessControllerState};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, PhysicalPosition, WebviewUrl, WebviewWindowBuilder};
+
+pub const HUD_WINDOW_LABEL: &str = "hud";
+
+const HUD_WIDTH: f64 = 220.0;
+const HUD_HEIGHT: f64 = 96.0;
+const HUD_MARGIN: i32 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum HudPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct HudStatus {
+    pub process_count: u32,
+    pub optimized_count: u32,
+    /// Combined CPU usage of all ACE Guard processes, `None` until two samples were taken
+    pub cpu_usage: Option<f32>,
+}
+
+// State wrapper for the HUD cpu sampler
+#[derive(Default)]
+pub struct HudState(pub Mutex<CpuUsageSampler>);
+
+/// Show the HUD window in a corner of the given monitor, creating it if needed
+pub fn show_hud(
+    app: &AppHandle,
+    position: HudPosition,
+    monitor: Option<u32>,
+) -> Result<(), String> {
+    let window = match app.get_webview_window(HUD_WINDOW_LABEL) {
+        Some(window) => window,
+        None => WebviewWindowBuilder::new(app, HUD_WINDOW_LABEL, WebviewUrl::App("hud".into()))
+            .title("Tencent Ace Tools HUD")
+            .inner_size(HUD_WIDTH, HUD_HEIGHT)
+            .decorations(false)
+            .resizable(false)
+            .always_on_top(true)
+            .skip_taskbar(true)
+            .focused(false)
+            .shadow(false)
+            .build()
+            .map_err(|e| format!("Failed to create HUD window: {}", e))?,
+    };
+
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| format!("Failed to get monitors: {}", e))?;
+
+    let target_monitor = match monitor {
+        Some(index) => monitors.get(index as usize).cloned(),
+        None => app
+            .primary_monitor()
+            .map_err(|e| format!("Failed to get primary monitor: {}", e))?,
+    }
+    .ok_or_else(|| "Target monitor not found".to_string())?;
+
+    let window_size = window
+        .outer_size()
+        .map_err(|e| format!("Failed to get HUD window size: {}", e))?;
+
+    let work_area = target_monitor.work_area();
+    let left = work_area.position.x + HUD_MARGIN;
+    let top = work_area.position.y + HUD_MARGIN;
+    let right =
+        work_area.position.x + work_area.size.width as i32 - window_size.width as i32 - HUD_MARGIN;
+    let bottom = work_area.position.y + work_area.size.height as i32
+        - window_size.height as i32
+        - HUD_MARGIN;
+
+    let (x, y) = match position {
+        HudPosition::TopLeft => (left, top),
+        HudPosition::TopRight => (right, top),
+        HudPosition::BottomLeft => (left, bottom),
+        HudPosition::BottomRight => (right, bottom),
+    };
+
+    window
+        .set_position(PhysicalPosition::new(x, y))
+        .map_err(|e| format!("Failed to move HUD window: {}", e))?;
+    window
+        .show()
+        .map_err(|e| format!("Failed to show HUD window: {}", e))?;
+
+    tracing::debug!("HUD shown at {:?} ({}, {})", position, x, y);
+    Ok(())
+}
+
+/// Close the HUD window if it is open
+pub fn hide_hud(app: &AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(HUD_WINDOW_LABEL) {
+        window
+            .close()
+            .map_err(|e| format!("Failed to close HUD window: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Collect the data rendered by the HUD window
+pub fn get_hud_status(
+    controller_state: &AceProcessControllerState,
+    hud_state: &HudState,
+) -> Result<HudStatus, String> {
+    // polled every second by the HUD window, a scan under the controller lock would
+    // stall every other command, the process status watcher keeps the list current
+    let processes = controller_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire controller lock: {}", e))?
+        .get_processes()
+        .to_vec();
+
+    let mut sampler = hud_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire HUD lock: {}", e))?;
+
+    let process_ids: Vec<u32> = processes.iter().map(|p| p.process_id).collect();
+    sampler.retain(&process_ids);
+
+    let samples: Vec<Option<f32>> = process_ids.iter().map(|&pid| sampler.sample(pid)).collect();
+    let cpu_usage = if samples.is_empty() || samples.iter().any(|s| s.is_none()) {
+        None
+    } else {
+        Some(samples.into_iter().flatten().sum())
+    };
+
+    Ok(HudStatus {
+        process_count: processes.len() as u32,
+        optimized_count: processes.iter().filter(|p| p.is_optimized).count() as u32,
+        cpu_usage,
+    })
+}
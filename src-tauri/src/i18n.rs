@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Languages of the backend texts, the frontend messages use the same tags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Type)]
+pub enum Language {
+    /// Simplified Chinese, most players of the ACE protected games
+    #[default]
+    ZhCn,
+    En,
+}
+
+static CURRENT_LANGUAGE: AtomicU8 = AtomicU8::new(Language::ZhCn as u8);
+
+impl Language {
+    /// Locale tag as used in config.toml and by the web frontend
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::ZhCn => "zh-cn",
+            Self::En => "en",
+        }
+    }
+
+    /// Parse a locale tag like `zh-CN`, `zh-Hans-CN` or `en-US`
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?.to_ascii_lowercase();
+        match primary.as_str() {
+            "zh" => Some(Self::ZhCn),
+            "en" => Some(Self::En),
+            _ => None,
+        }
+    }
+}
+
+/// Language of the user locale, Chinese locales map to Chinese and everything else to English
+pub fn system_language() -> Language {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Globalization::GetUserDefaultLocaleName;
+
+        // LOCALE_NAME_MAX_LENGTH
+        let mut buffer = [0u16; 85];
+        let length = unsafe { GetUserDefaultLocaleName(&mut buffer) };
+        if length > 1 {
+            let locale = String::from_utf16_lossy(&buffer[..length as usize - 1]);
+            return Language::from_tag(&locale).unwrap_or(Language::En);
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        if let Ok(locale) = std::env::var("LANG") {
+            return Language::from_tag(&locale).unwrap_or(Language::En);
+        }
+    }
+
+    Language::default()
+}
+
+pub fn current_language() -> Language {
+    match CURRENT_LANGUAGE.load(Ordering::Relaxed) {
+        x if x == Language::En as u8 => Language::En,
+        _ => Language::ZhCn,
+    }
+}
+
+/// Switch the language of backend texts, takes effect for the next text shown
+pub fn set_language(language: Language) {
+    tracing::debug!("Language set to {}", language.tag());
+    CURRENT_LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_tag() {
+        assert_eq!(Language::from_tag("zh-CN"), Some(Language::ZhCn));
+        assert_eq!(Language::from_tag("zh_Hans_CN"), Some(Language::ZhCn));
+        assert_eq!(Language::from_tag("en-US"), Some(Language::En));
+        assert_eq!(Language::from_tag("de-DE"), None);
+    }
+
+    #[test]
+    fn test_tag_round_trip() {
+        for language in [Language::ZhCn, Language::En] {
+            assert_eq!(Language::from_tag(language.tag()), Some(language));
+        }
+    }
+}
@@ -1,7 +1,18 @@
+use crate::hotkeys::{HotkeyCaptureEvent, HotkeyEvent};
 use crate::logging::LogEvent;
+use crate::updates::UpdateDownloadProgress;
+use crate::windows::cpu_alert::CpuAlertEvent;
+use crate::windows::monitor::ProcessUsageEvent;
+use crate::windows::ace_tools::OptimizationProgress;
+use crate::windows::auto_optimize::GameLaunchEvent;
+use crate::windows::background_pause::BackgroundPauseEvent;
+use crate::windows::scheduler::ScheduleRuleEvent;
+use crate::windows::status_events::ProcessStatusEvent;
+use crate::windows::watchdog::WatchdogEvent;
 use specta_typescript::BigIntExportBehavior;
 use specta_typescript::Typescript;
 use std::{io, path::Path, process::Command};
+use tauri::Manager;
 use tauri_specta::{collect_commands, collect_events, Builder};
 
 pub mod command;
@@ -9,6 +20,37 @@ use command::*;
 
 pub mod logging;
 
+pub mod cleanup;
+
+pub mod hotkeys;
+
+pub mod history;
+
+pub mod hud;
+
+pub mod i18n;
+
+pub mod notifications;
+
+pub mod report;
+
+pub mod session_report;
+
+pub mod status_summary;
+
+pub mod tray;
+
+pub mod updates;
+
+#[cfg(target_os = "windows")]
+pub mod config;
+
+#[cfg(target_os = "windows")]
+pub mod window_state;
+
+#[cfg(target_os = "windows")]
+pub mod region_picker;
+
 #[cfg(target_os = "windows")]
 pub mod windows;
 
@@ -18,21 +60,153 @@ pub mod consts;
 pub fn app_run() {
     logging::init_logging();
 
+    // the elevated instance takes over, nothing left to do here
+    #[cfg(not(debug_assertions))]
+    if windows::elevation::ensure_elevated_on_startup() {
+        return;
+    }
+
     let command_builder = Builder::<tauri::Wry>::new()
         // Then register them (separated by a comma)
         .commands(collect_commands![
             greet,
             is_running_as_admin,
+            restart_as_admin,
             get_all_ace_guard_processes,
+            get_ace_process_tree,
+            detect_anti_cheats,
+            get_optimized_anti_cheats,
+            set_anti_cheat_optimized,
             optimize_all_ace_guard_processes,
             get_controller_privileges_status,
             get_all_windows,
+            get_all_windows_with_thumbnails,
             try_capture_image_by_window_id,
+            capture_by_window_name,
+            capture_by_window_pattern,
+            capture_window_advanced,
+            capture_full_screen,
+            save_screenshot_to_file,
             ocr_screen_region,
             ocr_image_region,
+            ocr_window,
             ocr_full_screen,
+            get_screenshot_window,
+            set_screenshot_window,
+            capture_screenshot_window,
+            pick_screen_region,
+            submit_screen_region,
+            get_hotkey_bindings,
+            set_hotkey_bindings,
+            show_status_hud,
+            hide_status_hud,
+            get_hud_status,
+            get_status_summary,
+            get_timer_resolution_status,
+            set_timer_resolution_enabled,
+            get_cpu_alert_config,
+            set_cpu_alert_config,
+            get_cpu_alert_history,
+            get_ace_guard_usage_history,
+            get_process_usage,
+            get_ace_instance_settings,
+            set_ace_instance_settings,
+            get_affinity_strategy,
+            set_affinity_strategy,
+            get_affinity_processors,
+            set_affinity_processors,
+            get_notifications_enabled,
+            set_notifications_enabled,
+            query_history,
+            get_average_cpu_usage,
+            export_process_report,
+            export_session_report,
+            get_log_history,
+            query_logs,
+            export_logs,
+            copy_to_clipboard,
+            copy_logs_to_clipboard,
+            copy_screenshot_to_clipboard,
+            benchmark_process_scan,
+            get_target_patterns,
+            set_target_patterns,
+            get_game_mode_status,
+            get_power_diagnostics,
+            get_system_info,
+            get_app_info,
+            check_for_updates,
+            install_update,
+            cleanup_installation,
+            restore_all_ace_guard_processes,
+            revert_all_ace_guard_processes,
+            get_target_priority,
+            set_target_priority,
+            get_watchdog_config,
+            set_watchdog_config,
+            get_lower_io_priority,
+            set_lower_io_priority,
+            get_lower_memory_priority,
+            set_lower_memory_priority,
+            get_efficiency_mode,
+            set_efficiency_mode,
+            is_efficiency_mode_supported,
+            get_trim_working_set,
+            set_trim_working_set,
+            optimize_ace_guard_process,
+            restore_ace_guard_process,
+            suspend_ace_guard_process,
+            resume_ace_guard_process,
+            get_cpu_topology,
+            get_game_boost_config,
+            set_game_boost_config,
+            boost_game_processes,
+            revert_game_boost,
+            get_saved_process_states,
+            get_cpu_rate_limit,
+            set_cpu_rate_limit,
+            get_lower_thread_priority,
+            set_lower_thread_priority,
+            get_require_trusted_signature,
+            set_require_trusted_signature,
+            get_auto_optimize_config,
+            set_auto_optimize_config,
+            get_process_status_config,
+            set_process_status_config,
+            get_ace_service_status,
+            get_ace_installation_info,
+            install_logon_task,
+            uninstall_logon_task,
+            is_logon_task_installed,
+            get_autostart_enabled,
+            set_autostart_enabled,
+            get_lower_gpu_priority,
+            set_lower_gpu_priority,
+            get_throttle_mode,
+            set_throttle_mode,
+            get_settings,
+            set_settings,
+            get_background_pause_status,
+            pause_background_tasks,
+            resume_background_tasks,
+            get_schedule_rules,
+            set_schedule_rules,
+            get_theme,
+            set_theme,
         ])
-        .events(collect_events![LogEvent,]);
+        .events(collect_events![
+            LogEvent,
+            HotkeyEvent,
+            HotkeyCaptureEvent,
+            CpuAlertEvent,
+            WatchdogEvent,
+            GameLaunchEvent,
+            ProcessStatusEvent,
+            ProcessUsageEvent,
+            OptimizationProgress,
+            BackgroundPauseEvent,
+            ScheduleRuleEvent,
+            UpdateDownloadProgress,
+        ]);
 
     #[cfg(debug_assertions)]
     command_builder
@@ -53,17 +227,179 @@ pub fn app_run() {
         )
         .expect("Failed to export typescript bindings");
 
+    let typed_handler = command_builder.invoke_handler();
+    let binary_handler = tauri::generate_handler![
+        capture_window_png,
+        capture_window_png_by_name,
+        capture_window_png_by_pattern,
+        capture_full_screen_png,
+    ];
+
     tauri::Builder::default()
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_opener::init())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(hotkeys::handle_shortcut)
+                .build(),
+        )
         .manage(windows::AceProcessControllerState::default())
-        .invoke_handler(command_builder.invoke_handler())
+        .manage(hotkeys::HotkeyState::default())
+        .manage(hud::HudState::default())
+        .manage(region_picker::RegionPickerState::default())
+        .manage(notifications::NotificationState::default())
+        .manage(history::HistoryState::default())
+        .manage(windows::timer_resolution::TimerResolutionState::default())
+        .manage(windows::cpu_alert::CpuAlertState::default())
+        .manage(windows::monitor::UsageMonitorState::default())
+        .manage(windows::usage::ProcessUsageSamplerState::default())
+        .manage(windows::watchdog::WatchdogState::default())
+        .manage(windows::game_boost::GameBoostState::default())
+        .manage(windows::auto_optimize::AutoOptimizeState::default())
+        .manage(windows::status_events::ProcessStatusState::default())
+        .manage(windows::background_pause::BackgroundPauseState::default())
+        .manage(windows::scheduler::SchedulerState::default())
+        .invoke_handler(move |invoke| {
+            if BINARY_COMMANDS.contains(&invoke.message.command()) {
+                binary_handler(invoke)
+            } else {
+                typed_handler(invoke)
+            }
+        })
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                if window.label() == window_state::MAIN_WINDOW_LABEL {
+                    if let Some(window) = window.get_webview_window(window.label()) {
+                        if let Err(e) = window_state::save(&window) {
+                            tracing::warn!("Failed to save window geometry: {}", e);
+                        }
+                    }
+
+                    // keep running in the tray, Quit in the tray menu exits
+                    if tray::close_to_tray() {
+                        api.prevent_close();
+                        let _ = window.hide();
+                    }
+                }
+            }
+        })
         .setup(move |app| {
             // This is also required if you want to use events
             command_builder.mount_events(app);
 
             // set app handle via once lock
             let _ = consts::TAURI_APP_HANDLE.set(app.handle().clone());
+            let _ = consts::SESSION_STARTED_AT.set(chrono::Utc::now());
+
+            // settings shared with the CLI, earlier versions kept the targets separately
+            windows::target_config::migrate_legacy_config(app.handle());
+            let settings = config::load_or_default();
+            i18n::set_language(settings.language());
+            logging::set_log_buffer_capacity(settings.log_buffer_capacity as usize);
+            tray::set_close_to_tray(settings.close_to_tray);
+            let status_state = app.state::<windows::status_events::ProcessStatusState>();
+            if let Ok(mut watcher) = status_state.0.lock() {
+                watcher.set_config(settings.process_status.clone());
+            }
+            if let Ok(mut scheduler) = app.state::<windows::scheduler::SchedulerState>().0.lock() {
+                scheduler.set_rules(settings.schedule.clone());
+            }
+            if let Ok(mut watchdog) = app.state::<windows::watchdog::WatchdogState>().0.lock() {
+                watchdog.set_config(settings.watchdog.clone());
+            }
+            if let Ok(mut boost) = app.state::<windows::game_boost::GameBoostState>().0.lock() {
+                boost.set_config(settings.game_boost.clone());
+            }
+            if let Ok(mut monitor) = app.state::<windows::cpu_alert::CpuAlertState>().0.lock() {
+                monitor.set_config(settings.cpu_alert.clone());
+            }
+
+            if let Some(window) = app.get_webview_window(window_state::MAIN_WINDOW_LABEL) {
+                if let Err(e) = window_state::restore(&window, &settings) {
+                    tracing::warn!("Failed to restore window geometry: {}", e);
+                }
+                if let Err(e) = window.set_theme(settings.theme.window_theme()) {
+                    tracing::warn!("Failed to set window theme: {}", e);
+                }
+
+                // started from the Run key at logon, stay out of the way
+                if windows::autostart::is_started_minimized() {
+                    let _ = window.minimize();
+                }
+            }
+
+            // open the history database in the app data directory
+            match app.path().app_data_dir() {
+                Ok(dir) => {
+                    let path = dir.join(history::HISTORY_DATABASE_FILE);
+                    match history::HistoryStore::open(&path) {
+                        Ok(store) => {
+                            if let Ok(mut guard) = app.state::<history::HistoryState>().0.lock() {
+                                *guard = Some(store);
+                            }
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to resolve app data directory: {}", e),
+            }
+
+            {
+                let state = app.state::<windows::AceProcessControllerState>();
+                if let Ok(mut controller) = state.0.lock() {
+                    controller.set_target_priority(settings.priority);
+                    controller.set_affinity_strategy(settings.affinity_strategy);
+                    controller.set_throttle_mode(settings.throttle_mode);
+                    if let Err(e) = controller.set_target_patterns(settings.target_patterns.clone())
+                    {
+                        tracing::warn!("{}", e);
+                    }
+                }
+            }
+
+            // original process settings are kept on disk so a crash can still be reverted
+            match app.path().app_local_data_dir() {
+                Ok(dir) => {
+                    let state = app.state::<windows::AceProcessControllerState>();
+                    if let Ok(mut controller) = state.0.lock() {
+                        let path = dir.join(windows::saved_state::PROCESS_STATE_FILE);
+                        controller.set_state_file(path);
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to resolve app local data directory: {}", e),
+            }
+
+            // restore the state handed over by the unelevated instance
+            if let Some(handover) = windows::elevation::take_handover() {
+                let state = app.state::<windows::AceProcessControllerState>();
+                if let Ok(mut controller) = state.0.lock() {
+                    handover.apply(&mut controller);
+                    tracing::info!("Restored state from the unelevated instance");
+                }
+            }
+
+            // register the saved global shortcuts, a conflict should not prevent startup
+            let bindings = hotkeys::with_defaults(settings.hotkeys.clone());
+            if let Err(e) = hotkeys::apply_bindings(app.handle(), &bindings) {
+                tracing::warn!("Failed to register global shortcuts: {}", e);
+            }
+            if let Ok(mut guard) = app.state::<hotkeys::HotkeyState>().0.lock() {
+                *guard = bindings;
+            }
+
+            windows::timer_resolution::spawn_game_monitor(app.handle().clone());
+            windows::cpu_alert::spawn_cpu_alert_monitor(app.handle().clone());
+            windows::monitor::spawn_usage_monitor(app.handle().clone());
+            windows::watchdog::spawn_watchdog(app.handle().clone());
+            windows::auto_optimize::spawn_game_launch_detector(app.handle().clone());
+            windows::status_events::spawn_status_watcher(app.handle().clone());
+            windows::scheduler::run_logon_rules(app.handle());
+
+            if let Err(e) = tray::create_tray(app.handle()) {
+                tracing::warn!("{}", e);
+            }
 
             Ok(())
         })
@@ -1,6 +1,16 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
 use tauri_specta::Event;
 use tracing::{field::Visit, Level, Subscriber};
 use tracing_subscriber::{
@@ -11,8 +21,8 @@ use tracing_subscriber::{
 
 use crate::consts::TAURI_APP_HANDLE;
 
-// Log level enum for TypeScript bindings
-#[derive(Debug, Clone, Serialize, Type)]
+// Log level enum for TypeScript bindings, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Type)]
 pub enum LogLevel {
     TRACE,
     DEBUG,
@@ -39,7 +49,7 @@ impl From<&Level> for LogLevel {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Type, Event)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
 pub struct LogEvent {
     pub level: LogLevel,
     pub target: String,
@@ -48,6 +58,182 @@ pub struct LogEvent {
     pub fields: std::collections::HashMap<String, String>,
 }
 
+impl LogEvent {
+    /// One line in the plain text export
+    pub fn to_line(&self) -> String {
+        let mut line = format!(
+            "{} {:?} {}: {}",
+            self.timestamp.to_rfc3339(),
+            self.level,
+            self.target,
+            self.message
+        );
+
+        let mut fields: Vec<_> = self.fields.iter().collect();
+        fields.sort();
+        for (key, value) in fields {
+            let _ = write!(line, " {}={}", key, value);
+        }
+
+        line
+    }
+}
+
+/// Default number of log events kept in memory since the app started
+pub const LOG_BUFFER_CAPACITY: usize = 1000;
+/// Upper bound of the configurable capacity, an event takes a few hundred bytes
+pub const MAX_LOG_BUFFER_CAPACITY: usize = 100_000;
+
+static LOG_BUFFER: Mutex<VecDeque<LogEvent>> = Mutex::new(VecDeque::new());
+static LOG_BUFFER_LIMIT: AtomicUsize = AtomicUsize::new(LOG_BUFFER_CAPACITY);
+
+fn trim_buffer(buffer: &mut VecDeque<LogEvent>, capacity: usize) {
+    while buffer.len() > capacity {
+        buffer.pop_front();
+    }
+}
+
+fn push_to_buffer(event: &LogEvent) {
+    let Ok(mut buffer) = LOG_BUFFER.lock() else {
+        return;
+    };
+
+    buffer.push_back(event.clone());
+    trim_buffer(&mut buffer, LOG_BUFFER_LIMIT.load(Ordering::Relaxed));
+}
+
+pub fn log_buffer_capacity() -> usize {
+    LOG_BUFFER_LIMIT.load(Ordering::Relaxed)
+}
+
+/// Change how many log events are kept, dropping the oldest ones when shrinking
+pub fn set_log_buffer_capacity(capacity: usize) {
+    let capacity = capacity.clamp(1, MAX_LOG_BUFFER_CAPACITY);
+    LOG_BUFFER_LIMIT.store(capacity, Ordering::Relaxed);
+
+    if let Ok(mut buffer) = LOG_BUFFER.lock() {
+        trim_buffer(&mut buffer, capacity);
+    }
+}
+
+/// All log events still in the in-memory buffer, oldest first
+pub fn buffered_logs() -> Vec<LogEvent> {
+    LOG_BUFFER
+        .lock()
+        .map(|buffer| buffer.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// The newest `limit` events at `min_level` or above, oldest first
+fn filter_history<'a>(
+    logs: impl DoubleEndedIterator<Item = &'a LogEvent>,
+    limit: Option<usize>,
+    min_level: Option<LogLevel>,
+) -> Vec<LogEvent> {
+    let mut history: Vec<LogEvent> = logs
+        .rev()
+        .filter(|log| min_level.is_none_or(|level| log.level >= level))
+        .take(limit.unwrap_or(usize::MAX))
+        .cloned()
+        .collect();
+
+    history.reverse();
+    history
+}
+
+/// Buffered log events for a frontend that missed the live events, e.g. mounted late
+pub fn log_history(limit: Option<usize>, min_level: Option<LogLevel>) -> Vec<LogEvent> {
+    LOG_BUFFER
+        .lock()
+        .map(|buffer| filter_history(buffer.iter(), limit, min_level))
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LogQuery {
+    /// Searched in the message, target and field values
+    pub pattern: String,
+    /// Treat `pattern` as a regular expression instead of a plain substring
+    pub regex: bool,
+    pub case_sensitive: bool,
+}
+
+impl LogQuery {
+    fn matcher(&self) -> Result<Regex, String> {
+        let pattern = if self.regex {
+            self.pattern.clone()
+        } else {
+            regex::escape(&self.pattern)
+        };
+
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .map_err(|e| format!("Invalid search pattern '{}': {}", self.pattern, e))
+    }
+}
+
+fn matches_log(matcher: &Regex, log: &LogEvent) -> bool {
+    matcher.is_match(&log.message)
+        || matcher.is_match(&log.target)
+        || log.fields.values().any(|value| matcher.is_match(value))
+}
+
+/// Buffered log events matching the query, oldest first
+pub fn query_logs(query: &LogQuery) -> Result<Vec<LogEvent>, String> {
+    let matcher = query.matcher()?;
+
+    Ok(LOG_BUFFER
+        .lock()
+        .map(|buffer| {
+            buffer
+                .iter()
+                .filter(|log| matches_log(&matcher, log))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum LogExportFormat {
+    Text,
+    /// One JSON encoded `LogEvent` per line
+    JsonLines,
+}
+
+impl LogExportFormat {
+    /// Pick the format from the file extension, plain text unless it is a `.jsonl` file
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("jsonl") => Self::JsonLines,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// Write the whole in-memory log buffer to `path`, returns the number of exported events
+pub fn export_logs(path: &Path) -> Result<usize, String> {
+    let logs = buffered_logs();
+    let format = LogExportFormat::from_path(path);
+
+    let mut content = String::new();
+    for log in &logs {
+        let line = match format {
+            LogExportFormat::Text => log.to_line(),
+            LogExportFormat::JsonLines => serde_json::to_string(log)
+                .map_err(|e| format!("Failed to serialize log event: {}", e))?,
+        };
+        content.push_str(&line);
+        content.push('\n');
+    }
+
+    std::fs::write(path, content)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    Ok(logs.len())
+}
+
 struct TauriEventLayer;
 
 struct LogFieldVisitor {
@@ -89,22 +275,25 @@ where
     S: Subscriber,
 {
     fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
-        if let Some(app_handle) = TAURI_APP_HANDLE.get() {
-            let mut visitor = LogFieldVisitor::new();
-            event.record(&mut visitor);
+        let mut visitor = LogFieldVisitor::new();
+        event.record(&mut visitor);
 
-            let log_event = LogEvent {
-                level: LogLevel::from(event.metadata().level()),
-                target: event.metadata().target().to_string(),
-                message: visitor.message,
-                timestamp: Utc::now(),
-                fields: visitor.fields,
-            };
+        let log_event = LogEvent {
+            level: LogLevel::from(event.metadata().level()),
+            target: event.metadata().target().to_string(),
+            message: visitor.message,
+            timestamp: Utc::now(),
+            fields: visitor.fields,
+        };
+
+        // buffer startup events as well, the frontend reads them from the history
+        push_to_buffer(&log_event);
 
+        // The TAURI_APP_HANDLE will be set once the app is properly initialized,
+        // logging a warning about it here would recurse into this layer
+        if let Some(app_handle) = TAURI_APP_HANDLE.get() {
             log_event.emit(app_handle).unwrap();
         }
-        // Remove the warning log to prevent infinite recursion
-        // The TAURI_APP_HANDLE will be set once the app is properly initialized
     }
 }
 
@@ -130,3 +319,113 @@ pub fn init_logging() {
             .ok();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_format_from_path() {
+        assert_eq!(
+            LogExportFormat::from_path(Path::new("logs.JSONL")),
+            LogExportFormat::JsonLines
+        );
+        assert_eq!(
+            LogExportFormat::from_path(Path::new("logs.log")),
+            LogExportFormat::Text
+        );
+    }
+
+    #[test]
+    fn test_trim_buffer_drops_the_oldest_events() {
+        let mut buffer: VecDeque<LogEvent> = (0..5)
+            .map(|i| LogEvent {
+                level: LogLevel::INFO,
+                target: "test".to_string(),
+                message: i.to_string(),
+                timestamp: Utc::now(),
+                fields: Default::default(),
+            })
+            .collect();
+
+        trim_buffer(&mut buffer, 2);
+        let messages: Vec<_> = buffer.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, ["3", "4"]);
+    }
+
+    #[test]
+    fn test_filter_history_keeps_the_newest_events() {
+        let logs: Vec<LogEvent> = [
+            LogLevel::WARN,
+            LogLevel::DEBUG,
+            LogLevel::ERROR,
+            LogLevel::INFO,
+            LogLevel::WARN,
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, level)| LogEvent {
+            level,
+            target: "test".to_string(),
+            message: i.to_string(),
+            timestamp: Utc::now(),
+            fields: Default::default(),
+        })
+        .collect();
+        let messages = |limit, min_level| {
+            filter_history(logs.iter(), limit, min_level)
+                .into_iter()
+                .map(|e| e.message)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(messages(None, None), ["0", "1", "2", "3", "4"]);
+        assert_eq!(messages(Some(2), None), ["3", "4"]);
+        assert_eq!(messages(None, Some(LogLevel::WARN)), ["0", "2", "4"]);
+        assert_eq!(messages(Some(2), Some(LogLevel::WARN)), ["2", "4"]);
+    }
+
+    #[test]
+    fn test_log_query_matches_substring_and_regex() {
+        let log = LogEvent {
+            level: LogLevel::INFO,
+            target: "tencent_ace_tools_lib::windows::watchdog".to_string(),
+            message: "Process SGuard64.exe (PID: 42) optimized".to_string(),
+            timestamp: Utc::now(),
+            fields: [("game".to_string(), "Delta Force".to_string())].into(),
+        };
+        let query = |pattern: &str, regex: bool, case_sensitive: bool| LogQuery {
+            pattern: pattern.to_string(),
+            regex,
+            case_sensitive,
+        };
+
+        let matches = |q: LogQuery| matches_log(&q.matcher().unwrap(), &log);
+        assert!(matches(query("sguard64.exe", false, false)));
+        assert!(!matches(query("sguard64.exe", false, true)));
+        // the dot is literal in substring mode
+        assert!(!matches(query("PID. 42", false, false)));
+        assert!(matches(query(r"PID: \d+", true, true)));
+        assert!(matches(query("watchdog", false, true)));
+        assert!(matches(query("delta", false, false)));
+        assert!(query("(", true, false).matcher().is_err());
+    }
+
+    #[test]
+    fn test_to_line() {
+        let event = LogEvent {
+            level: LogLevel::WARN,
+            target: "tencent_ace_tools_lib::windows".to_string(),
+            message: "Access denied".to_string(),
+            timestamp: DateTime::parse_from_rfc3339("2025-08-01T12:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            fields: [("pid".to_string(), "42".to_string())].into(),
+        };
+
+        assert_eq!(
+            event.to_line(),
+            "2025-08-01T12:00:00+00:00 WARN tencent_ace_tools_lib::windows: Access denied pid=42"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use crate::i18n::{self, Language};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum NotificationKind {
+    /// Optimization was applied without user interaction, e.g. after a game launch
+    OptimizationApplied,
+    /// ACE restored its own priority or affinity and settings were re-applied
+    SettingsReverted,
+    OptimizationFailed,
+    ScreenshotSaved,
+    TextRecognized,
+}
+
+impl NotificationKind {
+    fn title(&self, language: Language) -> &'static str {
+        match (self, language) {
+            (NotificationKind::OptimizationApplied, Language::En) => "ACE Guard optimized",
+            (NotificationKind::OptimizationApplied, Language::ZhCn) => "ACE Guard 已优化",
+            (NotificationKind::SettingsReverted, Language::En) => "ACE Guard reverted settings",
+            (NotificationKind::SettingsReverted, Language::ZhCn) => "ACE Guard 已还原设置",
+            (NotificationKind::OptimizationFailed, Language::En) => "ACE Guard optimization failed",
+            (NotificationKind::OptimizationFailed, Language::ZhCn) => "ACE Guard 优化失败",
+            (NotificationKind::ScreenshotSaved, Language::En) => "Screenshot saved",
+            (NotificationKind::ScreenshotSaved, Language::ZhCn) => "截图已保存",
+            (NotificationKind::TextRecognized, Language::En) => "Text recognized",
+            (NotificationKind::TextRecognized, Language::ZhCn) => "文字识别完成",
+        }
+    }
+}
+
+// State wrapper for the desktop notification toggle
+pub struct NotificationState(pub AtomicBool);
+
+impl Default for NotificationState {
+    fn default() -> Self {
+        Self(AtomicBool::new(true))
+    }
+}
+
+impl NotificationState {
+    pub fn is_enabled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.0.store(enabled, Ordering::Relaxed);
+    }
+}
+
+/// Show a native toast notification, works without any open window
+pub fn notify(app: &AppHandle, kind: NotificationKind, body: &str) {
+    if !app.state::<NotificationState>().is_enabled() {
+        return;
+    }
+
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title(kind.title(i18n::current_language()))
+        .body(body)
+        .show()
+    {
+        tracing::warn!("Failed to show notification: {}", e);
+    }
+}
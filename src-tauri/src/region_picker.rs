@@ -0,0 +1,147 @@
+use crate::windows::{
+    ocr::{self, OcrRegion, OcrResponse},
+    screenshot::{ScreenShot, ScreenshotCapture},
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{
+    sync::{mpsc, Mutex},
+    time::Duration,
+};
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+
+pub const REGION_PICKER_WINDOW_LABEL: &str = "region-picker";
+
+/// The overlay cancels itself when nothing was picked in this time
+const SELECTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time for the compositor to remove the overlay before the display is captured
+const OVERLAY_CLOSE_DELAY: Duration = Duration::from_millis(200);
+
+/// A region picked on the primary display, with its capture and optionally its text
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RegionCapture {
+    pub region: OcrRegion,
+    pub screenshot: ScreenShot,
+    pub ocr: Option<OcrResponse>,
+}
+
+// State wrapper for the pending selection of the overlay
+#[derive(Default)]
+pub struct RegionPickerState(pub Mutex<Option<mpsc::Sender<Option<OcrRegion>>>>);
+
+/// Open the translucent overlay on the primary display, the returned channel
+/// receives the dragged region or `None` when the selection was cancelled
+fn open_overlay(app: &AppHandle) -> Result<mpsc::Receiver<Option<OcrRegion>>, String> {
+    let (sender, receiver) = mpsc::channel();
+
+    // a second pick replaces the first one, which then reads as cancelled
+    *app.state::<RegionPickerState>()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire region picker lock: {}", e))? = Some(sender);
+
+    if app.get_webview_window(REGION_PICKER_WINDOW_LABEL).is_some() {
+        return Ok(receiver);
+    }
+
+    let window = WebviewWindowBuilder::new(
+        app,
+        REGION_PICKER_WINDOW_LABEL,
+        WebviewUrl::App("region-picker".into()),
+    )
+    .title("Tencent Ace Tools Region Picker")
+    .fullscreen(true)
+    .decorations(false)
+    .transparent(true)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .shadow(false)
+    .build()
+    .map_err(|e| format!("Failed to create region picker window: {}", e))?;
+
+    // closing the overlay any other way counts as cancelling
+    let app_handle = app.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            let _ = submit_region(&app_handle, None);
+        }
+    });
+
+    Ok(receiver)
+}
+
+/// Hand the selection of the overlay to the waiting `pick_region` and close the overlay
+pub fn submit_region(app: &AppHandle, region: Option<OcrRegion>) -> Result<(), String> {
+    let sender = app
+        .state::<RegionPickerState>()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire region picker lock: {}", e))?
+        .take();
+
+    if let Some(sender) = sender {
+        let _ = sender.send(region);
+    }
+
+    if let Some(window) = app.get_webview_window(REGION_PICKER_WINDOW_LABEL) {
+        window
+            .close()
+            .map_err(|e| format!("Failed to close region picker window: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Let the user drag a region on the primary display and capture it,
+/// `None` when the selection was cancelled
+pub async fn pick_region(app: &AppHandle, run_ocr: bool) -> Result<Option<RegionCapture>, String> {
+    let receiver = open_overlay(app)?;
+
+    let region =
+        tauri::async_runtime::spawn_blocking(move || receiver.recv_timeout(SELECTION_TIMEOUT))
+            .await
+            .map_err(|e| format!("Region picker task failed: {}", e))?;
+    let region = match region {
+        Ok(region) => region,
+        // the overlay still waits for us, close it like Esc would
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            tracing::debug!("Region selection timed out");
+            submit_region(app, None)?;
+            None
+        }
+        // replaced by a newer pick
+        Err(mpsc::RecvTimeoutError::Disconnected) => None,
+    };
+    let Some(region) = region else {
+        tracing::debug!("Region selection cancelled");
+        return Ok(None);
+    };
+
+    if region.x < 0 || region.y < 0 || region.width <= 0 || region.height <= 0 {
+        return Err(format!("Invalid region: {:?}", region));
+    }
+
+    let _ = tauri::async_runtime::spawn_blocking(|| std::thread::sleep(OVERLAY_CLOSE_DELAY)).await;
+
+    let display = ScreenshotCapture::capture_display()?;
+    let screenshot = ScreenshotCapture::crop(
+        &display,
+        region.x as u32,
+        region.y as u32,
+        region.width as u32,
+        region.height as u32,
+    )?;
+    let ocr = if run_ocr {
+        Some(ocr::ocr_image_region(&display.image_data, region.clone())?)
+    } else {
+        None
+    };
+
+    tracing::debug!("Captured region {:?}", region);
+    Ok(Some(RegionCapture {
+        region,
+        screenshot,
+        ocr,
+    }))
+}
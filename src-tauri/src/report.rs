@@ -0,0 +1,298 @@
+use crate::windows::{
+    ace_tools::{AceInstanceSettings, ProcessInfo},
+    affinity::AffinityStrategy,
+    game_mode::{get_game_mode_status, GameModeStatus},
+    power::{get_power_diagnostics, PowerDiagnostics},
+    priority::TargetPriority,
+    system_info::{get_system_info, SystemInfo},
+    topology::{get_cpu_topology, CpuTopology},
+    AceProcessControllerState,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{collections::HashMap, fmt::Write, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// Snapshot of everything useful for a bug report
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProcessReport {
+    pub app_version: String,
+    pub generated_at: DateTime<Utc>,
+    pub system: SystemInfo,
+    pub privileges_enabled: bool,
+    pub affinity_strategy: AffinityStrategy,
+    pub target_priority: TargetPriority,
+    pub instance_settings: HashMap<String, AceInstanceSettings>,
+    /// `None` when the topology could not be detected
+    pub cpu_topology: Option<CpuTopology>,
+    pub game_mode: GameModeStatus,
+    pub power: PowerDiagnostics,
+    pub processes: Vec<ProcessInfo>,
+}
+
+impl ProcessReport {
+    /// Rescan the ACE processes and collect the report data
+    pub fn collect(
+        controller_state: &AceProcessControllerState,
+        app_version: String,
+    ) -> Result<Self, String> {
+        let mut controller = controller_state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+        // an empty scan still produces a useful report
+        let _ = controller.scan_ace_guard_processes();
+
+        let cpu_topology = get_cpu_topology()
+            .inspect_err(|e| tracing::warn!("{}", e))
+            .ok();
+
+        Ok(Self {
+            app_version,
+            generated_at: Utc::now(),
+            system: get_system_info(),
+            privileges_enabled: controller.get_privileges_enabled(),
+            affinity_strategy: controller.get_affinity_strategy(),
+            target_priority: controller.get_target_priority(),
+            instance_settings: controller.get_instance_settings(),
+            cpu_topology,
+            game_mode: get_game_mode_status(),
+            power: get_power_diagnostics(),
+            processes: controller.get_processes().to_vec(),
+        })
+    }
+
+    pub fn render(&self, format: ReportFormat) -> Result<String, String> {
+        match format {
+            ReportFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| format!("Failed to serialize report: {}", e)),
+            ReportFormat::Csv => Ok(self.render_csv()),
+            ReportFormat::Markdown => Ok(self.render_markdown()),
+        }
+    }
+
+    /// Write the rendered report to `path`
+    pub fn export(&self, format: ReportFormat, path: &Path) -> Result<(), String> {
+        let content = self.render(format)?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write report: {}", e))
+    }
+
+    fn summary_rows(&self) -> Vec<(&'static str, String)> {
+        let mut rows = vec![
+            ("App version", self.app_version.clone()),
+            ("Generated at", self.generated_at.to_rfc3339()),
+            (
+                "OS",
+                format!("{} (build {})", self.system.os_name, self.system.os_build),
+            ),
+            ("CPU", self.system.cpu_model.clone()),
+            (
+                "Cores / threads",
+                format!(
+                    "{} / {}",
+                    self.system.physical_cores, self.system.logical_processors
+                ),
+            ),
+            (
+                "Memory",
+                format!("{} MiB", self.system.total_memory / (1024 * 1024)),
+            ),
+            (
+                "GPU",
+                self.system
+                    .gpu_name
+                    .clone()
+                    .unwrap_or_else(|| "unknown".to_string()),
+            ),
+            ("Elevated", self.system.is_elevated.to_string()),
+            (
+                "Enabled privileges",
+                self.system.enabled_privileges.join(" "),
+            ),
+            ("Privileges enabled", self.privileges_enabled.to_string()),
+            ("Affinity strategy", format!("{:?}", self.affinity_strategy)),
+            ("Target priority", format!("{:?}", self.target_priority)),
+        ];
+
+        match &self.cpu_topology {
+            Some(topology) => {
+                rows.push((
+                    "Logical processors",
+                    topology.logical_processor_count.to_string(),
+                ));
+                rows.push(("Physical cores", topology.cores.len().to_string()));
+                rows.push(("L3 caches", topology.l3_caches.len().to_string()));
+                rows.push(("Hybrid CPU", topology.is_hybrid.to_string()));
+            }
+            None => rows.push(("CPU topology", "unavailable".to_string())),
+        }
+
+        rows.push((
+            "Core parking min cores",
+            self.power
+                .core_parking_min_cores_percent
+                .map(|percent| format!("{}%", percent))
+                .unwrap_or_else(|| "unknown".to_string()),
+        ));
+        rows.push((
+            "Boost mode",
+            self.power
+                .boost_mode
+                .map(|mode| format!("{:?}", mode))
+                .unwrap_or_else(|| "unknown".to_string()),
+        ));
+        if self.power.core_parking_enabled()
+            && matches!(
+                self.affinity_strategy,
+                AffinityStrategy::LastCore | AffinityStrategy::LastTwoCores
+            )
+        {
+            rows.push((
+                "Core parking warning",
+                "Core parking is enabled, the last cores may be parked which makes \
+                 last core pinning ineffective"
+                    .to_string(),
+            ));
+        }
+
+        rows.push(("Game Mode enabled", self.game_mode.enabled.to_string()));
+        rows.push((
+            "Foreground recognized as game",
+            self.game_mode.foreground_recognized.to_string(),
+        ));
+        if self.game_mode.enabled {
+            // Game Mode boosts the foreground game, our changes only touch the ACE processes
+            // so both work together, but pinning ACE to a core Game Mode reserves for the game
+            // can move the contention instead of removing it
+            rows.push((
+                "Game Mode interaction",
+                "Game Mode prioritizes the foreground game; ACE priority and affinity changes \
+                 stack with it. Prefer an affinity strategy that avoids the game's busiest cores."
+                    .to_string(),
+            ));
+        }
+
+        let mut games: Vec<_> = self.instance_settings.iter().collect();
+        games.sort_by(|a, b| a.0.cmp(b.0));
+        for (game, settings) in games {
+            rows.push((
+                "Instance settings",
+                format!(
+                    "{}: enabled {}, {:?}",
+                    game, settings.enabled, settings.throttle_mode
+                ),
+            ));
+        }
+
+        rows
+    }
+
+    fn process_rows(&self) -> Vec<[String; 9]> {
+        self.processes
+            .iter()
+            .map(|p| {
+                [
+                    p.process_id.to_string(),
+                    p.process_name.clone(),
+                    p.process_path.clone(),
+                    p.associated_game.clone().unwrap_or_default(),
+                    p.current_priority.clone(),
+                    p.current_affinity.clone(),
+                    p.is_optimized.to_string(),
+                    format!("{:?}", p.signature_status),
+                    p.signer.clone().unwrap_or_default(),
+                ]
+            })
+            .collect()
+    }
+
+    fn render_csv(&self) -> String {
+        let mut output = String::from("key,value\n");
+        for (key, value) in self.summary_rows() {
+            let _ = writeln!(output, "{},{}", escape_csv(key), escape_csv(&value));
+        }
+
+        output.push('\n');
+        output.push_str(&PROCESS_COLUMNS.join(","));
+        output.push('\n');
+        for row in self.process_rows() {
+            let fields: Vec<String> = row.iter().map(|f| escape_csv(f)).collect();
+            output.push_str(&fields.join(","));
+            output.push('\n');
+        }
+
+        output
+    }
+
+    fn render_markdown(&self) -> String {
+        let mut output =
+            String::from("# Tencent Ace Tools Report\n\n| Key | Value |\n| --- | --- |\n");
+        for (key, value) in self.summary_rows() {
+            let _ = writeln!(output, "| {} | {} |", key, escape_markdown(&value));
+        }
+
+        output.push_str("\n## Processes\n\n");
+        if self.processes.is_empty() {
+            output.push_str("No ACE Guard processes found.\n");
+            return output;
+        }
+
+        let _ = writeln!(output, "| {} |", PROCESS_COLUMNS.join(" | "));
+        let _ = writeln!(output, "|{}", " --- |".repeat(PROCESS_COLUMNS.len()));
+        for row in self.process_rows() {
+            let fields: Vec<String> = row.iter().map(|f| escape_markdown(f)).collect();
+            let _ = writeln!(output, "| {} |", fields.join(" | "));
+        }
+
+        output
+    }
+}
+
+const PROCESS_COLUMNS: [&str; 9] = [
+    "PID",
+    "Name",
+    "Path",
+    "Game",
+    "Priority",
+    "Affinity",
+    "Optimized",
+    "Signature",
+    "Signer",
+];
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn escape_markdown(field: &str) -> String {
+    field.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_csv() {
+        assert_eq!(escape_csv("SGuard64.exe"), "SGuard64.exe");
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(escape_markdown("a|b\nc"), "a\\|b c");
+    }
+}
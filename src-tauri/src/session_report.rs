@@ -0,0 +1,248 @@
+use crate::{
+    consts,
+    windows::{
+        ace_tools::OriginalProcessSettings, monitor::UsageMonitorState, utils::priority_class_name,
+        AceProcessControllerState,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{fmt::Write, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum SessionReportFormat {
+    Json,
+    Html,
+}
+
+impl SessionReportFormat {
+    /// Pick the format from the file extension, JSON unless it is an HTML file
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(extension)
+                if extension.eq_ignore_ascii_case("html")
+                    || extension.eq_ignore_ascii_case("htm") =>
+            {
+                Self::Html
+            }
+            _ => Self::Json,
+        }
+    }
+}
+
+/// What happened to one ACE process during the session
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SessionProcess {
+    pub process_id: u32,
+    pub process_name: String,
+    pub associated_game: Option<String>,
+    pub original_settings: Option<OriginalProcessSettings>,
+    pub applied_settings: Option<OriginalProcessSettings>,
+    pub is_optimized: bool,
+    pub reset_count: u32,
+    /// Average CPU usage in percent before and after the optimization
+    pub average_cpu_unoptimized: Option<f32>,
+    pub average_cpu_optimized: Option<f32>,
+}
+
+/// Summary of the current session, from app start until the report is generated
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SessionReport {
+    pub app_version: String,
+    pub session_started_at: Option<DateTime<Utc>>,
+    pub generated_at: DateTime<Utc>,
+    pub processes: Vec<SessionProcess>,
+}
+
+impl SessionReport {
+    /// Rescan the ACE processes and combine them with the recorded usage
+    pub fn collect(
+        controller_state: &AceProcessControllerState,
+        usage_state: &UsageMonitorState,
+        app_version: String,
+    ) -> Result<Self, String> {
+        let mut controller = controller_state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+
+        // an empty scan still produces a useful report
+        let _ = controller.scan_ace_guard_processes();
+
+        let histories = usage_state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire usage monitor lock: {}", e))?
+            .get_history(None);
+
+        let processes = controller
+            .get_processes()
+            .iter()
+            .map(|p| {
+                let history = histories.iter().find(|h| h.process_id == p.process_id);
+                SessionProcess {
+                    process_id: p.process_id,
+                    process_name: p.process_name.clone(),
+                    associated_game: p.associated_game.clone(),
+                    original_settings: p.original_settings,
+                    applied_settings: p.applied_settings,
+                    is_optimized: p.is_optimized,
+                    reset_count: p.reset_count,
+                    average_cpu_unoptimized: history.and_then(|h| h.average_cpu_unoptimized),
+                    average_cpu_optimized: history.and_then(|h| h.average_cpu_optimized),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            app_version,
+            session_started_at: consts::SESSION_STARTED_AT.get().copied(),
+            generated_at: Utc::now(),
+            processes,
+        })
+    }
+
+    pub fn render(&self, format: SessionReportFormat) -> Result<String, String> {
+        match format {
+            SessionReportFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| format!("Failed to serialize session report: {}", e)),
+            SessionReportFormat::Html => Ok(self.render_html()),
+        }
+    }
+
+    /// Write the rendered report to `path`
+    pub fn export(&self, format: SessionReportFormat, path: &Path) -> Result<(), String> {
+        let content = self.render(format)?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write session report: {}", e))
+    }
+
+    fn render_html(&self) -> String {
+        let mut output = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Tencent Ace Tools Session Report</title>\n</head>\n<body>\n\
+             <h1>Tencent Ace Tools Session Report</h1>\n",
+        );
+
+        let _ = writeln!(
+            output,
+            "<p>Version {}, session from {} to {}</p>",
+            escape_html(&self.app_version),
+            self.session_started_at
+                .map(|time| time.to_rfc3339())
+                .unwrap_or_else(|| "unknown".to_string()),
+            self.generated_at.to_rfc3339()
+        );
+
+        if self.processes.is_empty() {
+            output.push_str("<p>No ACE Guard processes found.</p>\n</body>\n</html>\n");
+            return output;
+        }
+
+        output.push_str("<table border=\"1\">\n<tr>");
+        for column in PROCESS_COLUMNS {
+            let _ = write!(output, "<th>{}</th>", column);
+        }
+        output.push_str("</tr>\n");
+
+        for p in &self.processes {
+            let cells = [
+                p.process_id.to_string(),
+                p.process_name.clone(),
+                p.associated_game.clone().unwrap_or_default(),
+                format_settings(p.original_settings),
+                format_settings(p.applied_settings),
+                p.is_optimized.to_string(),
+                p.reset_count.to_string(),
+                format_cpu(p.average_cpu_unoptimized),
+                format_cpu(p.average_cpu_optimized),
+            ];
+
+            output.push_str("<tr>");
+            for cell in cells {
+                let _ = write!(output, "<td>{}</td>", escape_html(&cell));
+            }
+            output.push_str("</tr>\n");
+        }
+
+        output.push_str("</table>\n</body>\n</html>\n");
+        output
+    }
+}
+
+const PROCESS_COLUMNS: [&str; 9] = [
+    "PID",
+    "Name",
+    "Game",
+    "Original settings",
+    "Applied settings",
+    "Optimized",
+    "Resets",
+    "CPU before",
+    "CPU after",
+];
+
+fn format_settings(settings: Option<OriginalProcessSettings>) -> String {
+    settings
+        .map(|s| {
+            format!(
+                "{}, affinity {:#x}",
+                priority_class_name(s.priority_class),
+                s.affinity_mask
+            )
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_cpu(usage: Option<f32>) -> String {
+    usage
+        .map(|usage| format!("{:.1}%", usage))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<b>\"R&D\"</b>"),
+            "&lt;b&gt;&quot;R&amp;D&quot;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            SessionReportFormat::from_path(Path::new("session.HTML")),
+            SessionReportFormat::Html
+        );
+        assert_eq!(
+            SessionReportFormat::from_path(Path::new("session.json")),
+            SessionReportFormat::Json
+        );
+        assert_eq!(
+            SessionReportFormat::from_path(Path::new("session")),
+            SessionReportFormat::Json
+        );
+    }
+
+    #[test]
+    fn test_format_settings() {
+        let settings = OriginalProcessSettings {
+            priority_class: 0x40,
+            affinity_mask: 0x80,
+        };
+
+        assert_eq!(format_settings(Some(settings)), "IDLE, affinity 0x80");
+        assert_eq!(format_settings(None), "-");
+    }
+}
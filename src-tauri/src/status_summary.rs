@@ -0,0 +1,88 @@
+use crate::{
+    history::{HistoryEntry, HistoryKind, HistoryQuery, HistoryState},
+    windows::{
+        background_pause::BackgroundPauseState, utils::is_running_as_admin,
+        watchdog::WatchdogState, AceProcessControllerState,
+    },
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Everything shown in the status bar at the bottom of the main window
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct StatusSummary {
+    pub is_admin: bool,
+    pub process_count: u32,
+    pub optimized_count: u32,
+    pub watchdog_enabled: bool,
+    /// Background rescan and watchdog are paused from the UI
+    pub background_paused: bool,
+    /// Newest optimization or revert, `None` before the first one
+    pub last_action: Option<HistoryEntry>,
+}
+
+/// Summarize the current controller state, the process list is the one of the last scan
+pub fn get_status_summary(
+    controller_state: &AceProcessControllerState,
+    watchdog_state: &WatchdogState,
+    history_state: &HistoryState,
+    pause_state: &BackgroundPauseState,
+) -> Result<StatusSummary, String> {
+    let (process_count, optimized_count) = {
+        let controller = controller_state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire controller lock: {}", e))?;
+        let processes = controller.get_processes();
+
+        (
+            processes.len() as u32,
+            processes.iter().filter(|p| p.is_optimized).count() as u32,
+        )
+    };
+
+    let watchdog_enabled = watchdog_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire watchdog lock: {}", e))?
+        .config()
+        .enabled;
+
+    let background_paused = pause_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire background pause lock: {}", e))?
+        .status()
+        .paused;
+
+    Ok(StatusSummary {
+        is_admin: is_running_as_admin().unwrap_or(false),
+        process_count,
+        optimized_count,
+        watchdog_enabled,
+        background_paused,
+        last_action: last_action(history_state)?,
+    })
+}
+
+/// Scans are left out, the process list refreshes them all the time
+fn last_action(history_state: &HistoryState) -> Result<Option<HistoryEntry>, String> {
+    let guard = history_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire history lock: {}", e))?;
+    let Some(store) = guard.as_ref() else {
+        return Ok(None);
+    };
+
+    let mut entries = Vec::new();
+    for kind in [HistoryKind::Optimization, HistoryKind::Revert] {
+        entries.extend(store.query(&HistoryQuery {
+            kind: Some(kind),
+            limit: Some(1),
+            ..Default::default()
+        })?);
+    }
+
+    Ok(entries.into_iter().max_by_key(|entry| entry.timestamp))
+}
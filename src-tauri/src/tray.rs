@@ -0,0 +1,183 @@
+use crate::{
+    i18n::{self, Language},
+    notifications::{notify, NotificationKind},
+    window_state::MAIN_WINDOW_LABEL,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{
+    menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem},
+    tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Manager, WebviewWindow,
+};
+
+const TRAY_ID: &str = "main";
+
+/// Hide the main window instead of exiting when it is closed, mirrors the setting
+static CLOSE_TO_TRAY: AtomicBool = AtomicBool::new(true);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TrayAction {
+    Show,
+    Optimize,
+    Revert,
+    Quit,
+}
+
+impl TrayAction {
+    const ALL: [TrayAction; 4] = [
+        TrayAction::Show,
+        TrayAction::Optimize,
+        TrayAction::Revert,
+        TrayAction::Quit,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            TrayAction::Show => "show",
+            TrayAction::Optimize => "optimize",
+            TrayAction::Revert => "revert",
+            TrayAction::Quit => "quit",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.id() == id)
+    }
+
+    fn label(self, language: Language) -> &'static str {
+        match (self, language) {
+            (TrayAction::Show, Language::En) => "Show",
+            (TrayAction::Show, Language::ZhCn) => "显示主窗口",
+            (TrayAction::Optimize, Language::En) => "Optimize now",
+            (TrayAction::Optimize, Language::ZhCn) => "立即优化",
+            (TrayAction::Revert, Language::En) => "Revert",
+            (TrayAction::Revert, Language::ZhCn) => "还原",
+            (TrayAction::Quit, Language::En) => "Quit",
+            (TrayAction::Quit, Language::ZhCn) => "退出",
+        }
+    }
+}
+
+pub fn close_to_tray() -> bool {
+    CLOSE_TO_TRAY.load(Ordering::Relaxed)
+}
+
+pub fn set_close_to_tray(enabled: bool) {
+    CLOSE_TO_TRAY.store(enabled, Ordering::Relaxed);
+}
+
+fn build_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let language = i18n::current_language();
+    let item = |action: TrayAction| {
+        MenuItem::with_id(app, action.id(), action.label(language), true, None::<&str>)
+    };
+
+    Menu::with_items(
+        app,
+        &[
+            &item(TrayAction::Show)?,
+            &PredefinedMenuItem::separator(app)?,
+            &item(TrayAction::Optimize)?,
+            &item(TrayAction::Revert)?,
+            &PredefinedMenuItem::separator(app)?,
+            &item(TrayAction::Quit)?,
+        ],
+    )
+}
+
+/// Add the tray icon, the menu labels follow the language at startup
+pub fn create_tray(app: &AppHandle) -> Result<(), String> {
+    let menu = build_menu(app).map_err(|e| format!("Failed to build tray menu: {}", e))?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip("Tencent ACE Tools")
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(handle_menu_event)
+        .on_tray_icon_event(handle_tray_icon_event);
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+
+    builder
+        .build(app)
+        .map(|_| ())
+        .map_err(|e| format!("Failed to create tray icon: {}", e))
+}
+
+fn main_window(app: &AppHandle) -> Option<WebviewWindow> {
+    app.get_webview_window(MAIN_WINDOW_LABEL)
+}
+
+/// Bring the main window back from the tray or the taskbar
+pub fn show_main_window(app: &AppHandle) {
+    let Some(window) = main_window(app) else {
+        return;
+    };
+
+    let _ = window.show();
+    let _ = window.unminimize();
+    let _ = window.set_focus();
+}
+
+fn handle_tray_icon_event(tray: &TrayIcon, event: TrayIconEvent) {
+    if let TrayIconEvent::Click {
+        button: MouseButton::Left,
+        button_state: MouseButtonState::Up,
+        ..
+    } = event
+    {
+        show_main_window(tray.app_handle());
+    }
+}
+
+fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
+    let Some(action) = TrayAction::from_id(event.id().as_ref()) else {
+        return;
+    };
+    tracing::debug!("Tray menu {:?} clicked", action);
+
+    match action {
+        TrayAction::Show => show_main_window(app),
+        TrayAction::Optimize => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let result = crate::command::optimize_all_ace_guard_processes(
+                    app.clone(),
+                    app.state(),
+                    app.state(),
+                )
+                .await;
+                // the main window is usually hidden while the tray is used
+                match result {
+                    Ok(message) => notify(&app, NotificationKind::OptimizationApplied, &message),
+                    Err(e) => {
+                        tracing::warn!("Tray optimization failed: {}", e);
+                        notify(&app, NotificationKind::OptimizationFailed, &e);
+                    }
+                }
+            });
+        }
+        TrayAction::Revert => {
+            if let Err(e) =
+                crate::command::restore_all_ace_guard_processes(app.state(), app.state())
+            {
+                tracing::warn!("Tray revert failed: {}", e);
+            }
+        }
+        TrayAction::Quit => app.exit(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_ids_round_trip() {
+        for action in TrayAction::ALL {
+            assert_eq!(TrayAction::from_id(action.id()), Some(action));
+        }
+        assert_eq!(TrayAction::from_id("unknown"), None);
+    }
+}
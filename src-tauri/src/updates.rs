@@ -0,0 +1,298 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tauri::AppHandle;
+use tauri_specta::Event;
+
+/// Owner and name of the GitHub repository releases are published to
+pub const GITHUB_REPOSITORY: &str = "keiko233/tencent-ace-tools";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// The NSIS bundle is published as `<product>_<version>_x64-setup.exe`
+const INSTALLER_SUFFIX: &str = "-setup.exe";
+/// Emit a download progress event every this many bytes
+const PROGRESS_STEP: u64 = 1024 * 1024;
+const USER_AGENT: &str = concat!("tencent-ace-tools/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AppInfo {
+    pub name: String,
+    pub version: String,
+    pub repository: String,
+    /// `None` while the package does not declare a license
+    pub license: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ReleaseInfo {
+    pub version: String,
+    pub name: Option<String>,
+    /// Release page on GitHub
+    pub url: String,
+    pub published_at: Option<String>,
+    /// Release notes in Markdown
+    pub notes: Option<String>,
+    /// NSIS installer attached to the release, `None` when it has no Windows build
+    pub installer: Option<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub download_url: String,
+    /// Size in bytes
+    pub size: u64,
+}
+
+// Emitted while the installer of an update downloads
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct UpdateDownloadProgress {
+    pub downloaded: u64,
+    /// `None` when the server does not report the size
+    pub total: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateStatus {
+    pub current_version: String,
+    /// Newest published release, `None` when nothing was released yet
+    pub latest: Option<ReleaseInfo>,
+    pub update_available: bool,
+}
+
+/// Subset of the GitHub Releases API response
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    name: Option<String>,
+    html_url: String,
+    published_at: Option<String>,
+    body: Option<String>,
+    #[serde(default)]
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+    size: u64,
+}
+
+pub fn app_info() -> AppInfo {
+    let license = env!("CARGO_PKG_LICENSE");
+
+    AppInfo {
+        name: env!("CARGO_PKG_NAME").to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        repository: env!("CARGO_PKG_REPOSITORY").to_string(),
+        license: (!license.is_empty()).then(|| license.to_string()),
+    }
+}
+
+/// Parse `v1.2.3` or `1.2.3-beta.1` into its numeric parts, the pre-release suffix is ignored
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches(['v', 'V']);
+    let core = version.split(['-', '+']).next()?;
+
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+
+    Some((major, minor, patch))
+}
+
+fn is_newer(latest: &str, current: &str) -> bool {
+    match (parse_version(latest), parse_version(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+fn find_installer(assets: &[GithubAsset]) -> Option<&GithubAsset> {
+    assets
+        .iter()
+        .find(|asset| asset.name.to_lowercase().ends_with(INSTALLER_SUFFIX))
+}
+
+/// Ask the GitHub Releases API for the newest release, this blocks on the network
+pub fn check_for_updates() -> Result<UpdateStatus, String> {
+    let current_version = env!("CARGO_PKG_VERSION").to_string();
+    let url = format!(
+        "https://api.github.com/repos/{}/releases/latest",
+        GITHUB_REPOSITORY
+    );
+
+    let response = ureq::get(&url)
+        .set("Accept", "application/vnd.github+json")
+        // GitHub rejects API requests without a user agent
+        .set("User-Agent", USER_AGENT)
+        .timeout(REQUEST_TIMEOUT)
+        .call();
+
+    let release: GithubRelease = match response {
+        Ok(response) => response
+            .into_json()
+            .map_err(|e| format!("Failed to parse release info: {}", e))?,
+        // the repository has no published release yet
+        Err(ureq::Error::Status(404, _)) => {
+            return Ok(UpdateStatus {
+                current_version,
+                latest: None,
+                update_available: false,
+            })
+        }
+        Err(e) => return Err(format!("Failed to check for updates: {}", e)),
+    };
+
+    let update_available = is_newer(&release.tag_name, &current_version);
+    tracing::info!(
+        "Latest release is {}, running {}",
+        release.tag_name,
+        current_version
+    );
+
+    Ok(UpdateStatus {
+        current_version,
+        latest: Some(ReleaseInfo {
+            installer: find_installer(&release.assets).map(|asset| ReleaseAsset {
+                name: asset.name.clone(),
+                download_url: asset.browser_download_url.clone(),
+                size: asset.size,
+            }),
+            version: release.tag_name,
+            name: release.name,
+            url: release.html_url,
+            published_at: release.published_at,
+            notes: release.body,
+        }),
+        update_available,
+    })
+}
+
+/// Download the installer into `dir`, reporting the progress as `UpdateDownloadProgress`
+pub fn download_installer(
+    app: &AppHandle,
+    asset: &ReleaseAsset,
+    dir: &Path,
+) -> Result<PathBuf, String> {
+    let response = ureq::get(&asset.download_url)
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|e| format!("Failed to download {}: {}", asset.name, e))?;
+    let total = response
+        .header("Content-Length")
+        .and_then(|length| length.parse::<u64>().ok());
+
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    // the asset name comes from GitHub, keep only the file name part
+    let file_name = Path::new(&asset.name)
+        .file_name()
+        .ok_or_else(|| format!("Invalid installer name: {}", asset.name))?;
+    let path = dir.join(file_name);
+    let mut file = std::fs::File::create(&path)
+        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+
+    let mut reader = response.into_reader();
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut downloaded = 0u64;
+    let mut reported = 0u64;
+    loop {
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to download {}: {}", asset.name, e))?;
+        if read == 0 {
+            break;
+        }
+
+        file.write_all(&buffer[..read])
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        downloaded += read as u64;
+
+        if downloaded - reported >= PROGRESS_STEP {
+            reported = downloaded;
+            let _ = UpdateDownloadProgress { downloaded, total }.emit(app);
+        }
+    }
+    let _ = UpdateDownloadProgress { downloaded, total }.emit(app);
+
+    if total.is_some_and(|total| total != downloaded) {
+        return Err(format!(
+            "Download of {} stopped after {} bytes",
+            asset.name, downloaded
+        ));
+    }
+
+    tracing::info!("Downloaded {} to {}", asset.name, path.display());
+    Ok(path)
+}
+
+/// Download and start the installer of the newest release. The installer replaces the
+/// running binary, so the caller exits the app once this returns
+pub fn download_and_run_installer(app: &AppHandle) -> Result<String, String> {
+    let status = check_for_updates()?;
+    let release = match status.latest {
+        Some(release) if status.update_available => release,
+        _ => return Err(format!("Version {} is up to date", status.current_version)),
+    };
+    let installer = release
+        .installer
+        .as_ref()
+        .ok_or_else(|| format!("Release {} has no Windows installer", release.version))?;
+
+    let dir = std::env::temp_dir().join("tencent-ace-tools-update");
+    let path = download_installer(app, installer, &dir)?;
+
+    std::process::Command::new(&path)
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", path.display(), e))?;
+
+    tracing::info!("Started the installer of {}", release.version);
+    Ok(release.version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("v1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_version("0.2.0-beta.1"), Some((0, 2, 0)));
+        assert_eq!(parse_version("2.1"), Some((2, 1, 0)));
+        assert_eq!(parse_version("nightly"), None);
+    }
+
+    #[test]
+    fn test_find_installer() {
+        let asset = |name: &str| GithubAsset {
+            name: name.to_string(),
+            browser_download_url: format!("https://example.com/{}", name),
+            size: 1,
+        };
+        let assets = vec![
+            asset("tencent-ace-tools_0.2.0_x64.msi"),
+            asset("tencent-ace-tools_0.2.0_x64-setup.exe"),
+        ];
+
+        assert_eq!(
+            find_installer(&assets).map(|a| a.name.as_str()),
+            Some("tencent-ace-tools_0.2.0_x64-setup.exe")
+        );
+        assert!(find_installer(&assets[..1]).is_none());
+    }
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("v0.2.0", "0.1.0"));
+        assert!(is_newer("v0.1.10", "0.1.9"));
+        assert!(!is_newer("v0.1.0", "0.1.0"));
+        assert!(!is_newer("nightly", "0.1.0"));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::config::{self, Settings, WindowGeometry};
+use tauri::{Monitor, PhysicalPosition, PhysicalSize, WebviewWindow};
+
+/// Label of the window created from tauri.conf.json
+pub const MAIN_WINDOW_LABEL: &str = "main";
+
+/// Whether the title bar area of the geometry lies on one of the monitors,
+/// so a window saved on a disconnected monitor does not open off-screen
+fn is_on_screen(geometry: &WindowGeometry, monitors: &[(i32, i32, u32, u32)]) -> bool {
+    monitors.iter().any(|&(x, y, width, height)| {
+        geometry.x < x + width as i32
+            && geometry.x + geometry.width as i32 > x
+            && geometry.y >= y
+            && geometry.y < y + height as i32
+    })
+}
+
+fn monitor_rects(monitors: &[Monitor]) -> Vec<(i32, i32, u32, u32)> {
+    monitors
+        .iter()
+        .map(|m| {
+            (
+                m.position().x,
+                m.position().y,
+                m.size().width,
+                m.size().height,
+            )
+        })
+        .collect()
+}
+
+/// Move and resize the window to the geometry of the last session
+pub fn restore(window: &WebviewWindow, settings: &Settings) -> Result<(), String> {
+    let Some(geometry) = settings.window else {
+        return Ok(());
+    };
+
+    let monitors = window
+        .available_monitors()
+        .map_err(|e| format!("Failed to get monitors: {}", e))?;
+    if !is_on_screen(&geometry, &monitor_rects(&monitors)) {
+        tracing::debug!(
+            "Saved window geometry {:?} is off-screen, ignoring",
+            geometry
+        );
+        return Ok(());
+    }
+
+    window
+        .set_size(PhysicalSize::new(geometry.width, geometry.height))
+        .map_err(|e| format!("Failed to resize window: {}", e))?;
+    window
+        .set_position(PhysicalPosition::new(geometry.x, geometry.y))
+        .map_err(|e| format!("Failed to move window: {}", e))?;
+    if geometry.maximized {
+        window
+            .maximize()
+            .map_err(|e| format!("Failed to maximize window: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Write the current geometry of the window to the config
+pub fn save(window: &WebviewWindow) -> Result<(), String> {
+    // a minimized window reports a position far off-screen
+    if window.is_minimized().unwrap_or(false) {
+        return Ok(());
+    }
+
+    let maximized = window.is_maximized().unwrap_or(false);
+    let position = window
+        .outer_position()
+        .map_err(|e| format!("Failed to get window position: {}", e))?;
+    let size = window
+        .inner_size()
+        .map_err(|e| format!("Failed to get window size: {}", e))?;
+
+    config::update(|settings| {
+        settings.window = match (maximized, settings.window) {
+            // keep the restored size so unmaximizing after the next start still works
+            (true, Some(previous)) => Some(WindowGeometry {
+                maximized: true,
+                ..previous
+            }),
+            _ => Some(WindowGeometry {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized,
+            }),
+        };
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_on_screen() {
+        let monitors = [(0, 0, 1920, 1080), (-1280, 0, 1280, 1024)];
+        let geometry = |x, y| WindowGeometry {
+            x,
+            y,
+            width: 800,
+            height: 600,
+            maximized: false,
+        };
+
+        assert!(is_on_screen(&geometry(100, 100), &monitors));
+        assert!(is_on_screen(&geometry(-1000, 200), &monitors));
+        assert!(!is_on_screen(&geometry(2500, 100), &monitors));
+        assert!(!is_on_screen(&geometry(100, -700), &monitors));
+    }
+}
@@ -1,21 +1,55 @@
 use crate::{
     consts,
-    windows::utils::{enable_required_privileges, get_process_path, get_process_status},
-};
-use windows::Win32::{
-    Foundation::CloseHandle,
-    System::{
-        Diagnostics::ToolHelp::{
-            CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
-            TH32CS_SNAPPROCESS,
+    windows::{
+        affinity::{compute_affinity_mask, AffinityStrategy},
+        allowlist::{check_child_process_allowed, check_process_allowed},
+        anti_cheat::{
+            check_anti_cheat_allowed, AntiCheatCatalog, AntiCheatFamily, DetectedAntiCheat,
+        },
+        background_mode::{
+            enter_background_mode, set_io_priority, set_memory_priority, ThrottleMode,
+            IO_PRIORITY_NORMAL, IO_PRIORITY_VERY_LOW,
+        },
+        cpu_rate_limit::{
+            apply_cpu_rate_limit, release_cpu_rate_limit, MAX_CPU_RATE_PERCENT,
+            MIN_CPU_RATE_PERCENT,
         },
-        Threading::{
-            OpenProcess, SetPriorityClass, SetProcessAffinityMask, IDLE_PRIORITY_CLASS,
-            PROCESS_ALL_ACCESS, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION,
-            PROCESS_SET_INFORMATION,
+        efficiency_mode::{is_efficiency_mode_supported, set_efficiency_mode},
+        gpu_priority::{set_gpu_priority, GPU_PRIORITY_IDLE, GPU_PRIORITY_NORMAL},
+        priority::TargetPriority,
+        process_list::{query_processes, snapshot_processes},
+        process_matcher::{ProcessMatcher, ProcessNamePattern},
+        process_tree::{build_process_tree, find_descendants, AceProcessNode},
+        protection::{
+            access_denied_reason, get_integrity_level, get_protection_level, IntegrityLevel,
+            ProtectionLevel,
+        },
+        saved_state::{self, SavedProcessState},
+        signature::{verify_file_signature, SignatureInfo, SignatureStatus},
+        suspend::{resume_process, suspend_process},
+        threads::{lower_thread_priorities, restore_thread_priorities, ThreadPriority},
+        topology::{get_cpu_topology, mask_to_processors},
+        usage::{get_process_memory, get_process_start_time, trim_working_set},
+        utils::{
+            enable_required_privileges, get_process_path, get_process_status, is_running_as_admin,
         },
     },
 };
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::atomic::{AtomicU32, Ordering},
+};
+use tauri_specta::Event;
+use windows::Win32::{
+    Foundation::{CloseHandle, HANDLE},
+    System::Threading::{
+        GetCurrentProcess, GetPriorityClass, GetProcessAffinityMask, OpenProcess, SetPriorityClass,
+        SetProcessAffinityMask, MEMORY_PRIORITY_NORMAL, MEMORY_PRIORITY_VERY_LOW,
+        NORMAL_PRIORITY_CLASS, PROCESS_ALL_ACCESS, PROCESS_CREATION_FLAGS,
+        PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SET_INFORMATION,
+    },
+};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
 pub struct ProcessInfo {
@@ -24,15 +58,741 @@ pub struct ProcessInfo {
     pub process_path: String,
     pub priority_modified: bool,
     pub affinity_modified: bool,
+    pub io_priority_modified: bool,
+    pub memory_priority_modified: bool,
+    pub gpu_priority_modified: bool,
+    /// EcoQoS throttling applied, shown as Efficiency mode in Task Manager
+    pub efficiency_mode_enabled: bool,
+    /// Working set in bytes right before and after the last trim
+    pub working_set_before_trim: Option<u64>,
+    pub working_set_after_trim: Option<u64>,
+    /// Suspended through this tool, ACE does no work at all until resumed
+    pub is_suspended: bool,
+    /// Capped through a job object instead of pinned to cores
+    pub cpu_rate_limited: bool,
+    /// Number of threads set to idle priority
+    pub threads_adjusted: u32,
     pub current_priority: String,
     pub current_affinity: String,
     pub is_optimized: bool,
+    pub parent_process_id: u32,
+    pub parent_process_name: Option<String>,
+    /// Discovered as a helper spawned by another ACE process rather than by its name
+    pub is_child_process: bool,
+    /// Anti-cheat the process belongs to, other families than ACE are opt-in
+    pub anti_cheat_family: AntiCheatFamily,
+    /// The game or launcher this ACE instance was started for, when it can be resolved
+    pub associated_game: Option<String>,
+    pub signature_status: SignatureStatus,
+    pub signer: Option<String>,
+    /// Protected process light level, `None` when it could not be queried
+    pub protection_level: Option<ProtectionLevel>,
+    /// Integrity level of the process token, `None` when it could not be queried
+    pub integrity_level: Option<IntegrityLevel>,
+    /// Settings recorded before the first modification, used to restore the process
+    #[serde(skip)]
+    pub original_settings: Option<OriginalProcessSettings>,
+    #[serde(skip)]
+    pub original_thread_priorities: Vec<ThreadPriority>,
+    /// Times the process put its priority or affinity back after it was optimized
+    pub reset_count: u32,
+    /// Settings read right after the optimization, used to notice a reset
+    #[serde(skip)]
+    pub applied_settings: Option<OriginalProcessSettings>,
+}
+
+impl ProcessInfo {
+    /// Whether anything was changed that a revert has to undo. The original settings
+    /// are missing when the process could only be opened with set access
+    pub fn is_modified(&self) -> bool {
+        self.original_settings.is_some()
+            || self.is_suspended
+            || self.priority_modified
+            || self.affinity_modified
+            || self.io_priority_modified
+            || self.memory_priority_modified
+            || self.gpu_priority_modified
+            || self.efficiency_mode_enabled
+            || self.cpu_rate_limited
+            || self.threads_adjusted > 0
+    }
+
+    /// Take over what an optimization worker changed on its copy of this process.
+    /// The scanned fields, e.g. the current priority and affinity, the reset count and
+    /// a suspend that happened while the worker ran, are kept
+    fn merge_optimization(&mut self, optimized: ProcessInfo) {
+        self.priority_modified = optimized.priority_modified;
+        self.affinity_modified = optimized.affinity_modified;
+        self.io_priority_modified = optimized.io_priority_modified;
+        self.memory_priority_modified = optimized.memory_priority_modified;
+        self.gpu_priority_modified = optimized.gpu_priority_modified;
+        self.efficiency_mode_enabled = optimized.efficiency_mode_enabled;
+        self.working_set_before_trim = optimized.working_set_before_trim;
+        self.working_set_after_trim = optimized.working_set_after_trim;
+        self.cpu_rate_limited = optimized.cpu_rate_limited;
+        self.threads_adjusted = optimized.threads_adjusted;
+        self.is_optimized = optimized.is_optimized;
+        self.original_settings = optimized.original_settings;
+        self.original_thread_priorities = optimized.original_thread_priorities;
+        self.applied_settings = optimized.applied_settings;
+    }
+}
+
+/// Priority class and affinity mask of a process before it was optimized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct OriginalProcessSettings {
+    pub priority_class: u32,
+    pub affinity_mask: u64,
+}
+
+/// Settings applied to all ACE instances belonging to the same game
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct AceInstanceSettings {
+    pub enabled: bool,
+    #[serde(default)]
+    pub throttle_mode: ThrottleMode,
+}
+
+impl Default for AceInstanceSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            throttle_mode: ThrottleMode::default(),
+        }
+    }
+}
+
+/// Maximum number of ancestors inspected when attributing a process
+const MAX_PARENT_DEPTH: usize = 8;
+
+/// Get the display name of a known game by its executable name
+fn find_known_game(process_name: &str) -> Option<&'static str> {
+    consts::KNOWN_GAMES
+        .iter()
+        .find(|(_, executable)| executable.eq_ignore_ascii_case(process_name))
+        .map(|(name, _)| *name)
+}
+
+/// Get the display name of a known launcher by its executable name
+fn find_known_launcher(process_name: &str) -> Option<&'static str> {
+    consts::KNOWN_LAUNCHERS
+        .iter()
+        .find(|(_, executable)| executable.eq_ignore_ascii_case(process_name))
+        .map(|(name, _)| *name)
+}
+
+/// Walk up the process tree and return the closest known game,
+/// falling back to the closest known launcher
+fn resolve_associated_game(
+    parents: &HashMap<u32, (u32, String)>,
+    process_id: u32,
+) -> Option<String> {
+    let mut launcher = None;
+    let mut visited = vec![process_id];
+    let mut current = parents.get(&process_id).map(|(parent_id, _)| *parent_id);
+
+    while let Some(pid) = current {
+        // pid 0 is the idle process and pid reuse can create cycles
+        if pid == 0 || visited.contains(&pid) || visited.len() > MAX_PARENT_DEPTH {
+            break;
+        }
+        visited.push(pid);
+
+        let Some((parent_id, name)) = parents.get(&pid) else {
+            break;
+        };
+
+        if let Some(game) = find_known_game(name) {
+            return Some(game.to_string());
+        }
+
+        if launcher.is_none() {
+            launcher = find_known_launcher(name);
+        }
+
+        current = Some(*parent_id);
+    }
+
+    launcher.map(|name| name.to_string())
+}
+
+/// Check the allowlist, helpers spawned by ACE are matched by their location only
+fn check_allowed(process: &ProcessInfo, targets: &ProcessMatcher) -> Result<(), String> {
+    if process.anti_cheat_family != AntiCheatFamily::Ace {
+        check_anti_cheat_allowed(
+            process.anti_cheat_family,
+            &process.process_name,
+            &process.process_path,
+        )
+    } else if process.is_child_process {
+        check_child_process_allowed(&process.process_name, &process.process_path)
+    } else {
+        check_process_allowed(&process.process_name, &process.process_path, targets)
+    }
+}
+
+/// Check that the executable is signed by a known ACE publisher,
+/// a process with a borrowed name would fail this check
+fn check_signature(process: &ProcessInfo) -> Result<(), String> {
+    if process.signature_status == SignatureStatus::Trusted {
+        return Ok(());
+    }
+
+    Err(format!(
+        "{} (PID: {}) is not signed by a known ACE publisher ({:?}, signer: {})",
+        process.process_name,
+        process.process_id,
+        process.signature_status,
+        process.signer.as_deref().unwrap_or("none")
+    ))
+}
+
+/// What an optimization applied to a process, kept across rescans so reverting undoes it
+#[derive(Debug, Clone, Copy, Default)]
+struct AppliedModifications {
+    priority_modified: bool,
+    affinity_modified: bool,
+    io_priority_modified: bool,
+    memory_priority_modified: bool,
+    gpu_priority_modified: bool,
+    efficiency_mode_enabled: bool,
+    working_set_before_trim: Option<u64>,
+    working_set_after_trim: Option<u64>,
+    cpu_rate_limited: bool,
+    threads_adjusted: u32,
+}
+
+impl From<&ProcessInfo> for AppliedModifications {
+    fn from(process: &ProcessInfo) -> Self {
+        Self {
+            priority_modified: process.priority_modified,
+            affinity_modified: process.affinity_modified,
+            io_priority_modified: process.io_priority_modified,
+            memory_priority_modified: process.memory_priority_modified,
+            gpu_priority_modified: process.gpu_priority_modified,
+            efficiency_mode_enabled: process.efficiency_mode_enabled,
+            working_set_before_trim: process.working_set_before_trim,
+            working_set_after_trim: process.working_set_after_trim,
+            cpu_rate_limited: process.cpu_rate_limited,
+            threads_adjusted: process.threads_adjusted,
+        }
+    }
+}
+
+/// Upper bound of worker threads used to optimize processes in parallel
+const MAX_OPTIMIZATION_WORKERS: usize = 4;
+
+/// Settings shared by all processes of one optimization run
+#[derive(Debug, Clone, Copy)]
+struct OptimizationSettings {
+    priority: TargetPriority,
+    affinity_mask: Option<usize>,
+    lower_io_priority: bool,
+    lower_memory_priority: bool,
+    lower_gpu_priority: bool,
+    efficiency_mode: bool,
+    trim_working_set: bool,
+    cpu_rate_limit: Option<u32>,
+    lower_thread_priority: bool,
+    require_trusted_signature: bool,
+}
+
+// Emitted after each process of a multi-process optimization, in completion order
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type, Event)]
+pub struct OptimizationProgress {
+    /// Number of processes handled so far, including this one
+    pub completed: u32,
+    pub total: u32,
+    pub process_id: u32,
+    pub process_name: String,
+    pub success: bool,
+}
+
+/// Outcome of reverting a single process
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ProcessRestoreResult {
+    pub process_id: u32,
+    pub process_name: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// A process handed to an optimization worker
+struct OptimizationJob {
+    process: ProcessInfo,
+    throttle_mode: ThrottleMode,
+    success: bool,
+}
+
+/// Processes of one optimization run, optimized without holding the controller.
+/// See `AceProcessController::prepare_optimization`
+pub struct OptimizationBatch {
+    jobs: Vec<OptimizationJob>,
+    settings: OptimizationSettings,
+    targets: ProcessMatcher,
+    /// Number of processes found by the scan, including skipped ones
+    found: usize,
+}
+
+impl OptimizationBatch {
+    /// Optimize the processes, `on_progress` is called from the worker threads
+    /// once per process as soon as it is done
+    pub async fn run<F>(self, on_progress: F) -> Result<Self, String>
+    where
+        F: Fn(OptimizationProgress) + Send + Sync + 'static,
+    {
+        let Self {
+            jobs,
+            settings,
+            targets,
+            found,
+        } = self;
+
+        // the Win32 calls block, keep them off the async runtime
+        tauri::async_runtime::spawn_blocking(move || {
+            let jobs = optimize_processes_parallel(jobs, settings, &targets, &on_progress);
+            Self {
+                jobs,
+                settings,
+                targets,
+                found,
+            }
+        })
+        .await
+        .map_err(|e| format!("Failed to run optimization workers: {}", e))
+    }
+}
+
+/// Optimize the given processes on a bounded number of worker threads,
+/// `success` of each job is set when the process was modified
+fn optimize_processes_parallel(
+    mut pending: Vec<OptimizationJob>,
+    settings: OptimizationSettings,
+    targets: &ProcessMatcher,
+    on_progress: &(dyn Fn(OptimizationProgress) + Sync),
+) -> Vec<OptimizationJob> {
+    if pending.is_empty() {
+        return pending;
+    }
+
+    let total = pending.len() as u32;
+    let completed = AtomicU32::new(0);
+    let workers = pending.len().min(MAX_OPTIMIZATION_WORKERS);
+    let chunk_size = pending.len().div_ceil(workers);
+
+    std::thread::scope(|scope| {
+        for chunk in pending.chunks_mut(chunk_size) {
+            let completed = &completed;
+            scope.spawn(move || {
+                for job in chunk.iter_mut() {
+                    job.success =
+                        apply_optimization(&mut job.process, job.throttle_mode, settings, targets);
+                    on_progress(OptimizationProgress {
+                        completed: completed.fetch_add(1, Ordering::Relaxed) + 1,
+                        total,
+                        process_id: job.process.process_id,
+                        process_name: job.process.process_name.clone(),
+                        success: job.success,
+                    });
+                }
+            });
+        }
+    });
+
+    pending
+}
+
+/// Lower the priority and restrict the affinity of a single process
+fn apply_optimization(
+    process: &mut ProcessInfo,
+    throttle_mode: ThrottleMode,
+    settings: OptimizationSettings,
+    targets: &ProcessMatcher,
+) -> bool {
+    if let Err(e) = check_allowed(process, targets) {
+        tracing::warn!("{}", e);
+        return false;
+    }
+
+    if let Err(e) = check_signature(process) {
+        tracing::warn!("{}", e);
+        if settings.require_trusted_signature {
+            return false;
+        }
+    }
+
+    let permissions = [
+        PROCESS_SET_INFORMATION | PROCESS_QUERY_INFORMATION,
+        PROCESS_SET_INFORMATION,
+        PROCESS_ALL_ACCESS,
+        PROCESS_QUERY_INFORMATION,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    ];
+
+    let mut process_handle = None;
+    let mut used_permission = 0;
+
+    unsafe {
+        for (i, &permission) in permissions.iter().enumerate() {
+            match OpenProcess(permission, false, process.process_id) {
+                Ok(handle) => {
+                    process_handle = Some(handle);
+                    used_permission = i;
+                    break;
+                }
+                Err(e) => {
+                    tracing::debug!("Permission level {} failed: {:?}", i, e);
+                    continue;
+                }
+            }
+        }
+
+        match process_handle {
+            Some(handle) => {
+                tracing::info!(
+                    "Successfully opened process handle (permission level: {})",
+                    used_permission
+                );
+
+                // only the first modification is recorded, later ones would save our own values
+                if process.original_settings.is_none() {
+                    process.original_settings = read_process_settings(handle);
+                }
+
+                let mut operation_success = false;
+
+                // Set the configured priority class, background mode also lowers I/O and memory priority
+                let priority_class = settings.priority.priority_class();
+                let priority_result = match throttle_mode {
+                    ThrottleMode::PriorityOnly => {
+                        SetPriorityClass(handle, priority_class).map_err(|e| format!("{:?}", e))
+                    }
+                    ThrottleMode::Background => enter_background_mode(handle, priority_class),
+                };
+
+                if priority_result.is_ok() {
+                    tracing::info!(
+                        "Successfully lowered process priority ({:?}, {:?})",
+                        settings.priority,
+                        throttle_mode
+                    );
+                    process.priority_modified = true;
+                    // background mode already includes the I/O priority
+                    if throttle_mode == ThrottleMode::Background {
+                        process.io_priority_modified = true;
+                        process.memory_priority_modified = true;
+                    }
+                    operation_success = true;
+                } else {
+                    tracing::warn!("Failed to set priority: {:?}", priority_result.err());
+                }
+
+                // Lower the I/O priority so disk access of ACE does not stall the game
+                if settings.lower_io_priority && !process.io_priority_modified {
+                    match set_io_priority(handle, IO_PRIORITY_VERY_LOW) {
+                        Ok(()) => {
+                            tracing::info!("Successfully lowered I/O priority");
+                            process.io_priority_modified = true;
+                            operation_success = true;
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                }
+
+                // Lower the memory priority so ACE pages are trimmed before the game's
+                if settings.lower_memory_priority && !process.memory_priority_modified {
+                    match set_memory_priority(handle, MEMORY_PRIORITY_VERY_LOW) {
+                        Ok(()) => {
+                            tracing::info!("Successfully lowered memory priority");
+                            process.memory_priority_modified = true;
+                            operation_success = true;
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                }
+
+                // Lower the GPU scheduling priority so ACE does not contend with overlay rendering
+                if settings.lower_gpu_priority && !process.gpu_priority_modified {
+                    match set_gpu_priority(handle, GPU_PRIORITY_IDLE) {
+                        Ok(()) => {
+                            tracing::info!("Successfully lowered GPU priority");
+                            process.gpu_priority_modified = true;
+                            operation_success = true;
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                }
+
+                // EcoQoS lets the scheduler prefer efficient cores and clocks for ACE
+                if settings.efficiency_mode && !process.efficiency_mode_enabled {
+                    match set_efficiency_mode(handle, true) {
+                        Ok(()) => {
+                            tracing::info!("Successfully enabled efficiency mode");
+                            process.efficiency_mode_enabled = true;
+                            operation_success = true;
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                }
+
+                // Some ACE threads raise their own priority regardless of the process class
+                if settings.lower_thread_priority {
+                    match lower_thread_priorities(process.process_id) {
+                        Ok(threads) => {
+                            tracing::info!("Lowered priority of {} threads", threads.len());
+                            process.threads_adjusted = threads.len() as u32;
+                            operation_success |= !threads.is_empty();
+                            // keep the priorities from before the first adjustment
+                            for thread in threads {
+                                if !process
+                                    .original_thread_priorities
+                                    .iter()
+                                    .any(|t| t.thread_id == thread.thread_id)
+                                {
+                                    process.original_thread_priorities.push(thread);
+                                }
+                            }
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                }
+
+                // Cap the CPU usage through a job object, used instead of affinity pinning
+                if let Some(percent) = settings.cpu_rate_limit {
+                    match apply_cpu_rate_limit(process.process_id, percent) {
+                        Ok(()) => {
+                            tracing::info!("Successfully limited CPU rate to {}%", percent);
+                            process.cpu_rate_limited = true;
+                            operation_success = true;
+                        }
+                        Err(e) => tracing::warn!("{}", e),
+                    }
+                }
+
+                // Set CPU affinity according to the selected strategy
+                if let Some(affinity_mask) = settings.affinity_mask {
+                    tracing::info!("Setting CPU affinity mask to {:#x}...", affinity_mask);
+
+                    let affinity_result = SetProcessAffinityMask(handle, affinity_mask);
+                    if affinity_result.is_ok() {
+                        process.affinity_modified = true;
+                        operation_success = true;
+                    } else {
+                        tracing::warn!("Failed to set CPU affinity: {:?}", affinity_result.err());
+                    }
+                }
+
+                // Trim last, the pages are only paged back in slowly at the lowered priorities
+                if settings.trim_working_set && operation_success {
+                    trim_process_working_set(process);
+                }
+
+                if operation_success {
+                    process.is_optimized = true;
+                    process.applied_settings = read_process_settings(handle);
+                    tracing::info!("Process optimization completed");
+                } else {
+                    process.is_optimized = false;
+                    tracing::warn!("No operations succeeded for this process");
+                }
+
+                let _ = CloseHandle(handle);
+                operation_success
+            }
+            None => {
+                tracing::warn!(
+                    "Failed to open process {}: {}",
+                    process.process_id,
+                    access_denied_reason(
+                        process.protection_level,
+                        process.integrity_level,
+                        is_running_as_admin().unwrap_or(false),
+                    )
+                );
+                false
+            }
+        }
+    }
+}
+
+/// Empty the working set of a process and record its size before and after
+fn trim_process_working_set(process: &mut ProcessInfo) {
+    let working_set = |process_id| get_process_memory(process_id).ok().map(|m| m.working_set);
+
+    let before = working_set(process.process_id);
+    if let Err(e) = trim_working_set(process.process_id) {
+        tracing::warn!("{}", e);
+        return;
+    }
+    let after = working_set(process.process_id);
+
+    tracing::info!(
+        "Trimmed working set of {} (PID: {}): {:?} -> {:?} bytes",
+        process.process_name,
+        process.process_id,
+        before,
+        after
+    );
+
+    process.working_set_before_trim = before;
+    process.working_set_after_trim = after;
+}
+
+/// Settings saved by an earlier run for this very process, not just the same pid
+fn take_recovered_settings(
+    recovered_states: &mut Vec<SavedProcessState>,
+    process_id: u32,
+) -> Option<OriginalProcessSettings> {
+    let index = recovered_states
+        .iter()
+        .position(|s| s.process_id == process_id)?;
+    let state = recovered_states.remove(index);
+
+    state.is_running().then(|| {
+        tracing::info!(
+            "Recovered original settings of {} (PID: {})",
+            state.process_name,
+            process_id
+        );
+        state.original_settings
+    })
+}
+
+/// Read the current priority class and affinity mask, the handle needs query access
+pub fn read_process_settings(handle: HANDLE) -> Option<OriginalProcessSettings> {
+    unsafe {
+        let priority_class = GetPriorityClass(handle);
+        if priority_class == 0 {
+            return None;
+        }
+
+        let mut process_mask = 0usize;
+        let mut system_mask = 0usize;
+        GetProcessAffinityMask(handle, &mut process_mask, &mut system_mask).ok()?;
+
+        Some(OriginalProcessSettings {
+            priority_class,
+            affinity_mask: process_mask as u64,
+        })
+    }
+}
+
+/// Read the priority class and affinity mask of a process by its pid
+fn query_process_settings(process_id: u32) -> Option<OriginalProcessSettings> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+        let settings = read_process_settings(handle);
+        let _ = CloseHandle(handle);
+        settings
+    }
+}
+
+/// Put a process back to its recorded settings,
+/// or to normal priority and all cores when nothing was recorded
+fn restore_process(process: &mut ProcessInfo) -> Result<(), String> {
+    // a suspended process would stay frozen forever once the tool exits
+    if process.is_suspended {
+        resume_process(process.process_id)?;
+        process.is_suspended = false;
+    }
+
+    unsafe {
+        // the optimization may have got in with set access only, so may the restore
+        let handle = OpenProcess(
+            PROCESS_SET_INFORMATION | PROCESS_QUERY_INFORMATION,
+            false,
+            process.process_id,
+        )
+        .or_else(|_| OpenProcess(PROCESS_SET_INFORMATION, false, process.process_id))
+        .map_err(|e| format!("Failed to open process {}: {:?}", process.process_id, e))?;
+
+        let result = match process.original_settings {
+            Some(original) => {
+                SetPriorityClass(handle, PROCESS_CREATION_FLAGS(original.priority_class))
+                    .and_then(|_| SetProcessAffinityMask(handle, original.affinity_mask as usize))
+            }
+            // the system mask is the same for every process, ours needs no access rights
+            None => SetPriorityClass(handle, NORMAL_PRIORITY_CLASS).and_then(|_| {
+                let mut process_mask = 0usize;
+                let mut system_mask = 0usize;
+                GetProcessAffinityMask(GetCurrentProcess(), &mut process_mask, &mut system_mask)?;
+                SetProcessAffinityMask(handle, system_mask)
+            }),
+        };
+
+        // not part of the recorded settings, every process starts with normal I/O and memory priority
+        if let Err(e) = set_io_priority(handle, IO_PRIORITY_NORMAL) {
+            tracing::debug!("{}", e);
+        }
+        if let Err(e) = set_memory_priority(handle, MEMORY_PRIORITY_NORMAL) {
+            tracing::debug!("{}", e);
+        }
+        if process.gpu_priority_modified {
+            if let Err(e) = set_gpu_priority(handle, GPU_PRIORITY_NORMAL) {
+                tracing::debug!("{}", e);
+            }
+        }
+        restore_thread_priorities(&process.original_thread_priorities);
+        if let Err(e) = release_cpu_rate_limit(process.process_id) {
+            tracing::debug!("{}", e);
+        }
+        if process.efficiency_mode_enabled {
+            if let Err(e) = set_efficiency_mode(handle, false) {
+                tracing::debug!("{}", e);
+            }
+        }
+
+        let _ = CloseHandle(handle);
+
+        result.map_err(|e| format!("Failed to restore process {}: {:?}", process.process_id, e))?;
+    }
+
+    tracing::info!(
+        "Restored {} (PID: {})",
+        process.process_name,
+        process.process_id
+    );
+
+    process.priority_modified = false;
+    process.affinity_modified = false;
+    process.io_priority_modified = false;
+    process.memory_priority_modified = false;
+    process.gpu_priority_modified = false;
+    process.efficiency_mode_enabled = false;
+    process.cpu_rate_limited = false;
+    process.threads_adjusted = 0;
+    process.original_thread_priorities.clear();
+    process.is_optimized = false;
+    process.original_settings = None;
+    process.applied_settings = None;
+    Ok(())
 }
 
 #[derive(Clone)]
 pub struct AceProcessController {
     processes: Vec<ProcessInfo>,
     privileges_enabled: bool,
+    instance_settings: HashMap<String, AceInstanceSettings>,
+    affinity_strategy: AffinityStrategy,
+    signature_cache: HashMap<String, SignatureInfo>,
+    target_matcher: ProcessMatcher,
+    target_priority: TargetPriority,
+    /// Used for processes without per game settings
+    throttle_mode: ThrottleMode,
+    lower_io_priority: bool,
+    lower_memory_priority: bool,
+    lower_gpu_priority: bool,
+    efficiency_mode: bool,
+    trim_working_set: bool,
+    cpu_rate_limit: Option<u32>,
+    lower_thread_priority: bool,
+    /// Refuse unsigned processes instead of only warning about them
+    require_trusted_signature: bool,
+    /// File the original settings are saved to, see `set_state_file`
+    state_file: Option<PathBuf>,
+    /// Saved settings of a previous run not yet matched to a scanned process
+    recovered_states: Vec<SavedProcessState>,
+    anti_cheat_catalog: AntiCheatCatalog,
 }
 
 impl AceProcessController {
@@ -43,6 +803,23 @@ impl AceProcessController {
         Self {
             processes: Vec::new(),
             privileges_enabled,
+            instance_settings: HashMap::new(),
+            affinity_strategy: AffinityStrategy::default(),
+            signature_cache: HashMap::new(),
+            target_matcher: ProcessMatcher::default(),
+            target_priority: TargetPriority::default(),
+            throttle_mode: ThrottleMode::default(),
+            lower_io_priority: false,
+            lower_memory_priority: false,
+            lower_gpu_priority: false,
+            efficiency_mode: false,
+            trim_working_set: false,
+            cpu_rate_limit: None,
+            lower_thread_priority: false,
+            require_trusted_signature: false,
+            state_file: None,
+            recovered_states: Vec::new(),
+            anti_cheat_catalog: AntiCheatCatalog::default(),
         }
     }
 
@@ -58,19 +835,84 @@ impl AceProcessController {
         Ok(self.processes.clone())
     }
 
+    /// Rescan quietly for pollers, an empty result is not an error and nothing is
+    /// logged at info level
+    pub fn refresh_processes(&mut self) -> Result<&[ProcessInfo], String> {
+        self.scan_processes()
+            .map_err(|e| format!("Failed to scan processes: {}", e))?;
+        Ok(&self.processes)
+    }
+
     pub async fn optimize_all_processes(&mut self) -> Result<String, String> {
+        self.optimize_all_processes_with_progress(|_| {}).await
+    }
+
+    /// Same as `optimize_all_processes`, `on_progress` is called from the worker
+    /// threads once per process as soon as it is done
+    pub async fn optimize_all_processes_with_progress<F>(
+        &mut self,
+        on_progress: F,
+    ) -> Result<String, String>
+    where
+        F: Fn(OptimizationProgress) + Send + Sync + 'static,
+    {
+        let batch = self.prepare_optimization()?.run(on_progress).await?;
+        self.finish_optimization(batch)
+    }
+
+    /// Pick the scanned processes to optimize, the returned batch can run while
+    /// the controller is unlocked and is handed back to `finish_optimization`
+    pub fn prepare_optimization(&self) -> Result<OptimizationBatch, String> {
         if self.processes.is_empty() {
             return Err("No processes to optimize. Please scan processes first.".to_string());
         }
 
-        let mut modified_count = 0;
-        let processes_len = self.processes.len();
+        let mut jobs = Vec::new();
+        for process in &self.processes {
+            if !self.is_instance_enabled(process) {
+                tracing::info!(
+                    "Skipping {} (PID: {}), disabled for {}",
+                    process.process_name,
+                    process.process_id,
+                    process.associated_game.as_deref().unwrap_or("unknown game")
+                );
+                continue;
+            }
+
+            jobs.push(OptimizationJob {
+                process: process.clone(),
+                throttle_mode: self.instance_throttle_mode(process),
+                success: false,
+            });
+        }
 
-        for i in 0..self.processes.len() {
-            if self.optimize_process_at_index(i).await {
+        Ok(OptimizationBatch {
+            jobs,
+            settings: self.optimization_settings(),
+            targets: self.target_matcher.clone(),
+            found: self.processes.len(),
+        })
+    }
+
+    /// Merge the optimization results back by PID, anything else that changed
+    /// while the batch ran is kept. Processes that exited meanwhile are dropped
+    pub fn finish_optimization(&mut self, batch: OptimizationBatch) -> Result<String, String> {
+        let processes_len = batch.found;
+
+        let mut modified_count = 0;
+        for job in batch.jobs {
+            if job.success {
                 modified_count += 1;
             }
+            if let Some(process) = self
+                .processes
+                .iter_mut()
+                .find(|p| p.process_id == job.process.process_id)
+            {
+                process.merge_optimization(job.process);
+            }
         }
+        self.persist_state();
 
         let result = format!(
             "Process optimization completed: Found {} processes, Modified {} processes",
@@ -88,7 +930,7 @@ impl AceProcessController {
         Ok(result)
     }
 
-    pub async fn optimize_single_process(&mut self, process_id: u32) -> Result<String, String> {
+    pub fn optimize_single_process(&mut self, process_id: u32) -> Result<String, String> {
         let process_index = self
             .processes
             .iter()
@@ -100,7 +942,7 @@ impl AceProcessController {
                 )
             })?;
 
-        if self.optimize_process_at_index(process_index).await {
+        if self.optimize_process_at_index(process_index) {
             Ok(format!(
                 "Process {} (PID: {}) optimized successfully",
                 self.processes[process_index].process_name, process_id
@@ -125,148 +967,581 @@ impl AceProcessController {
             .iter()
             .map(|p| (p.process_id, p.is_optimized))
             .collect();
+        let mut previous_thread_priorities: HashMap<u32, Vec<ThreadPriority>> = self
+            .processes
+            .iter_mut()
+            .map(|p| {
+                (
+                    p.process_id,
+                    std::mem::take(&mut p.original_thread_priorities),
+                )
+            })
+            .collect();
+        let previous_suspended: Vec<u32> = self
+            .processes
+            .iter()
+            .filter(|p| p.is_suspended)
+            .map(|p| p.process_id)
+            .collect();
+        let previous_resets: HashMap<u32, (u32, Option<OriginalProcessSettings>)> = self
+            .processes
+            .iter()
+            .map(|p| (p.process_id, (p.reset_count, p.applied_settings)))
+            .collect();
+        let previous_original_settings: HashMap<u32, OriginalProcessSettings> = self
+            .processes
+            .iter()
+            .filter_map(|p| p.original_settings.map(|s| (p.process_id, s)))
+            .collect();
+        let previous_modifications: HashMap<u32, AppliedModifications> = self
+            .processes
+            .iter()
+            .map(|p| (p.process_id, AppliedModifications::from(p)))
+            .collect();
 
         self.processes.clear();
 
-        unsafe {
-            let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)
-                .map_err(|e| format!("Failed to create process snapshot: {:?}", e))?;
+        tracing::debug!("Enumerating system processes...");
 
-            let mut process_entry = PROCESSENTRY32W {
-                dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
-                ..Default::default()
-            };
+        // the native query is cheap enough for the watchers polling every second,
+        // Toolhelp stays as a fallback in case ntdll refuses the call
+        let entries = query_processes().or_else(|e| {
+            tracing::debug!("{}, falling back to Toolhelp snapshot", e);
+            snapshot_processes()
+        })?;
+        let parents: HashMap<u32, (u32, String)> = entries
+            .iter()
+            .map(|e| (e.process_id, (e.parent_process_id, e.process_name.clone())))
+            .collect();
 
-            tracing::debug!("Enumerating system processes...");
+        let roots: Vec<u32> = entries
+            .iter()
+            .filter(|e| self.target_matcher.is_match(&e.process_name))
+            .map(|e| e.process_id)
+            .collect();
+        // helpers spawned by ACE are picked up whatever their name is
+        let descendants = find_descendants(&entries, &roots);
+        let other_anti_cheats: HashMap<u32, AntiCheatFamily> = entries
+            .iter()
+            .filter(|e| !roots.contains(&e.process_id))
+            .filter_map(|e| {
+                self.anti_cheat_catalog
+                    .optimized_family_of(&e.process_name)
+                    .map(|family| (e.process_id, family))
+            })
+            .collect();
 
-            if Process32FirstW(snapshot, &mut process_entry).is_ok() {
-                loop {
-                    let process_name_raw = String::from_utf16_lossy(&process_entry.szExeFile);
-                    let process_name = process_name_raw.trim_end_matches('\0');
+        for entry in entries.iter().filter(|e| {
+            roots.contains(&e.process_id)
+                || descendants.contains(&e.process_id)
+                || other_anti_cheats.contains_key(&e.process_id)
+        }) {
+            let process_path =
+                get_process_path(entry.process_id).unwrap_or_else(|_| "Access Denied".to_string());
 
-                    if process_name.eq(consts::ACE_GUARD_64_PROCESS_NAME) {
-                        let process_path = get_process_path(process_entry.th32ProcessID)
-                            .unwrap_or_else(|_| "Access Denied".to_string());
+            tracing::debug!(
+                "Found ACE Guard process: {} (PID: {})",
+                entry.process_name,
+                entry.process_id
+            );
 
-                        tracing::debug!(
-                            "Found ACE Guard process: {} (PID: {})",
-                            process_name,
-                            process_entry.th32ProcessID
-                        );
+            let (current_priority, current_affinity) = get_process_status(entry.process_id)
+                .unwrap_or_else(|_| ("Access Denied".to_string(), "Access Denied".to_string()));
 
-                        let (current_priority, current_affinity) =
-                            get_process_status(process_entry.th32ProcessID).unwrap_or_else(|_| {
-                                ("Access Denied".to_string(), "Access Denied".to_string())
-                            });
-
-                        // 检查是否之前已优化过此进程
-                        let is_optimized = previous_optimized_states
-                            .get(&process_entry.th32ProcessID)
-                            .copied()
-                            .unwrap_or(false);
-
-                        self.processes.push(ProcessInfo {
-                            process_id: process_entry.th32ProcessID,
-                            process_name: process_name.to_string(),
-                            process_path,
-                            priority_modified: false,
-                            affinity_modified: false,
-                            current_priority,
-                            current_affinity,
-                            is_optimized,
-                        });
-                    }
+            // 检查是否之前已优化过此进程
+            let mut is_optimized = previous_optimized_states
+                .get(&entry.process_id)
+                .copied()
+                .unwrap_or(false);
 
-                    if Process32NextW(snapshot, &mut process_entry).is_err() {
-                        break;
+            // ACE may put its own priority or affinity back, which undoes the optimization
+            let (mut reset_count, mut applied_settings) = previous_resets
+                .get(&entry.process_id)
+                .copied()
+                .unwrap_or_default();
+            if let Some(applied) = applied_settings.filter(|_| is_optimized) {
+                if let Some(current) = query_process_settings(entry.process_id) {
+                    if current != applied {
+                        tracing::warn!(
+                            "{} (PID: {}) reset its settings: {:?} -> {:?}",
+                            entry.process_name,
+                            entry.process_id,
+                            applied,
+                            current
+                        );
+                        reset_count += 1;
+                        is_optimized = false;
+                        applied_settings = None;
                     }
                 }
             }
 
-            let _ = CloseHandle(snapshot);
+            let modifications = previous_modifications
+                .get(&entry.process_id)
+                .copied()
+                .unwrap_or_default();
+
+            let parent_process_name = parents
+                .get(&entry.parent_process_id)
+                .map(|(_, parent_name)| parent_name.clone());
+            let associated_game = resolve_associated_game(&parents, entry.process_id);
+
+            // verifying a signature reads the whole file, so results are cached per path
+            let signature = self
+                .signature_cache
+                .entry(process_path.clone())
+                .or_insert_with(|| verify_file_signature(&process_path))
+                .clone();
+
+            self.processes.push(ProcessInfo {
+                process_id: entry.process_id,
+                process_name: entry.process_name.clone(),
+                process_path,
+                priority_modified: modifications.priority_modified,
+                affinity_modified: modifications.affinity_modified,
+                io_priority_modified: modifications.io_priority_modified,
+                memory_priority_modified: modifications.memory_priority_modified,
+                gpu_priority_modified: modifications.gpu_priority_modified,
+                efficiency_mode_enabled: modifications.efficiency_mode_enabled,
+                working_set_before_trim: modifications.working_set_before_trim,
+                working_set_after_trim: modifications.working_set_after_trim,
+                is_suspended: previous_suspended.contains(&entry.process_id),
+                cpu_rate_limited: modifications.cpu_rate_limited,
+                threads_adjusted: modifications.threads_adjusted,
+                current_priority,
+                current_affinity,
+                is_optimized,
+                parent_process_id: entry.parent_process_id,
+                parent_process_name,
+                is_child_process: descendants.contains(&entry.process_id),
+                anti_cheat_family: other_anti_cheats
+                    .get(&entry.process_id)
+                    .copied()
+                    .unwrap_or(AntiCheatFamily::Ace),
+                associated_game,
+                signature_status: signature.status,
+                signer: signature.signer,
+                protection_level: get_protection_level(entry.process_id),
+                integrity_level: get_integrity_level(entry.process_id),
+                original_settings: previous_original_settings
+                    .get(&entry.process_id)
+                    .copied()
+                    .or_else(|| {
+                        take_recovered_settings(&mut self.recovered_states, entry.process_id)
+                    }),
+                original_thread_priorities: previous_thread_priorities
+                    .remove(&entry.process_id)
+                    .unwrap_or_default(),
+                reset_count,
+                applied_settings,
+            });
         }
 
         Ok(())
     }
 
-    async fn optimize_process_at_index(&mut self, index: usize) -> bool {
+    fn optimize_process_at_index(&mut self, index: usize) -> bool {
         if index >= self.processes.len() {
             return false;
         }
 
-        let process = &mut self.processes[index];
-        let permissions = [
-            PROCESS_SET_INFORMATION | PROCESS_QUERY_INFORMATION,
-            PROCESS_SET_INFORMATION,
-            PROCESS_ALL_ACCESS,
-            PROCESS_QUERY_INFORMATION,
-            PROCESS_QUERY_LIMITED_INFORMATION,
-        ];
-
-        let mut process_handle = None;
-        let mut used_permission = 0;
-
-        unsafe {
-            for (i, &permission) in permissions.iter().enumerate() {
-                match OpenProcess(permission, false, process.process_id) {
-                    Ok(handle) => {
-                        process_handle = Some(handle);
-                        used_permission = i;
-                        break;
-                    }
-                    Err(e) => {
-                        tracing::debug!("Permission level {} failed: {:?}", i, e);
-                        continue;
-                    }
+        let settings = self.optimization_settings();
+        let throttle_mode = self.instance_throttle_mode(&self.processes[index]);
+        let success = apply_optimization(
+            &mut self.processes[index],
+            throttle_mode,
+            settings,
+            &self.target_matcher,
+        );
+        self.persist_state();
+        success
+    }
+
+    fn optimization_settings(&self) -> OptimizationSettings {
+        // a CPU rate cap replaces affinity pinning
+        let affinity_mask = match self.cpu_rate_limit {
+            Some(_) => None,
+            None => match self.compute_affinity_mask() {
+                Ok(mask) => Some(mask),
+                Err(e) => {
+                    tracing::warn!("Failed to compute affinity mask: {}", e);
+                    None
                 }
+            },
+        };
+
+        OptimizationSettings {
+            priority: self.target_priority,
+            affinity_mask,
+            lower_io_priority: self.lower_io_priority,
+            lower_memory_priority: self.lower_memory_priority,
+            lower_gpu_priority: self.lower_gpu_priority,
+            efficiency_mode: self.efficiency_mode,
+            trim_working_set: self.trim_working_set,
+            cpu_rate_limit: self.cpu_rate_limit,
+            lower_thread_priority: self.lower_thread_priority,
+            require_trusted_signature: self.require_trusted_signature,
+        }
+    }
+
+    fn compute_affinity_mask(&self) -> Result<usize, String> {
+        // topology is only needed by the strategies that look at core types or caches
+        let topology = match self.affinity_strategy {
+            AffinityStrategy::ECoresOnly | AffinityStrategy::SmallestCacheCcd => get_cpu_topology()
+                .map_err(|e| tracing::warn!("Failed to detect CPU topology: {}", e))
+                .ok(),
+            _ => None,
+        };
+
+        compute_affinity_mask(
+            self.affinity_strategy,
+            topology.as_ref(),
+            num_cpus::get() as u32,
+        )
+    }
+
+    /// Logical processors the current strategy pins the processes to on this machine
+    pub fn get_affinity_processors(&self) -> Result<Vec<u32>, String> {
+        self.compute_affinity_mask().map(mask_to_processors)
+    }
+
+    pub fn get_affinity_strategy(&self) -> AffinityStrategy {
+        self.affinity_strategy
+    }
+
+    pub fn set_affinity_strategy(&mut self, strategy: AffinityStrategy) {
+        tracing::debug!("Affinity strategy set to {:?}", strategy);
+        self.affinity_strategy = strategy;
+    }
+
+    pub fn get_target_priority(&self) -> TargetPriority {
+        self.target_priority
+    }
+
+    pub fn set_target_priority(&mut self, priority: TargetPriority) {
+        tracing::debug!("Target priority set to {:?}", priority);
+        self.target_priority = priority;
+    }
+
+    pub fn get_throttle_mode(&self) -> ThrottleMode {
+        self.throttle_mode
+    }
+
+    pub fn set_throttle_mode(&mut self, mode: ThrottleMode) {
+        tracing::debug!("Throttle mode set to {:?}", mode);
+        self.throttle_mode = mode;
+    }
+
+    pub fn get_lower_io_priority(&self) -> bool {
+        self.lower_io_priority
+    }
+
+    pub fn set_lower_io_priority(&mut self, enabled: bool) {
+        tracing::debug!("Lower I/O priority set to {}", enabled);
+        self.lower_io_priority = enabled;
+    }
+
+    pub fn get_lower_memory_priority(&self) -> bool {
+        self.lower_memory_priority
+    }
+
+    pub fn set_lower_memory_priority(&mut self, enabled: bool) {
+        tracing::debug!("Lower memory priority set to {}", enabled);
+        self.lower_memory_priority = enabled;
+    }
+
+    pub fn get_lower_gpu_priority(&self) -> bool {
+        self.lower_gpu_priority
+    }
+
+    pub fn set_lower_gpu_priority(&mut self, enabled: bool) {
+        tracing::debug!("Lower GPU priority set to {}", enabled);
+        self.lower_gpu_priority = enabled;
+    }
+
+    pub fn get_efficiency_mode(&self) -> bool {
+        self.efficiency_mode
+    }
+
+    pub fn set_efficiency_mode(&mut self, enabled: bool) -> Result<(), String> {
+        if enabled && !is_efficiency_mode_supported() {
+            return Err("Efficiency mode requires Windows 11".to_string());
+        }
+
+        tracing::debug!("Efficiency mode set to {}", enabled);
+        self.efficiency_mode = enabled;
+        Ok(())
+    }
+
+    pub fn get_trim_working_set(&self) -> bool {
+        self.trim_working_set
+    }
+
+    pub fn set_trim_working_set(&mut self, enabled: bool) {
+        tracing::debug!("Trim working set set to {}", enabled);
+        self.trim_working_set = enabled;
+    }
+
+    pub fn get_cpu_rate_limit(&self) -> Option<u32> {
+        self.cpu_rate_limit
+    }
+
+    /// Cap ACE processes to a percentage of the total CPU time, `None` pins to cores instead
+    pub fn set_cpu_rate_limit(&mut self, percent: Option<u32>) -> Result<(), String> {
+        if let Some(percent) = percent {
+            if !(MIN_CPU_RATE_PERCENT..=MAX_CPU_RATE_PERCENT).contains(&percent) {
+                return Err(format!(
+                    "CPU rate limit must be between {}% and {}%",
+                    MIN_CPU_RATE_PERCENT, MAX_CPU_RATE_PERCENT
+                ));
             }
+        }
 
-            match process_handle {
-                Some(handle) => {
-                    tracing::info!(
-                        "Successfully opened process handle (permission level: {})",
-                        used_permission
-                    );
+        tracing::debug!("CPU rate limit set to {:?}", percent);
+        self.cpu_rate_limit = percent;
+        Ok(())
+    }
 
-                    let mut operation_success = false;
+    pub fn get_lower_thread_priority(&self) -> bool {
+        self.lower_thread_priority
+    }
 
-                    // Set process priority to idle
-                    let priority_result = SetPriorityClass(handle, IDLE_PRIORITY_CLASS);
+    pub fn set_lower_thread_priority(&mut self, enabled: bool) {
+        tracing::debug!("Lower thread priority set to {}", enabled);
+        self.lower_thread_priority = enabled;
+    }
 
-                    if priority_result.is_ok() {
-                        tracing::info!("Successfully lowered process priority");
-                        process.priority_modified = true;
-                        operation_success = true;
-                    } else {
-                        tracing::warn!("Failed to set priority: {:?}", priority_result.err());
-                    }
+    pub fn get_require_trusted_signature(&self) -> bool {
+        self.require_trusted_signature
+    }
 
-                    // Set CPU affinity to the last CPU core
-                    tracing::info!("Setting CPU affinity to last core...");
-                    let cpu_count = num_cpus::get();
-                    let last_core_mask = 1_usize << (cpu_count - 1);
+    pub fn set_require_trusted_signature(&mut self, enabled: bool) {
+        tracing::debug!("Require trusted signature set to {}", enabled);
+        self.require_trusted_signature = enabled;
+    }
 
-                    let affinity_result = SetProcessAffinityMask(handle, last_core_mask);
-                    if affinity_result.is_ok() {
-                        process.affinity_modified = true;
-                        operation_success = true;
-                    } else {
-                        tracing::warn!("Failed to set CPU affinity: {:?}", affinity_result.err());
-                    }
+    pub fn is_instance_enabled(&self, process: &ProcessInfo) -> bool {
+        process
+            .associated_game
+            .as_ref()
+            .and_then(|game| self.instance_settings.get(game))
+            .map(|settings| settings.enabled)
+            .unwrap_or(true)
+    }
 
-                    if operation_success {
-                        process.is_optimized = true;
-                        tracing::info!("Process optimization completed");
-                    } else {
-                        process.is_optimized = false;
-                        tracing::warn!("No operations succeeded for this process");
-                    }
+    fn instance_throttle_mode(&self, process: &ProcessInfo) -> ThrottleMode {
+        process
+            .associated_game
+            .as_ref()
+            .and_then(|game| self.instance_settings.get(game))
+            .map(|settings| settings.throttle_mode)
+            .unwrap_or(self.throttle_mode)
+    }
+
+    pub fn get_instance_settings(&self) -> HashMap<String, AceInstanceSettings> {
+        self.instance_settings.clone()
+    }
 
-                    let _ = CloseHandle(handle);
-                    operation_success
+    pub fn set_instance_settings(&mut self, game: String, settings: AceInstanceSettings) {
+        self.instance_settings.insert(game, settings);
+    }
+
+    pub fn get_target_patterns(&self) -> Vec<ProcessNamePattern> {
+        self.target_matcher.patterns().to_vec()
+    }
+
+    pub fn set_target_patterns(&mut self, patterns: Vec<ProcessNamePattern>) -> Result<(), String> {
+        self.target_matcher = ProcessMatcher::new(patterns)?;
+        tracing::debug!(
+            "Target patterns set to {:?}",
+            self.target_matcher.patterns()
+        );
+        Ok(())
+    }
+
+    /// Restore every scanned process, processes without recorded settings (e.g. optimized
+    /// by an earlier instance) get normal priority and full affinity, returns the number reset
+    pub fn reset_all_processes(&mut self) -> Result<usize, String> {
+        let mut reset_count = 0;
+        let mut errors = Vec::new();
+
+        for process in self.processes.iter_mut() {
+            match restore_process(process) {
+                Ok(()) => reset_count += 1,
+                Err(e) => errors.push(e),
+            }
+        }
+        self.persist_state();
+
+        if errors.is_empty() {
+            Ok(reset_count)
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
+    fn find_allowed_process(&mut self, process_id: u32) -> Result<&mut ProcessInfo, String> {
+        let process = self
+            .processes
+            .iter_mut()
+            .find(|p| p.process_id == process_id)
+            .ok_or_else(|| {
+                format!(
+                    "Process with PID {} not found in scanned processes",
+                    process_id
+                )
+            })?;
+
+        check_allowed(process, &self.target_matcher)?;
+        if let Err(e) = check_signature(process) {
+            if self.require_trusted_signature {
+                return Err(e);
+            }
+            tracing::warn!("{}", e);
+        }
+
+        Ok(process)
+    }
+
+    /// Fully pause an ACE process, e.g. during loading screens
+    pub fn suspend_process(&mut self, process_id: u32) -> Result<String, String> {
+        let process = self.find_allowed_process(process_id)?;
+        if process.is_suspended {
+            return Err(format!("Process {} is already suspended", process_id));
+        }
+
+        tracing::warn!(
+            "Suspending {} (PID: {}), the game may refuse to run or disconnect while ACE is paused",
+            process.process_name,
+            process_id
+        );
+        suspend_process(process_id)?;
+        process.is_suspended = true;
+
+        Ok(format!(
+            "Process {} (PID: {}) suspended",
+            process.process_name, process_id
+        ))
+    }
+
+    pub fn resume_process(&mut self, process_id: u32) -> Result<String, String> {
+        let process = self.find_allowed_process(process_id)?;
+        if !process.is_suspended {
+            return Err(format!("Process {} is not suspended", process_id));
+        }
+
+        resume_process(process_id)?;
+        process.is_suspended = false;
+        tracing::info!("Resumed {} (PID: {})", process.process_name, process_id);
+
+        Ok(format!(
+            "Process {} (PID: {}) resumed",
+            process.process_name, process_id
+        ))
+    }
+
+    /// Put a single process back to its recorded settings
+    pub fn restore_single_process(&mut self, process_id: u32) -> Result<String, String> {
+        let process = self.find_allowed_process(process_id)?;
+        if !process.is_modified() {
+            return Err(format!("Process {} has not been modified", process_id));
+        }
+
+        restore_process(process)?;
+        let message = format!(
+            "Process {} (PID: {}) restored",
+            process.process_name, process_id
+        );
+        self.persist_state();
+
+        Ok(message)
+    }
+
+    /// Put all processes with recorded settings back to them, including the ones
+    /// recovered from the state file of an earlier run
+    pub fn restore_all_processes(&mut self) -> Result<String, String> {
+        let results = self.restore_processes();
+        let restored_count = results.iter().filter(|r| r.success).count();
+        let errors: Vec<String> = results.into_iter().filter_map(|r| r.error).collect();
+
+        if restored_count == 0 && errors.is_empty() {
+            return Err("No optimized processes to restore.".to_string());
+        }
+
+        if restored_count == 0 {
+            return Err(errors.join("; "));
+        }
+
+        Ok(format!(
+            "Process restore completed: Restored {} processes, Failed {} processes",
+            restored_count,
+            errors.len()
+        ))
+    }
+
+    /// Revert every modified process and report the outcome per process,
+    /// an empty list when nothing was modified
+    pub fn restore_processes(&mut self) -> Vec<ProcessRestoreResult> {
+        let mut results = Vec::new();
+
+        for process in self.processes.iter_mut().filter(|p| p.is_modified()) {
+            let result = restore_process(process);
+            if let Err(e) = &result {
+                tracing::warn!("{}", e);
+            }
+
+            results.push(ProcessRestoreResult {
+                process_id: process.process_id,
+                process_name: process.process_name.clone(),
+                success: result.is_ok(),
+                error: result.err(),
+            });
+        }
+        self.persist_state();
+
+        results
+    }
+
+    /// Save original settings to `path` from now on and pick up the ones
+    /// left there by an earlier run, e.g. after a crash
+    pub fn set_state_file(&mut self, path: PathBuf) {
+        match saved_state::load(&path) {
+            Ok(states) => {
+                self.recovered_states = states.into_iter().filter(|s| s.is_running()).collect();
+                if !self.recovered_states.is_empty() {
+                    tracing::warn!(
+                        "Found {} processes still modified by an earlier run",
+                        self.recovered_states.len()
+                    );
                 }
-                None => false,
             }
+            Err(e) => tracing::warn!("{}", e),
+        }
+
+        self.state_file = Some(path);
+    }
+
+    /// Saved states of the processes that are still modified and can be reverted
+    pub fn get_saved_states(&self) -> Vec<SavedProcessState> {
+        let mut states = self.recovered_states.clone();
+        states.extend(self.processes.iter().filter_map(|p| {
+            Some(SavedProcessState {
+                process_id: p.process_id,
+                process_name: p.process_name.clone(),
+                start_time: get_process_start_time(p.process_id).ok()?,
+                original_settings: p.original_settings?,
+            })
+        }));
+        states
+    }
+
+    fn persist_state(&self) {
+        let Some(path) = &self.state_file else {
+            return;
+        };
+
+        if let Err(e) = saved_state::save(path, &self.get_saved_states()) {
+            tracing::warn!("{}", e);
         }
     }
 
@@ -274,6 +1549,25 @@ impl AceProcessController {
         &self.processes
     }
 
+    pub fn get_process_tree(&self) -> Vec<AceProcessNode> {
+        build_process_tree(&self.processes)
+    }
+
+    /// Report every known anti-cheat running, whether it is optimized or not
+    pub fn detect_anti_cheats(&self) -> Result<Vec<DetectedAntiCheat>, String> {
+        let entries = query_processes().or_else(|_| snapshot_processes())?;
+        Ok(self.anti_cheat_catalog.detect(&entries))
+    }
+
+    pub fn get_optimized_anti_cheats(&self) -> Vec<AntiCheatFamily> {
+        self.anti_cheat_catalog.optimized_families().to_vec()
+    }
+
+    pub fn set_anti_cheat_optimized(&mut self, family: AntiCheatFamily, enabled: bool) {
+        tracing::debug!("Optimization of {:?} set to {}", family, enabled);
+        self.anti_cheat_catalog.set_optimized(family, enabled);
+    }
+
     pub fn get_privileges_enabled(&self) -> bool {
         self.privileges_enabled
     }
@@ -283,10 +1577,7 @@ impl AceProcessController {
     }
 
     pub fn get_optimized_count(&self) -> usize {
-        self.processes
-            .iter()
-            .filter(|p| p.is_optimized)
-            .count()
+        self.processes.iter().filter(|p| p.is_optimized).count()
     }
 
     pub fn clear_processes(&mut self) {
@@ -297,3 +1588,40 @@ impl AceProcessController {
         !self.processes.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_associated_game_walks_up_the_tree() {
+        let parents: HashMap<u32, (u32, String)> = [
+            (100, (50, consts::ACE_GUARD_64_PROCESS_NAME.to_string())),
+            (50, (20, "helper.exe".to_string())),
+            (20, (10, consts::DELTA_FORCE_PROCESS_NAME.to_string())),
+            (10, (4, "wegame.exe".to_string())),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            resolve_associated_game(&parents, 100).as_deref(),
+            Some("Delta Force")
+        );
+    }
+
+    #[test]
+    fn test_resolve_associated_game_falls_back_to_launcher() {
+        let parents: HashMap<u32, (u32, String)> = [
+            (100, (10, consts::ACE_GUARD_64_PROCESS_NAME.to_string())),
+            (10, (100, "wegame.exe".to_string())),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            resolve_associated_game(&parents, 100).as_deref(),
+            Some("WeGame")
+        );
+    }
+}
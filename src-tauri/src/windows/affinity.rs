@@ -0,0 +1,215 @@
+use crate::windows::topology::CpuTopology;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Type)]
+pub enum AffinityStrategy {
+    /// Pin to the last logical processor
+    #[default]
+    LastCore,
+    /// Pin to the last two logical processors
+    LastTwoCores,
+    /// Pin to the efficiency cores of a hybrid CPU
+    ECoresOnly,
+    /// Pin to the CCD with the smallest L3 cache, e.g. the non-X3D die
+    SmallestCacheCcd,
+    /// Pin to an explicit affinity mask
+    CustomMask(u64),
+}
+
+fn processors_to_mask(processors: &[u32]) -> usize {
+    processors
+        .iter()
+        .filter(|&&p| p < usize::BITS)
+        .fold(0usize, |mask, &p| mask | (1 << p))
+}
+
+fn last_cores_mask(logical_processor_count: u32, count: u32) -> usize {
+    let count = count.min(logical_processor_count).max(1);
+    let first = logical_processor_count - count;
+    processors_to_mask(&(first..logical_processor_count).collect::<Vec<_>>())
+}
+
+/// Compute the affinity mask for a strategy, falling back to the last core
+/// when the topology does not support it
+pub fn compute_affinity_mask(
+    strategy: AffinityStrategy,
+    topology: Option<&CpuTopology>,
+    logical_processor_count: u32,
+) -> Result<usize, String> {
+    if logical_processor_count == 0 {
+        return Err("No logical processors available".to_string());
+    }
+
+    let logical_processor_count = logical_processor_count.min(usize::BITS);
+    let system_mask = last_cores_mask(logical_processor_count, logical_processor_count);
+    let fallback = last_cores_mask(logical_processor_count, 1);
+
+    let mask = match strategy {
+        AffinityStrategy::LastCore => fallback,
+        AffinityStrategy::LastTwoCores => last_cores_mask(logical_processor_count, 2),
+        AffinityStrategy::ECoresOnly => {
+            let processors = topology
+                .map(|t| t.efficiency_processors())
+                .unwrap_or_default();
+
+            if processors.is_empty() {
+                tracing::warn!("No efficiency cores detected, falling back to the last core");
+                fallback
+            } else {
+                processors_to_mask(&processors)
+            }
+        }
+        AffinityStrategy::SmallestCacheCcd => {
+            let caches = topology.map(|t| t.l3_caches.as_slice()).unwrap_or_default();
+
+            // prefer the later die when cache sizes are equal
+            match caches.iter().rev().min_by_key(|c| c.size) {
+                Some(cache) if caches.len() > 1 => processors_to_mask(&cache.logical_processors),
+                _ => {
+                    tracing::warn!("Only one CCD detected, falling back to the last core");
+                    fallback
+                }
+            }
+        }
+        AffinityStrategy::CustomMask(mask) => {
+            let mask = mask as usize & system_mask;
+            if mask == 0 {
+                return Err("Custom affinity mask does not contain any available core".to_string());
+            }
+            mask
+        }
+    };
+
+    Ok(mask & system_mask)
+}
+
+/// Custom strategy for the logical processors picked in the core picker
+pub fn strategy_from_processors(processors: &[u32]) -> Result<AffinityStrategy, String> {
+    if processors.is_empty() {
+        return Err("Select at least one logical processor".to_string());
+    }
+
+    if let Some(processor) = processors.iter().find(|&&p| p >= u64::BITS) {
+        return Err(format!(
+            "Logical processor {} is outside of processor group 0",
+            processor
+        ));
+    }
+
+    Ok(AffinityStrategy::CustomMask(
+        processors_to_mask(processors) as u64
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::windows::topology::{CacheGroup, PhysicalCore};
+
+    fn hybrid_topology() -> CpuTopology {
+        CpuTopology {
+            logical_processor_count: 8,
+            cores: vec![
+                PhysicalCore {
+                    logical_processors: vec![0, 1],
+                    efficiency_class: 1,
+                },
+                PhysicalCore {
+                    logical_processors: vec![2, 3],
+                    efficiency_class: 1,
+                },
+                PhysicalCore {
+                    logical_processors: vec![4],
+                    efficiency_class: 0,
+                },
+                PhysicalCore {
+                    logical_processors: vec![5],
+                    efficiency_class: 0,
+                },
+                PhysicalCore {
+                    logical_processors: vec![6],
+                    efficiency_class: 0,
+                },
+                PhysicalCore {
+                    logical_processors: vec![7],
+                    efficiency_class: 0,
+                },
+            ],
+            l3_caches: vec![CacheGroup {
+                level: 3,
+                size: 24 * 1024 * 1024,
+                logical_processors: (0..8).collect(),
+            }],
+            is_hybrid: true,
+        }
+    }
+
+    #[test]
+    fn test_last_core_strategies() {
+        assert_eq!(
+            compute_affinity_mask(AffinityStrategy::LastCore, None, 8),
+            Ok(0b1000_0000)
+        );
+        assert_eq!(
+            compute_affinity_mask(AffinityStrategy::LastTwoCores, None, 8),
+            Ok(0b1100_0000)
+        );
+        assert_eq!(
+            compute_affinity_mask(AffinityStrategy::LastTwoCores, None, 1),
+            Ok(0b1)
+        );
+    }
+
+    #[test]
+    fn test_efficiency_cores_strategy() {
+        let topology = hybrid_topology();
+        assert_eq!(
+            compute_affinity_mask(AffinityStrategy::ECoresOnly, Some(&topology), 8),
+            Ok(0b1111_0000)
+        );
+    }
+
+    #[test]
+    fn test_smallest_cache_ccd_strategy() {
+        let mut topology = hybrid_topology();
+        topology.l3_caches = vec![
+            CacheGroup {
+                level: 3,
+                size: 96 * 1024 * 1024,
+                logical_processors: (0..4).collect(),
+            },
+            CacheGroup {
+                level: 3,
+                size: 32 * 1024 * 1024,
+                logical_processors: (4..8).collect(),
+            },
+        ];
+
+        assert_eq!(
+            compute_affinity_mask(AffinityStrategy::SmallestCacheCcd, Some(&topology), 8),
+            Ok(0b1111_0000)
+        );
+    }
+
+    #[test]
+    fn test_strategy_from_processors() {
+        assert_eq!(
+            strategy_from_processors(&[6, 7]),
+            Ok(AffinityStrategy::CustomMask(0b1100_0000))
+        );
+        assert!(strategy_from_processors(&[]).is_err());
+        assert!(strategy_from_processors(&[64]).is_err());
+    }
+
+    #[test]
+    fn test_custom_mask_is_clamped_to_available_cores() {
+        assert_eq!(
+            compute_affinity_mask(AffinityStrategy::CustomMask(0b11_0000_0001), None, 8),
+            Ok(0b1)
+        );
+        assert!(
+            compute_affinity_mask(AffinityStrategy::CustomMask(0b1_0000_0000), None, 8).is_err()
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{consts, windows::process_matcher::ProcessMatcher};
+use std::path::{Component, Path};
+
+/// System processes that must never be modified, regardless of any rule
+pub const PROTECTED_SYSTEM_PROCESSES: &[&str] = &[
+    "System",
+    "Registry",
+    "smss.exe",
+    "csrss.exe",
+    "wininit.exe",
+    "winlogon.exe",
+    "services.exe",
+    "lsass.exe",
+    "svchost.exe",
+    "dwm.exe",
+    "explorer.exe",
+];
+
+pub fn is_protected_process(process_name: &str) -> bool {
+    PROTECTED_SYSTEM_PROCESSES
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(process_name))
+}
+
+/// Check that a process is a known ACE component before it gets modified,
+/// names matched by the configured target patterns still have to live in the ACE directory
+pub fn check_process_allowed(
+    process_name: &str,
+    process_path: &str,
+    targets: &ProcessMatcher,
+) -> Result<(), String> {
+    if is_protected_process(process_name) {
+        return Err(format!(
+            "Refusing to modify system process {}",
+            process_name
+        ));
+    }
+
+    let known_component = consts::ACE_ALLOWED_PROCESS_NAMES
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(process_name));
+
+    if !known_component && !targets.is_match(process_name) {
+        return Err(format!(
+            "Refusing to modify {}: not a known ACE component",
+            process_name
+        ));
+    }
+
+    // ACE is installed either globally or inside the game directory,
+    // both layouts keep the binaries below an AntiCheatExpert folder
+    check_install_location(process_name, process_path, consts::ACE_INSTALL_DIR_NAME)
+}
+
+/// Check a helper spawned by an allowed ACE process, being started by ACE replaces
+/// the name check but the executable still has to live in the ACE directory
+pub fn check_child_process_allowed(process_name: &str, process_path: &str) -> Result<(), String> {
+    if is_protected_process(process_name) {
+        return Err(format!(
+            "Refusing to modify system process {}",
+            process_name
+        ));
+    }
+
+    check_install_location(process_name, process_path, consts::ACE_INSTALL_DIR_NAME)
+}
+
+/// Check that the executable lives below a folder named `install_dir`
+pub fn check_install_location(
+    process_name: &str,
+    process_path: &str,
+    install_dir: &str,
+) -> Result<(), String> {
+    let path = Path::new(process_path);
+    if !path.is_absolute() {
+        return Err(format!(
+            "Refusing to modify {}: executable path could not be verified",
+            process_name
+        ));
+    }
+
+    let file_name_matches = path
+        .file_name()
+        .and_then(|f| f.to_str())
+        .map(|f| f.eq_ignore_ascii_case(process_name))
+        .unwrap_or(false);
+
+    let in_install_dir = path.components().any(|c| match c {
+        Component::Normal(part) => part
+            .to_str()
+            .map(|p| p.eq_ignore_ascii_case(install_dir))
+            .unwrap_or(false),
+        _ => false,
+    });
+
+    if !file_name_matches || !in_install_dir {
+        return Err(format!(
+            "Refusing to modify {}: unexpected location {}",
+            process_name, process_path
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::windows::process_matcher::ProcessNamePattern;
+
+    fn check(process_name: &str, process_path: &str) -> Result<(), String> {
+        check_process_allowed(process_name, process_path, &ProcessMatcher::default())
+    }
+
+    #[test]
+    fn test_allows_ace_components() {
+        assert!(check(
+            "SGuard64.exe",
+            r"C:\Program Files\AntiCheatExpert\SGuard\x64\SGuard64.exe"
+        )
+        .is_ok());
+        assert!(check(
+            "SGuard64.exe",
+            r"D:\Games\Delta Force\Win64\anticheatexpert\SGuard64.exe"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_rejects_lookalikes_and_system_processes() {
+        assert!(check("SGuard64.exe", r"C:\Users\Public\SGuard64.exe").is_err());
+        assert!(check("SGuard64.exe", "Access Denied").is_err());
+        assert!(check("lsass.exe", r"C:\Program Files\AntiCheatExpert\lsass.exe").is_err());
+        assert!(check(
+            "notepad.exe",
+            r"C:\Program Files\AntiCheatExpert\notepad.exe"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_pattern_targets_still_require_ace_directory() {
+        let targets =
+            ProcessMatcher::new(vec![ProcessNamePattern::Glob("SGuard*".to_string())]).unwrap();
+
+        assert!(check_process_allowed(
+            "SGuard64_v2.exe",
+            r"C:\Program Files\AntiCheatExpert\SGuard\x64\SGuard64_v2.exe",
+            &targets
+        )
+        .is_ok());
+        assert!(check_process_allowed(
+            "SGuard64_v2.exe",
+            r"C:\Users\Public\SGuard64_v2.exe",
+            &targets
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_child_processes_require_ace_directory() {
+        assert!(check_child_process_allowed(
+            "AceHelper64.exe",
+            r"C:\Program Files\AntiCheatExpert\SGuard\x64\AceHelper64.exe"
+        )
+        .is_ok());
+        assert!(check_child_process_allowed(
+            "conhost.exe",
+            r"C:\Windows\System32\conhost.exe"
+        )
+        .is_err());
+        assert!(check_child_process_allowed(
+            "svchost.exe",
+            r"C:\Program Files\AntiCheatExpert\svchost.exe"
+        )
+        .is_err());
+    }
+}
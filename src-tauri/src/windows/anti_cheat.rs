@@ -0,0 +1,216 @@
+use crate::{
+    consts,
+    windows::{
+        allowlist::{check_install_location, is_protected_process},
+        process_list::ProcessEntry,
+        utils::get_process_path,
+    },
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+pub enum AntiCheatFamily {
+    /// Tencent AntiCheatExpert, the scanner this tool is built around
+    Ace,
+    NProtect,
+    BattlEye,
+    EasyAntiCheat,
+}
+
+/// Built-in description of the user mode processes of one anti-cheat
+#[derive(Debug)]
+pub struct AntiCheatSignature {
+    pub family: AntiCheatFamily,
+    pub name: &'static str,
+    pub process_names: &'static [&'static str],
+    /// Folder the executables have to live in before they may be modified
+    pub install_dir: &'static str,
+}
+
+pub const ANTI_CHEAT_SIGNATURES: &[AntiCheatSignature] = &[
+    AntiCheatSignature {
+        family: AntiCheatFamily::Ace,
+        name: "AntiCheatExpert",
+        process_names: consts::ACE_ALLOWED_PROCESS_NAMES,
+        install_dir: consts::ACE_INSTALL_DIR_NAME,
+    },
+    AntiCheatSignature {
+        family: AntiCheatFamily::NProtect,
+        name: "nProtect GameGuard",
+        process_names: &["GameMon.des", "GameMon64.des"],
+        install_dir: "GameGuard",
+    },
+    AntiCheatSignature {
+        family: AntiCheatFamily::BattlEye,
+        name: "BattlEye",
+        process_names: &["BEService.exe", "BEService_x64.exe"],
+        install_dir: "BattlEye",
+    },
+    AntiCheatSignature {
+        family: AntiCheatFamily::EasyAntiCheat,
+        name: "Easy Anti-Cheat",
+        process_names: &["EasyAntiCheat.exe", "EasyAntiCheat_EOS.exe"],
+        install_dir: "EasyAntiCheat",
+    },
+];
+
+/// An anti-cheat process found on the system
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct DetectedAntiCheat {
+    pub family: AntiCheatFamily,
+    pub name: String,
+    pub process_id: u32,
+    pub process_name: String,
+    pub process_path: String,
+    /// Whether the optimizer picks up this process as well
+    pub optimization_enabled: bool,
+}
+
+/// Recognizes anti-cheat processes, only ACE is optimized unless a family is opted in
+#[derive(Debug, Clone, Default)]
+pub struct AntiCheatCatalog {
+    optimized_families: Vec<AntiCheatFamily>,
+}
+
+impl AntiCheatCatalog {
+    pub fn signature(family: AntiCheatFamily) -> &'static AntiCheatSignature {
+        ANTI_CHEAT_SIGNATURES
+            .iter()
+            .find(|s| s.family == family)
+            .expect("every family has a signature")
+    }
+
+    /// Find the signature a process name belongs to
+    pub fn identify(process_name: &str) -> Option<&'static AntiCheatSignature> {
+        ANTI_CHEAT_SIGNATURES.iter().find(|s| {
+            s.process_names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(process_name))
+        })
+    }
+
+    pub fn optimized_families(&self) -> &[AntiCheatFamily] {
+        &self.optimized_families
+    }
+
+    pub fn is_optimized(&self, family: AntiCheatFamily) -> bool {
+        family == AntiCheatFamily::Ace || self.optimized_families.contains(&family)
+    }
+
+    pub fn set_optimized(&mut self, family: AntiCheatFamily, enabled: bool) {
+        self.optimized_families.retain(|f| *f != family);
+        // ACE is always optimized through the target patterns
+        if enabled && family != AntiCheatFamily::Ace {
+            self.optimized_families.push(family);
+        }
+    }
+
+    /// The family of a process the optimizer should pick up besides the ACE targets
+    pub fn optimized_family_of(&self, process_name: &str) -> Option<AntiCheatFamily> {
+        Self::identify(process_name)
+            .map(|s| s.family)
+            .filter(|family| self.optimized_families.contains(family))
+    }
+
+    /// Report every known anti-cheat process among the entries
+    pub fn detect(&self, entries: &[ProcessEntry]) -> Vec<DetectedAntiCheat> {
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let signature = Self::identify(&entry.process_name)?;
+                Some(DetectedAntiCheat {
+                    family: signature.family,
+                    name: signature.name.to_string(),
+                    process_id: entry.process_id,
+                    process_name: entry.process_name.clone(),
+                    process_path: get_process_path(entry.process_id)
+                        .unwrap_or_else(|_| "Access Denied".to_string()),
+                    optimization_enabled: self.is_optimized(signature.family),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Check a process of an opted-in family before it gets modified
+pub fn check_anti_cheat_allowed(
+    family: AntiCheatFamily,
+    process_name: &str,
+    process_path: &str,
+) -> Result<(), String> {
+    if is_protected_process(process_name) {
+        return Err(format!(
+            "Refusing to modify system process {}",
+            process_name
+        ));
+    }
+
+    let signature = AntiCheatCatalog::signature(family);
+    if AntiCheatCatalog::identify(process_name).map(|s| s.family) != Some(family) {
+        return Err(format!(
+            "Refusing to modify {}: not a known {} component",
+            process_name, signature.name
+        ));
+    }
+
+    check_install_location(process_name, process_path, signature.install_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identify() {
+        assert_eq!(
+            AntiCheatCatalog::identify("sguard64.exe").map(|s| s.family),
+            Some(AntiCheatFamily::Ace)
+        );
+        assert_eq!(
+            AntiCheatCatalog::identify("BEService_x64.exe").map(|s| s.family),
+            Some(AntiCheatFamily::BattlEye)
+        );
+        assert!(AntiCheatCatalog::identify("notepad.exe").is_none());
+    }
+
+    #[test]
+    fn test_optimization_is_opt_in() {
+        let mut catalog = AntiCheatCatalog::default();
+        assert_eq!(catalog.optimized_family_of("GameMon.des"), None);
+        // ACE is scanned through the target patterns instead
+        catalog.set_optimized(AntiCheatFamily::Ace, true);
+        assert_eq!(catalog.optimized_family_of("SGuard64.exe"), None);
+
+        catalog.set_optimized(AntiCheatFamily::NProtect, true);
+        assert_eq!(
+            catalog.optimized_family_of("GameMon.des"),
+            Some(AntiCheatFamily::NProtect)
+        );
+
+        catalog.set_optimized(AntiCheatFamily::NProtect, false);
+        assert_eq!(catalog.optimized_family_of("GameMon.des"), None);
+    }
+
+    #[test]
+    fn test_check_anti_cheat_allowed() {
+        assert!(check_anti_cheat_allowed(
+            AntiCheatFamily::BattlEye,
+            "BEService_x64.exe",
+            r"C:\Program Files (x86)\Common Files\BattlEye\BEService_x64.exe"
+        )
+        .is_ok());
+        assert!(check_anti_cheat_allowed(
+            AntiCheatFamily::BattlEye,
+            "BEService_x64.exe",
+            r"C:\Users\Public\BEService_x64.exe"
+        )
+        .is_err());
+        assert!(check_anti_cheat_allowed(
+            AntiCheatFamily::BattlEye,
+            "GameMon.des",
+            r"C:\Games\GameGuard\GameMon.des"
+        )
+        .is_err());
+    }
+}
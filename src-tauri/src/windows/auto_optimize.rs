@@ -0,0 +1,335 @@
+use crate::{
+    consts,
+    history::{self, HistoryKind},
+    notifications::{notify, NotificationKind},
+    windows::{
+        background_pause,
+        process_list::find_processes_by_name,
+        scheduler::{self, ScheduleRule, SchedulerState},
+        AceProcessControllerState,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{
+    collections::HashSet,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+const GAME_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const ACE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AutoOptimizeConfig {
+    pub enabled: bool,
+    /// Executable names of the games that trigger an optimization
+    pub games: Vec<String>,
+    /// How long to wait for ACE to start after a game launch or a scheduled optimization
+    pub ace_wait_timeout_secs: u32,
+}
+
+impl Default for AutoOptimizeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            games: consts::KNOWN_GAMES
+                .iter()
+                .map(|(_, executable)| executable.to_string())
+                .collect(),
+            ace_wait_timeout_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum GameLaunchStage {
+    /// The game started, waiting for ACE
+    Detected,
+    Optimized,
+    Failed,
+    /// ACE did not show up in time
+    TimedOut,
+}
+
+// Emitted while a game launch is handled
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct GameLaunchEvent {
+    pub game: String,
+    pub process_id: u32,
+    pub stage: GameLaunchStage,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl GameLaunchEvent {
+    fn emit_stage(
+        app: &AppHandle,
+        game: &str,
+        process_id: u32,
+        stage: GameLaunchStage,
+        message: &str,
+    ) {
+        let _ = GameLaunchEvent {
+            game: game.to_string(),
+            process_id,
+            stage,
+            message: message.to_string(),
+            timestamp: Utc::now(),
+        }
+        .emit(app);
+    }
+}
+
+/// What asked for the optimization that runs once ACE appears
+#[derive(Debug, Clone)]
+pub enum OptimizationRequest {
+    GameLaunch { game: String, process_id: u32 },
+    Rule(ScheduleRule),
+}
+
+/// Tracks running game processes to detect new launches
+#[derive(Debug, Default)]
+pub struct AutoOptimizer {
+    config: AutoOptimizeConfig,
+    running: HashSet<u32>,
+    /// Requests answered by the optimization currently waiting for ACE
+    pending: Vec<OptimizationRequest>,
+}
+
+impl AutoOptimizer {
+    pub fn config(&self) -> &AutoOptimizeConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: AutoOptimizeConfig) {
+        self.config = config;
+    }
+
+    /// Return the games started since the last call, games running when
+    /// the detector is enabled count as launched as well
+    pub fn take_launched(&mut self, process_ids: &[u32]) -> Vec<u32> {
+        self.running
+            .retain(|process_id| process_ids.contains(process_id));
+
+        process_ids
+            .iter()
+            .copied()
+            .filter(|process_id| self.running.insert(*process_id))
+            .collect()
+    }
+}
+
+// State wrapper for the game launch detector
+#[derive(Default)]
+pub struct AutoOptimizeState(pub Mutex<AutoOptimizer>);
+
+/// Wait for ACE to appear and optimize it, returns the stage reached and a message
+fn optimize_when_ace_appears(app: &AppHandle, timeout: Duration) -> (GameLaunchStage, String) {
+    let start = Instant::now();
+
+    while start.elapsed() < timeout {
+        std::thread::sleep(ACE_POLL_INTERVAL);
+
+        let controller_state = app.state::<AceProcessControllerState>();
+        let Ok(mut controller) = controller_state.0.lock() else {
+            continue;
+        };
+
+        if controller.scan_ace_guard_processes().is_err() {
+            continue;
+        }
+
+        return match tauri::async_runtime::block_on(controller.optimize_all_processes()) {
+            Ok(message) => (GameLaunchStage::Optimized, message),
+            Err(e) => (GameLaunchStage::Failed, e),
+        };
+    }
+
+    (
+        GameLaunchStage::TimedOut,
+        format!(
+            "No ACE Guard process appeared within {} seconds",
+            timeout.as_secs()
+        ),
+    )
+}
+
+/// Optimize ACE once it appears on a background thread, requests made while an
+/// optimization is already waiting are answered by that one
+pub fn request_optimization(app: &AppHandle, request: OptimizationRequest) {
+    let timeout = {
+        let Ok(mut optimizer) = app.state::<AutoOptimizeState>().0.lock() else {
+            return;
+        };
+
+        optimizer.pending.push(request);
+        if optimizer.pending.len() > 1 {
+            return;
+        }
+
+        Duration::from_secs(optimizer.config().ace_wait_timeout_secs as u64)
+    };
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let (stage, message) = optimize_when_ace_appears(&app, timeout);
+
+        let requests = match app.state::<AutoOptimizeState>().0.lock() {
+            Ok(mut optimizer) => std::mem::take(&mut optimizer.pending),
+            Err(_) => Vec::new(),
+        };
+
+        report_optimization(&app, stage, &message, requests);
+    });
+}
+
+fn report_optimization(
+    app: &AppHandle,
+    stage: GameLaunchStage,
+    message: &str,
+    requests: Vec<OptimizationRequest>,
+) {
+    match stage {
+        GameLaunchStage::Optimized => {
+            tracing::info!("Auto optimization: {}", message);
+            notify(app, NotificationKind::OptimizationApplied, message);
+        }
+        GameLaunchStage::Failed => {
+            tracing::warn!("Auto optimization: {}", message);
+            notify(app, NotificationKind::OptimizationFailed, message);
+        }
+        _ => tracing::warn!("Auto optimization: {}", message),
+    }
+    if stage != GameLaunchStage::TimedOut {
+        history::record(
+            HistoryKind::Optimization,
+            stage == GameLaunchStage::Optimized,
+            message,
+            None,
+        );
+    }
+
+    for request in requests {
+        match request {
+            OptimizationRequest::GameLaunch { game, process_id } => {
+                GameLaunchEvent::emit_stage(app, &game, process_id, stage, message);
+            }
+            OptimizationRequest::Rule(rule) => {
+                scheduler::emit_rule_result(
+                    app,
+                    &rule,
+                    stage == GameLaunchStage::Optimized,
+                    message,
+                );
+            }
+        }
+    }
+}
+
+/// Spawn the background thread detecting game starts and exits, for the auto
+/// optimization and the scheduled rules alike
+pub fn spawn_game_launch_detector(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(GAME_POLL_INTERVAL);
+
+        let config = match app.state::<AutoOptimizeState>().0.lock() {
+            Ok(optimizer) => optimizer.config().clone(),
+            Err(_) => continue,
+        };
+        let scheduled_games = match app.state::<SchedulerState>().0.lock() {
+            Ok(scheduler) => scheduler.watched_games(),
+            Err(_) => continue,
+        };
+
+        // look up every executable once, even when both watch it
+        let mut executables: HashSet<String> = scheduled_games.clone();
+        if config.enabled {
+            executables.extend(config.games.iter().map(|game| game.to_lowercase()));
+        }
+
+        let mut running = HashSet::new();
+        let mut games = Vec::new();
+        for executable in executables {
+            match find_processes_by_name(&executable) {
+                Ok(processes) if processes.is_empty() => {}
+                Ok(processes) => {
+                    if config
+                        .games
+                        .iter()
+                        .any(|game| game.eq_ignore_ascii_case(&executable))
+                    {
+                        games.extend(processes);
+                    }
+                    running.insert(executable);
+                }
+                Err(e) => tracing::debug!("{}", e),
+            }
+        }
+
+        let triggered = match app.state::<SchedulerState>().0.lock() {
+            Ok(mut scheduler) => scheduler.take_triggered(running),
+            Err(_) => continue,
+        };
+
+        // starts and exits during a pause are dropped, not queued
+        if !background_pause::is_paused(&app) {
+            for rule in &triggered {
+                scheduler::run_rule(&app, rule);
+            }
+        }
+
+        if !config.enabled {
+            continue;
+        }
+
+        let process_ids: Vec<u32> = games.iter().map(|g| g.process_id).collect();
+        let launched = match app.state::<AutoOptimizeState>().0.lock() {
+            Ok(mut optimizer) => optimizer.take_launched(&process_ids),
+            Err(_) => continue,
+        };
+
+        for game in games.iter().filter(|g| launched.contains(&g.process_id)) {
+            tracing::info!(
+                "Detected game launch: {} (PID: {})",
+                game.process_name,
+                game.process_id
+            );
+            GameLaunchEvent::emit_stage(
+                &app,
+                &game.process_name,
+                game.process_id,
+                GameLaunchStage::Detected,
+                "Waiting for ACE Guard",
+            );
+
+            request_optimization(
+                &app,
+                OptimizationRequest::GameLaunch {
+                    game: game.process_name.clone(),
+                    process_id: game.process_id,
+                },
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_launches_are_reported_once() {
+        let mut optimizer = AutoOptimizer::default();
+
+        assert_eq!(optimizer.take_launched(&[10]), vec![10]);
+        assert!(optimizer.take_launched(&[10]).is_empty());
+
+        // the game was closed and started again
+        assert!(optimizer.take_launched(&[]).is_empty());
+        assert_eq!(optimizer.take_launched(&[11]), vec![11]);
+    }
+}
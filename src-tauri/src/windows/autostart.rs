@@ -0,0 +1,62 @@
+use crate::windows::registry;
+use std::path::Path;
+use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+/// Per-user Run key, started at logon without needing administrator rights
+const RUN_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Run";
+const RUN_VALUE_NAME: &str = "TencentAceTools";
+/// Command line flag that starts the GUI with its main window minimized
+pub const MINIMIZED_ARG: &str = "--minimized";
+
+fn autostart_command(executable: &Path) -> String {
+    format!("\"{}\" {}", executable.display(), MINIMIZED_ARG)
+}
+
+fn current_command() -> Result<String, String> {
+    let executable =
+        std::env::current_exe().map_err(|e| format!("Failed to get current executable: {}", e))?;
+    Ok(autostart_command(&executable))
+}
+
+/// Whether the Run key launches this executable, an entry left behind by
+/// a moved installation counts as disabled
+pub fn is_autostart_enabled() -> bool {
+    let Some(command) = registry::read_string(HKEY_CURRENT_USER, RUN_KEY, RUN_VALUE_NAME) else {
+        return false;
+    };
+
+    current_command().is_ok_and(|current| current.eq_ignore_ascii_case(&command))
+}
+
+/// Launch the GUI minimized at logon, the elevation prompt follows on start
+pub fn set_autostart_enabled(enabled: bool) -> Result<(), String> {
+    if enabled {
+        let command = current_command()?;
+        registry::write_string(HKEY_CURRENT_USER, RUN_KEY, RUN_VALUE_NAME, &command)?;
+        tracing::info!("Enabled autostart: {}", command);
+    } else {
+        registry::delete_value(HKEY_CURRENT_USER, RUN_KEY, RUN_VALUE_NAME)?;
+        tracing::info!("Disabled autostart");
+    }
+
+    Ok(())
+}
+
+pub fn is_started_minimized() -> bool {
+    std::env::args().any(|arg| arg == MINIMIZED_ARG)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autostart_command() {
+        assert_eq!(
+            autostart_command(Path::new(
+                r"C:\Program Files\Tencent Ace Tools\tencent-ace-tools.exe"
+            )),
+            r#""C:\Program Files\Tencent Ace Tools\tencent-ace-tools.exe" --minimized"#
+        );
+    }
+}
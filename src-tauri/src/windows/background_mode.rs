@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::Win32::{
+    Foundation::{HANDLE, NTSTATUS},
+    System::Threading::{
+        ProcessMemoryPriority, SetPriorityClass, SetProcessInformation, MEMORY_PRIORITY,
+        MEMORY_PRIORITY_INFORMATION, MEMORY_PRIORITY_VERY_LOW, PROCESS_CREATION_FLAGS,
+    },
+};
+
+/// `PROCESSINFOCLASS::ProcessIoPriority`
+const PROCESS_IO_PRIORITY: i32 = 33;
+/// `IO_PRIORITY_HINT::IoPriorityVeryLow`
+pub const IO_PRIORITY_VERY_LOW: u32 = 0;
+/// `IO_PRIORITY_HINT::IoPriorityNormal`, the default of every process
+pub const IO_PRIORITY_NORMAL: u32 = 2;
+
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtSetInformationProcess(
+        process_handle: HANDLE,
+        process_information_class: i32,
+        process_information: *const core::ffi::c_void,
+        process_information_length: u32,
+    ) -> NTSTATUS;
+}
+
+/// How the CPU priority of an ACE process is lowered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Type)]
+pub enum ThrottleMode {
+    /// Priority class only
+    #[default]
+    PriorityOnly,
+    /// Priority class plus very low I/O and memory priority, like `PROCESS_MODE_BACKGROUND_BEGIN`
+    Background,
+}
+
+/// Set the I/O priority hint of another process, the handle needs `PROCESS_SET_INFORMATION` access
+pub fn set_io_priority(handle: HANDLE, io_priority: u32) -> Result<(), String> {
+    let status = unsafe {
+        NtSetInformationProcess(
+            handle,
+            PROCESS_IO_PRIORITY,
+            &io_priority as *const u32 as *const _,
+            std::mem::size_of::<u32>() as u32,
+        )
+    };
+
+    // negative values are error codes
+    if status.0 < 0 {
+        Err(format!("Failed to set I/O priority: 0x{:08X}", status.0))
+    } else {
+        Ok(())
+    }
+}
+
+/// Set the memory priority of another process, pages of a lower priority are trimmed first.
+/// The handle needs `PROCESS_SET_INFORMATION` access
+pub fn set_memory_priority(handle: HANDLE, memory_priority: MEMORY_PRIORITY) -> Result<(), String> {
+    let information = MEMORY_PRIORITY_INFORMATION {
+        MemoryPriority: memory_priority,
+    };
+
+    unsafe {
+        SetProcessInformation(
+            handle,
+            ProcessMemoryPriority,
+            &information as *const MEMORY_PRIORITY_INFORMATION as *const _,
+            std::mem::size_of::<MEMORY_PRIORITY_INFORMATION>() as u32,
+        )
+    }
+    .map_err(|e| format!("Failed to set memory priority: {:?}", e))
+}
+
+/// Put another process into background processing mode.
+///
+/// `PROCESS_MODE_BACKGROUND_BEGIN` is only accepted for the calling process,
+/// so the three priorities it lowers are set one by one instead.
+/// The handle needs `PROCESS_SET_INFORMATION` access.
+pub fn enter_background_mode(
+    handle: HANDLE,
+    priority_class: PROCESS_CREATION_FLAGS,
+) -> Result<(), String> {
+    let mut failures = Vec::new();
+
+    unsafe {
+        if let Err(e) = SetPriorityClass(handle, priority_class) {
+            failures.push(format!("priority class: {:?}", e));
+        }
+
+        if let Err(e) = set_io_priority(handle, IO_PRIORITY_VERY_LOW) {
+            failures.push(e);
+        }
+
+        if let Err(e) = set_memory_priority(handle, MEMORY_PRIORITY_VERY_LOW) {
+            failures.push(e);
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Failed to enter background mode ({})",
+            failures.join(", ")
+        ))
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct BackgroundPauseStatus {
+    pub paused: bool,
+    pub paused_at: Option<DateTime<Utc>>,
+    /// When the background tasks resume on their own, `None` pauses until resumed
+    pub resume_at: Option<DateTime<Utc>>,
+}
+
+// Emitted whenever the background rescan and watchdog are paused or resumed
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct BackgroundPauseEvent {
+    pub status: BackgroundPauseStatus,
+}
+
+/// Temporary pause of the background rescan and the watchdog, e.g. while troubleshooting.
+/// Unlike disabling them in the config this is not persisted
+#[derive(Debug, Default)]
+pub struct BackgroundPause {
+    paused_at: Option<DateTime<Utc>>,
+    resume_at: Option<DateTime<Utc>>,
+}
+
+impl BackgroundPause {
+    pub fn pause(&mut self, now: DateTime<Utc>, duration: Option<Duration>) {
+        self.paused_at = Some(now);
+        self.resume_at = duration.map(|duration| now + duration);
+    }
+
+    pub fn resume(&mut self) {
+        self.paused_at = None;
+        self.resume_at = None;
+    }
+
+    /// Whether the pause expired and was lifted by this call
+    fn expire(&mut self, now: DateTime<Utc>) -> bool {
+        match self.resume_at {
+            Some(resume_at) if now >= resume_at => {
+                self.resume();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn status(&self) -> BackgroundPauseStatus {
+        BackgroundPauseStatus {
+            paused: self.paused_at.is_some(),
+            paused_at: self.paused_at,
+            resume_at: self.resume_at,
+        }
+    }
+}
+
+// State wrapper for the background pause
+#[derive(Default)]
+pub struct BackgroundPauseState(pub Mutex<BackgroundPause>);
+
+/// Checked by the background loops before every round, lifts an expired pause
+pub fn is_paused(app: &AppHandle) -> bool {
+    let (status, expired) = {
+        let state = app.state::<BackgroundPauseState>();
+        let Ok(mut pause) = state.0.lock() else {
+            return false;
+        };
+
+        let expired = pause.expire(Utc::now());
+        (pause.status(), expired)
+    };
+
+    if expired {
+        tracing::info!("Background tasks resumed, the pause expired");
+        emit_status(app, status.clone());
+    }
+
+    status.paused
+}
+
+pub fn emit_status(app: &AppHandle, status: BackgroundPauseStatus) {
+    let _ = BackgroundPauseEvent { status }.emit(app);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_expires_after_duration() {
+        let now = Utc::now();
+        let mut pause = BackgroundPause::default();
+
+        pause.pause(now, Some(Duration::minutes(5)));
+        assert!(pause.status().paused);
+        assert!(!pause.expire(now + Duration::minutes(4)));
+        assert!(pause.expire(now + Duration::minutes(5)));
+        assert_eq!(pause.status(), BackgroundPauseStatus::default());
+
+        // without a duration only resume lifts the pause
+        pause.pause(now, None);
+        assert!(!pause.expire(now + Duration::days(1)));
+        pause.resume();
+        assert!(!pause.status().paused);
+    }
+}
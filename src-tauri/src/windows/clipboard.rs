@@ -0,0 +1,156 @@
+use crate::windows::screenshot::ScreenShot;
+use windows::core::w;
+use windows::Win32::{
+    Foundation::{GlobalFree, HANDLE},
+    System::{
+        DataExchange::{
+            CloseClipboard, EmptyClipboard, OpenClipboard, RegisterClipboardFormatW,
+            SetClipboardData,
+        },
+        Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE},
+    },
+};
+
+/// `CF_UNICODETEXT`, UTF-16 text terminated by a null character
+const CF_UNICODETEXT: u32 = 13;
+/// `CF_DIB`, a BITMAPINFOHEADER followed by the pixels
+const CF_DIB: u32 = 8;
+const BITMAPINFOHEADER_SIZE: usize = 40;
+/// Uncompressed pixels
+const BI_RGB: u32 = 0;
+
+/// Closes the clipboard when dropped, so every early return releases it
+struct OpenedClipboard;
+
+impl OpenedClipboard {
+    fn open() -> Result<Self, String> {
+        unsafe { OpenClipboard(None) }
+            .map(|_| Self)
+            .map_err(|e| format!("Failed to open clipboard: {}", e))
+    }
+}
+
+impl Drop for OpenedClipboard {
+    fn drop(&mut self) {
+        let _ = unsafe { CloseClipboard() };
+    }
+}
+
+/// Copy `data` into movable global memory and hand it to the opened clipboard
+fn set_data(_clipboard: &OpenedClipboard, format: u32, data: &[u8]) -> Result<(), String> {
+    unsafe {
+        let memory = GlobalAlloc(GMEM_MOVEABLE, data.len())
+            .map_err(|e| format!("Failed to allocate clipboard memory: {}", e))?;
+
+        let pointer = GlobalLock(memory) as *mut u8;
+        if pointer.is_null() {
+            let _ = GlobalFree(Some(memory));
+            return Err("Failed to lock clipboard memory".to_string());
+        }
+        std::ptr::copy_nonoverlapping(data.as_ptr(), pointer, data.len());
+        // reports an error once the lock count drops to zero, which is the expected outcome
+        let _ = GlobalUnlock(memory);
+
+        // the system owns the memory once the data is set
+        if let Err(e) = SetClipboardData(format, Some(HANDLE(memory.0))) {
+            let _ = GlobalFree(Some(memory));
+            return Err(format!("Failed to set clipboard data: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace the clipboard content with `text`
+pub fn set_text(text: &str) -> Result<(), String> {
+    let data: Vec<u8> = text
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .flat_map(u16::to_le_bytes)
+        .collect();
+
+    let clipboard = OpenedClipboard::open()?;
+    unsafe { EmptyClipboard() }.map_err(|e| format!("Failed to empty clipboard: {}", e))?;
+    set_data(&clipboard, CF_UNICODETEXT, &data)?;
+
+    tracing::debug!(
+        "Copied {} characters to the clipboard",
+        text.chars().count()
+    );
+    Ok(())
+}
+
+/// Build a bottom-up 32 bit `CF_DIB` from RGBA pixels, the alpha channel is kept
+/// in the unused fourth byte
+fn dib_from_rgba(width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+    let mut dib = Vec::with_capacity(BITMAPINFOHEADER_SIZE + rgba.len());
+
+    // BITMAPINFOHEADER
+    dib.extend_from_slice(&(BITMAPINFOHEADER_SIZE as u32).to_le_bytes());
+    dib.extend_from_slice(&(width as i32).to_le_bytes());
+    // a positive height stores the rows bottom-up
+    dib.extend_from_slice(&(height as i32).to_le_bytes());
+    dib.extend_from_slice(&1u16.to_le_bytes());
+    dib.extend_from_slice(&32u16.to_le_bytes());
+    dib.extend_from_slice(&BI_RGB.to_le_bytes());
+    dib.extend_from_slice(&(rgba.len() as u32).to_le_bytes());
+    dib.extend_from_slice(&[0; 16]);
+
+    let row_length = width as usize * 4;
+    for row in rgba.chunks_exact(row_length.max(1)).rev() {
+        for pixel in row.chunks_exact(4) {
+            dib.extend_from_slice(&[pixel[2], pixel[1], pixel[0], pixel[3]]);
+        }
+    }
+
+    dib
+}
+
+/// Replace the clipboard content with an image, as a DIB for most apps and as PNG
+/// for the ones that keep transparency
+pub fn set_image(screenshot: &ScreenShot) -> Result<(), String> {
+    let image = image::load_from_memory(&screenshot.image_data)
+        .map_err(|e| format!("Failed to decode screenshot: {}", e))?
+        .to_rgba8();
+    let dib = dib_from_rgba(image.width(), image.height(), image.as_raw());
+
+    let clipboard = OpenedClipboard::open()?;
+    unsafe { EmptyClipboard() }.map_err(|e| format!("Failed to empty clipboard: {}", e))?;
+    set_data(&clipboard, CF_DIB, &dib)?;
+
+    if screenshot.format == "png" {
+        let png_format = unsafe { RegisterClipboardFormatW(w!("PNG")) };
+        if png_format == 0 {
+            tracing::warn!("Failed to register the PNG clipboard format");
+        } else {
+            set_data(&clipboard, png_format, &screenshot.image_data)?;
+        }
+    }
+
+    tracing::debug!(
+        "Copied a {}x{} image to the clipboard",
+        image.width(),
+        image.height()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dib_from_rgba_is_bottom_up_bgra() {
+        // 1x2 image, red on top of blue
+        let rgba = [255, 0, 0, 255, 0, 0, 255, 128];
+        let dib = dib_from_rgba(1, 2, &rgba);
+
+        assert_eq!(dib.len(), BITMAPINFOHEADER_SIZE + rgba.len());
+        assert_eq!(&dib[4..8], &1i32.to_le_bytes());
+        assert_eq!(&dib[8..12], &2i32.to_le_bytes());
+        assert_eq!(
+            &dib[BITMAPINFOHEADER_SIZE..],
+            &[255, 0, 0, 128, 0, 0, 255, 255]
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    notifications::{notify, NotificationKind},
+    windows::{usage::CpuUsageSampler, AceProcessControllerState},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_ALERT_HISTORY: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct CpuAlertConfig {
+    pub enabled: bool,
+    /// CPU usage in percent of all logical cores
+    pub threshold_percent: f32,
+    /// How long the usage must stay above the threshold before alerting
+    pub duration_secs: u32,
+    /// Re-apply the optimization to the offending process
+    pub auto_reapply: bool,
+}
+
+impl Default for CpuAlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_percent: 15.0,
+            duration_secs: 30,
+            auto_reapply: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct CpuAlertEvent {
+    pub process_id: u32,
+    pub process_name: String,
+    pub cpu_usage: f32,
+    pub threshold_percent: f32,
+    pub reapplied: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Tracks how long each process has been above the configured threshold
+#[derive(Debug, Default)]
+pub struct CpuAlertMonitor {
+    config: CpuAlertConfig,
+    exceeded_since: HashMap<u32, Instant>,
+    history: VecDeque<CpuAlertEvent>,
+}
+
+impl CpuAlertMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn config(&self) -> &CpuAlertConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: CpuAlertConfig) {
+        self.config = config;
+        self.exceeded_since.clear();
+    }
+
+    pub fn history(&self) -> Vec<CpuAlertEvent> {
+        self.history.iter().cloned().collect()
+    }
+
+    /// Feed a usage sample, returns true once the usage stayed above the threshold long enough
+    pub fn check(&mut self, process_id: u32, cpu_usage: f32, now: Instant) -> bool {
+        if cpu_usage < self.config.threshold_percent {
+            self.exceeded_since.remove(&process_id);
+            return false;
+        }
+
+        let since = *self.exceeded_since.entry(process_id).or_insert(now);
+        if now.duration_since(since) >= Duration::from_secs(self.config.duration_secs as u64) {
+            // restart the window so a persisting spike alerts again after another full duration
+            self.exceeded_since.insert(process_id, now);
+            return true;
+        }
+
+        false
+    }
+
+    pub fn record(&mut self, alert: CpuAlertEvent) {
+        if self.history.len() >= MAX_ALERT_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(alert);
+    }
+
+    pub fn retain(&mut self, process_ids: &[u32]) {
+        self.exceeded_since
+            .retain(|process_id, _| process_ids.contains(process_id));
+    }
+}
+
+// State wrapper for CpuAlertMonitor
+#[derive(Default)]
+pub struct CpuAlertState(pub Mutex<CpuAlertMonitor>);
+
+/// Spawn a background thread that samples ACE Guard cpu usage and raises alerts
+pub fn spawn_cpu_alert_monitor(app: AppHandle) {
+    std::thread::spawn(move || {
+        let mut sampler = CpuUsageSampler::new();
+
+        loop {
+            std::thread::sleep(SAMPLE_INTERVAL);
+
+            let alert_state = app.state::<CpuAlertState>();
+            let config = match alert_state.0.lock() {
+                Ok(monitor) => monitor.config().clone(),
+                Err(_) => continue,
+            };
+
+            if !config.enabled {
+                continue;
+            }
+
+            let controller_state = app.state::<AceProcessControllerState>();
+            let Ok(mut controller) = controller_state.0.lock() else {
+                continue;
+            };
+
+            let _ = controller.scan_ace_guard_processes();
+            let processes = controller.get_processes().to_vec();
+            let process_ids: Vec<u32> = processes.iter().map(|p| p.process_id).collect();
+            sampler.retain(&process_ids);
+
+            for process in &processes {
+                let Some(cpu_usage) = sampler.sample(process.process_id) else {
+                    continue;
+                };
+
+                let triggered = match alert_state.0.lock() {
+                    Ok(mut monitor) => {
+                        monitor.retain(&process_ids);
+                        monitor.check(process.process_id, cpu_usage, Instant::now())
+                    }
+                    Err(_) => false,
+                };
+
+                if !triggered {
+                    continue;
+                }
+
+                tracing::warn!(
+                    "{} (PID: {}) used {:.1}% CPU for more than {}s",
+                    process.process_name,
+                    process.process_id,
+                    cpu_usage,
+                    config.duration_secs
+                );
+
+                let reapplied = config.auto_reapply
+                    && controller
+                        .optimize_single_process(process.process_id)
+                        .is_ok();
+
+                let alert = CpuAlertEvent {
+                    process_id: process.process_id,
+                    process_name: process.process_name.clone(),
+                    cpu_usage,
+                    threshold_percent: config.threshold_percent,
+                    reapplied,
+                    timestamp: Utc::now(),
+                };
+
+                if let Ok(mut monitor) = alert_state.0.lock() {
+                    monitor.record(alert.clone());
+                }
+
+                if reapplied {
+                    notify(
+                        &app,
+                        NotificationKind::SettingsReverted,
+                        &format!(
+                            "{} (PID: {}) reached {:.1}% CPU, optimization was re-applied",
+                            process.process_name, process.process_id, cpu_usage
+                        ),
+                    );
+                }
+
+                let _ = alert.emit(&app);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alert_requires_sustained_usage() {
+        let mut monitor = CpuAlertMonitor::new();
+        monitor.set_config(CpuAlertConfig {
+            enabled: true,
+            threshold_percent: 15.0,
+            duration_secs: 30,
+            auto_reapply: false,
+        });
+
+        let start = Instant::now();
+        assert!(!monitor.check(1, 20.0, start));
+        assert!(!monitor.check(1, 20.0, start + Duration::from_secs(29)));
+        assert!(monitor.check(1, 20.0, start + Duration::from_secs(30)));
+
+        // dropping below the threshold resets the window
+        assert!(!monitor.check(1, 5.0, start + Duration::from_secs(31)));
+        assert!(!monitor.check(1, 20.0, start + Duration::from_secs(40)));
+        assert!(!monitor.check(1, 20.0, start + Duration::from_secs(60)));
+    }
+}
@@ -0,0 +1,99 @@
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::{
+        Foundation::{CloseHandle, HANDLE},
+        System::{
+            JobObjects::{
+                AssignProcessToJobObject, CreateJobObjectW, JobObjectCpuRateControlInformation,
+                OpenJobObjectW, SetInformationJobObject, JOBOBJECT_CPU_RATE_CONTROL_INFORMATION,
+                JOBOBJECT_CPU_RATE_CONTROL_INFORMATION_0, JOB_OBJECT_CPU_RATE_CONTROL,
+                JOB_OBJECT_CPU_RATE_CONTROL_ENABLE, JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP,
+            },
+            Threading::{OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE},
+        },
+    },
+};
+
+/// `JOB_OBJECT_SET_ATTRIBUTES` access right
+const JOB_OBJECT_SET_ATTRIBUTES: u32 = 0x0010;
+
+/// Allowed range of the CPU cap in percent of all logical processors
+pub const MIN_CPU_RATE_PERCENT: u32 = 1;
+pub const MAX_CPU_RATE_PERCENT: u32 = 100;
+
+/// Jobs are named after the process, so a later instance can still release the cap
+fn job_name(process_id: u32) -> HSTRING {
+    HSTRING::from(format!("Local\\AceToolsCpuRate{}", process_id))
+}
+
+fn set_cpu_rate(job: HANDLE, percent: Option<u32>) -> Result<(), String> {
+    let information = match percent {
+        Some(percent) => JOBOBJECT_CPU_RATE_CONTROL_INFORMATION {
+            ControlFlags: JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP,
+            // the rate is given in 1/100 of a percent
+            Anonymous: JOBOBJECT_CPU_RATE_CONTROL_INFORMATION_0 {
+                CpuRate: percent.clamp(MIN_CPU_RATE_PERCENT, MAX_CPU_RATE_PERCENT) * 100,
+            },
+        },
+        None => JOBOBJECT_CPU_RATE_CONTROL_INFORMATION {
+            ControlFlags: JOB_OBJECT_CPU_RATE_CONTROL(0),
+            ..Default::default()
+        },
+    };
+
+    unsafe {
+        SetInformationJobObject(
+            job,
+            JobObjectCpuRateControlInformation,
+            &information as *const JOBOBJECT_CPU_RATE_CONTROL_INFORMATION as *const _,
+            std::mem::size_of::<JOBOBJECT_CPU_RATE_CONTROL_INFORMATION>() as u32,
+        )
+    }
+    .map_err(|e| format!("Failed to set job CPU rate: {:?}", e))
+}
+
+/// Cap the CPU usage of a process by putting it into a job object with a hard CPU rate.
+/// A process cannot leave a job again, releasing only lifts the cap
+pub fn apply_cpu_rate_limit(process_id: u32, percent: u32) -> Result<(), String> {
+    let name = job_name(process_id);
+
+    unsafe {
+        // opens the existing job when the process was limited before
+        let job = CreateJobObjectW(None, PCWSTR(name.as_ptr()))
+            .map_err(|e| format!("Failed to create job object: {:?}", e))?;
+
+        let result = set_cpu_rate(job, Some(percent)).and_then(|_| {
+            let process = OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, false, process_id)
+                .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+            let result = AssignProcessToJobObject(job, process).map_err(|e| {
+                format!(
+                    "Failed to assign process {} to job object: {:?}",
+                    process_id, e
+                )
+            });
+
+            let _ = CloseHandle(process);
+            result
+        });
+
+        // the job lives on as long as the process is in it
+        let _ = CloseHandle(job);
+        result
+    }
+}
+
+/// Lift the CPU cap set by `apply_cpu_rate_limit`
+pub fn release_cpu_rate_limit(process_id: u32) -> Result<(), String> {
+    let name = job_name(process_id);
+
+    unsafe {
+        let job = OpenJobObjectW(JOB_OBJECT_SET_ATTRIBUTES, false, PCWSTR(name.as_ptr()))
+            .map_err(|e| format!("No CPU rate job for process {}: {:?}", process_id, e))?;
+
+        let result = set_cpu_rate(job, None);
+
+        let _ = CloseHandle(job);
+        result
+    }
+}
@@ -0,0 +1,56 @@
+use crate::windows::registry;
+use windows::Win32::{
+    Foundation::HANDLE,
+    System::{
+        Registry::HKEY_LOCAL_MACHINE,
+        Threading::{
+            ProcessPowerThrottling, SetProcessInformation,
+            PROCESS_POWER_THROTTLING_CURRENT_VERSION, PROCESS_POWER_THROTTLING_EXECUTION_SPEED,
+            PROCESS_POWER_THROTTLING_STATE,
+        },
+    },
+};
+
+const CURRENT_VERSION_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+
+/// First Windows 11 build, Task Manager's Efficiency mode is not available before it
+const WINDOWS_11_FIRST_BUILD: u32 = 22000;
+
+fn get_os_build_number() -> Option<u32> {
+    registry::read_string(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, "CurrentBuild")?
+        .parse()
+        .ok()
+}
+
+/// Whether EcoQoS is honored by the scheduler on this system
+pub fn is_efficiency_mode_supported() -> bool {
+    get_os_build_number().is_some_and(|build| build >= WINDOWS_11_FIRST_BUILD)
+}
+
+/// Turn EcoQoS execution speed throttling on or off for another process, disabling hands
+/// the decision back to the system. The handle needs `PROCESS_SET_INFORMATION` access
+pub fn set_efficiency_mode(handle: HANDLE, enabled: bool) -> Result<(), String> {
+    let state = PROCESS_POWER_THROTTLING_STATE {
+        Version: PROCESS_POWER_THROTTLING_CURRENT_VERSION,
+        ControlMask: if enabled {
+            PROCESS_POWER_THROTTLING_EXECUTION_SPEED
+        } else {
+            0
+        },
+        StateMask: if enabled {
+            PROCESS_POWER_THROTTLING_EXECUTION_SPEED
+        } else {
+            0
+        },
+    };
+
+    unsafe {
+        SetProcessInformation(
+            handle,
+            ProcessPowerThrottling,
+            &state as *const PROCESS_POWER_THROTTLING_STATE as *const _,
+            std::mem::size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+        )
+    }
+    .map_err(|e| format!("Failed to set efficiency mode: {:?}", e))
+}
@@ -0,0 +1,332 @@
+use crate::windows::{
+    ace_tools::{AceInstanceSettings, AceProcessController},
+    affinity::AffinityStrategy,
+    anti_cheat::AntiCheatFamily,
+    background_mode::ThrottleMode,
+    priority::TargetPriority,
+    process_matcher::ProcessNamePattern,
+    utils::is_running_as_admin,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::{
+        Foundation::ERROR_CANCELLED,
+        Security::Cryptography::{BCryptGenRandom, BCRYPT_USE_SYSTEM_PREFERRED_RNG},
+        UI::{
+            Shell::{ShellExecuteExW, SEE_MASK_NOASYNC, SHELLEXECUTEINFOW},
+            WindowsAndMessaging::SW_SHOWNORMAL,
+        },
+    },
+};
+
+/// Command line flag carrying the path of a handover file to the elevated instance
+pub const HANDOVER_ARG: &str = "--elevation-handover";
+/// Command line flag that disables the automatic elevation on startup
+pub const NO_ELEVATE_ARG: &str = "--no-elevate";
+/// File name prefix of handover files in the temp directory
+pub const HANDOVER_FILE_PREFIX: &str = "tencent-ace-tools-handover-";
+
+/// State passed from the unelevated instance to the elevated one
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ElevationHandover {
+    pub affinity_strategy: AffinityStrategy,
+    pub instance_settings: HashMap<String, AceInstanceSettings>,
+    #[serde(default)]
+    pub target_patterns: Vec<ProcessNamePattern>,
+    #[serde(default)]
+    pub target_priority: TargetPriority,
+    #[serde(default)]
+    pub throttle_mode: ThrottleMode,
+    #[serde(default)]
+    pub lower_io_priority: bool,
+    #[serde(default)]
+    pub lower_memory_priority: bool,
+    #[serde(default)]
+    pub lower_gpu_priority: bool,
+    #[serde(default)]
+    pub efficiency_mode: bool,
+    #[serde(default)]
+    pub trim_working_set: bool,
+    #[serde(default)]
+    pub cpu_rate_limit: Option<u32>,
+    #[serde(default)]
+    pub lower_thread_priority: bool,
+    #[serde(default)]
+    pub require_trusted_signature: bool,
+    #[serde(default)]
+    pub optimized_anti_cheats: Vec<AntiCheatFamily>,
+}
+
+impl ElevationHandover {
+    pub fn from_controller(controller: &AceProcessController) -> Self {
+        Self {
+            affinity_strategy: controller.get_affinity_strategy(),
+            instance_settings: controller.get_instance_settings(),
+            target_patterns: controller.get_target_patterns(),
+            target_priority: controller.get_target_priority(),
+            throttle_mode: controller.get_throttle_mode(),
+            lower_io_priority: controller.get_lower_io_priority(),
+            lower_memory_priority: controller.get_lower_memory_priority(),
+            lower_gpu_priority: controller.get_lower_gpu_priority(),
+            efficiency_mode: controller.get_efficiency_mode(),
+            trim_working_set: controller.get_trim_working_set(),
+            cpu_rate_limit: controller.get_cpu_rate_limit(),
+            lower_thread_priority: controller.get_lower_thread_priority(),
+            require_trusted_signature: controller.get_require_trusted_signature(),
+            optimized_anti_cheats: controller.get_optimized_anti_cheats(),
+        }
+    }
+
+    pub fn apply(self, controller: &mut AceProcessController) {
+        controller.set_affinity_strategy(self.affinity_strategy);
+        controller.set_target_priority(self.target_priority);
+        controller.set_throttle_mode(self.throttle_mode);
+        controller.set_lower_io_priority(self.lower_io_priority);
+        controller.set_lower_memory_priority(self.lower_memory_priority);
+        controller.set_lower_gpu_priority(self.lower_gpu_priority);
+        controller.set_trim_working_set(self.trim_working_set);
+        controller.set_lower_thread_priority(self.lower_thread_priority);
+        controller.set_require_trusted_signature(self.require_trusted_signature);
+        for family in self.optimized_anti_cheats {
+            controller.set_anti_cheat_optimized(family, true);
+        }
+        if let Err(e) = controller.set_cpu_rate_limit(self.cpu_rate_limit) {
+            tracing::warn!("{}", e);
+        }
+        if let Err(e) = controller.set_efficiency_mode(self.efficiency_mode) {
+            tracing::warn!("{}", e);
+        }
+        for (game, settings) in self.instance_settings {
+            controller.set_instance_settings(game, settings);
+        }
+        if !self.target_patterns.is_empty() {
+            if let Err(e) = controller.set_target_patterns(self.target_patterns) {
+                tracing::warn!("{}", e);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElevationOutcome {
+    /// The elevated instance was started, the current one should exit
+    Relaunched,
+    /// The user declined the UAC prompt
+    Cancelled,
+}
+
+/// Quote a single argument following the CommandLineToArgvW rules
+fn quote_argument(argument: &str) -> String {
+    if !argument.is_empty() && !argument.contains([' ', '\t', '"']) {
+        return argument.to_string();
+    }
+
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+
+    for c in argument.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// Random hex string making the handover file name unpredictable
+fn random_file_suffix() -> Result<String, String> {
+    let mut bytes = [0u8; 16];
+    unsafe { BCryptGenRandom(None, &mut bytes, BCRYPT_USE_SYSTEM_PREFERRED_RNG) }
+        .ok()
+        .map_err(|e| format!("Failed to generate handover file name: {}", e))?;
+
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+fn write_handover(handover: &ElevationHandover) -> Result<PathBuf, String> {
+    let path = std::env::temp_dir().join(format!(
+        "{}{}.json",
+        HANDOVER_FILE_PREFIX,
+        random_file_suffix()?
+    ));
+
+    let content = serde_json::to_string(handover)
+        .map_err(|e| format!("Failed to serialize handover state: {}", e))?;
+
+    // never follow a file or link planted at the path, the elevated instance trusts it
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|e| format!("Failed to write handover state: {}", e))?;
+
+    Ok(path)
+}
+
+/// Command line of the elevated instance, the arguments of the current one without
+/// the flags of a previous handover, followed by the new handover file if any
+fn relaunch_parameters(
+    args: impl IntoIterator<Item = String>,
+    handover_path: Option<&Path>,
+) -> String {
+    let mut arguments = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == HANDOVER_ARG {
+            args.next();
+            continue;
+        }
+        arguments.push(arg);
+    }
+
+    if let Some(path) = handover_path {
+        arguments.push(HANDOVER_ARG.to_string());
+        arguments.push(path.to_string_lossy().to_string());
+    }
+
+    arguments
+        .iter()
+        .map(|a| quote_argument(a))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Relaunch the current executable elevated, preserving arguments and working directory
+pub fn relaunch_elevated(handover: Option<&ElevationHandover>) -> Result<ElevationOutcome, String> {
+    let executable =
+        std::env::current_exe().map_err(|e| format!("Failed to get current executable: {}", e))?;
+    let working_directory =
+        std::env::current_dir().map_err(|e| format!("Failed to get working directory: {}", e))?;
+
+    let handover_path = handover.map(write_handover).transpose()?;
+    let parameters = relaunch_parameters(std::env::args().skip(1), handover_path.as_deref());
+
+    let verb = HSTRING::from("runas");
+    let file = HSTRING::from(executable.as_os_str());
+    let parameters = HSTRING::from(parameters);
+    let directory = HSTRING::from(working_directory.as_os_str());
+
+    let mut execute_info = SHELLEXECUTEINFOW {
+        cbSize: std::mem::size_of::<SHELLEXECUTEINFOW>() as u32,
+        fMask: SEE_MASK_NOASYNC,
+        lpVerb: PCWSTR(verb.as_ptr()),
+        lpFile: PCWSTR(file.as_ptr()),
+        lpParameters: PCWSTR(parameters.as_ptr()),
+        lpDirectory: PCWSTR(directory.as_ptr()),
+        nShow: SW_SHOWNORMAL.0,
+        ..Default::default()
+    };
+
+    tracing::info!("Relaunching {} as administrator", executable.display());
+
+    match unsafe { ShellExecuteExW(&mut execute_info) } {
+        Ok(()) => Ok(ElevationOutcome::Relaunched),
+        Err(e) if e.code() == ERROR_CANCELLED.to_hresult() => {
+            tracing::warn!("Elevation was cancelled by the user");
+            Ok(ElevationOutcome::Cancelled)
+        }
+        Err(e) => Err(format!("Failed to relaunch as administrator: {:?}", e)),
+    }
+}
+
+/// Read and remove the handover state passed by an unelevated instance, if any
+pub fn take_handover() -> Option<ElevationHandover> {
+    let mut args = std::env::args()
+        .skip_while(|arg| arg != HANDOVER_ARG)
+        .skip(1);
+    let path = PathBuf::from(args.next()?);
+
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| tracing::warn!("Failed to read handover state: {}", e))
+        .ok()?;
+    let _ = std::fs::remove_file(&path);
+
+    serde_json::from_str(&content)
+        .map_err(|e| tracing::warn!("Failed to parse handover state: {}", e))
+        .ok()
+}
+
+/// Relaunch elevated on startup unless already elevated or disabled,
+/// returns true when the current process should exit
+pub fn ensure_elevated_on_startup() -> bool {
+    if std::env::args().any(|arg| arg == NO_ELEVATE_ARG || arg == HANDOVER_ARG) {
+        return false;
+    }
+
+    if is_running_as_admin().unwrap_or(false) {
+        return false;
+    }
+
+    match relaunch_elevated(None) {
+        Ok(ElevationOutcome::Relaunched) => true,
+        Ok(ElevationOutcome::Cancelled) => false,
+        Err(e) => {
+            tracing::warn!("{}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_argument() {
+        assert_eq!(quote_argument("--watch"), "--watch");
+        assert_eq!(quote_argument(""), "\"\"");
+        assert_eq!(
+            quote_argument(r"C:\Program Files\app"),
+            r#""C:\Program Files\app""#
+        );
+        assert_eq!(quote_argument(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(
+            quote_argument(r"C:\dir with space\"),
+            r#""C:\dir with space\\""#
+        );
+    }
+
+    #[test]
+    fn test_relaunch_parameters_replace_the_previous_handover() {
+        let args = [
+            "--watch",
+            HANDOVER_ARG,
+            r"C:\Temp\old.json",
+            "--game",
+            "Delta Force",
+        ]
+        .map(String::from);
+
+        assert_eq!(
+            relaunch_parameters(args.clone(), None),
+            r#"--watch --game "Delta Force""#
+        );
+        assert_eq!(
+            relaunch_parameters(args, Some(Path::new(r"C:\Temp\new file.json"))),
+            format!(
+                r#"--watch --game "Delta Force" {} "C:\Temp\new file.json""#,
+                HANDOVER_ARG
+            )
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    consts,
+    windows::{
+        ace_tools::{read_process_settings, OriginalProcessSettings},
+        process_list::find_processes_by_name,
+    },
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{collections::HashMap, sync::Mutex};
+use windows::Win32::{
+    Foundation::CloseHandle,
+    System::Threading::{
+        GetProcessAffinityMask, OpenProcess, SetPriorityClass, SetProcessAffinityMask,
+        ABOVE_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS, PROCESS_CREATION_FLAGS,
+        PROCESS_QUERY_INFORMATION, PROCESS_SET_INFORMATION,
+    },
+};
+
+/// Priority class given to the game process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Type)]
+pub enum GamePriority {
+    AboveNormal,
+    #[default]
+    High,
+}
+
+impl GamePriority {
+    fn priority_class(self) -> PROCESS_CREATION_FLAGS {
+        match self {
+            Self::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+            Self::High => HIGH_PRIORITY_CLASS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct GameBoostConfig {
+    /// Boost the game together with every ACE optimization
+    pub enabled: bool,
+    pub priority: GamePriority,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BoostedGame {
+    pub process_id: u32,
+    pub process_name: String,
+    pub original_settings: OriginalProcessSettings,
+}
+
+/// Raises known game processes and remembers how to put them back
+#[derive(Debug, Default)]
+pub struct GameBoost {
+    config: GameBoostConfig,
+    boosted: HashMap<u32, BoostedGame>,
+}
+
+impl GameBoost {
+    pub fn config(&self) -> &GameBoostConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: GameBoostConfig) {
+        self.config = config;
+    }
+
+    pub fn boosted_games(&self) -> Vec<BoostedGame> {
+        self.boosted.values().cloned().collect()
+    }
+
+    /// Give every running known game the configured priority and all cores
+    pub fn boost_games(&mut self) -> Result<Vec<BoostedGame>, String> {
+        let mut games = Vec::new();
+        for (_, executable) in consts::KNOWN_GAMES {
+            games.extend(find_processes_by_name(executable)?);
+        }
+
+        if games.is_empty() {
+            return Err("No running game found to boost.".to_string());
+        }
+
+        let mut errors = Vec::new();
+        for game in games {
+            match boost_process(game.process_id, self.config.priority) {
+                Ok(original_settings) => {
+                    tracing::info!(
+                        "Boosted {} (PID: {}) to {:?}",
+                        game.process_name,
+                        game.process_id,
+                        self.config.priority
+                    );
+                    // keep the settings from before the first boost
+                    self.boosted.entry(game.process_id).or_insert(BoostedGame {
+                        process_id: game.process_id,
+                        process_name: game.process_name,
+                        original_settings,
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!("{}", e);
+                    errors.push(e);
+                }
+            }
+        }
+
+        if self.boosted.is_empty() {
+            return Err(errors.join("; "));
+        }
+
+        Ok(self.boosted_games())
+    }
+
+    /// Put boosted games back to their recorded settings, returns the number reverted
+    pub fn revert(&mut self) -> Result<usize, String> {
+        let mut reverted = 0;
+        let mut errors = Vec::new();
+
+        for (_, game) in self.boosted.drain() {
+            match restore_settings(game.process_id, game.original_settings) {
+                Ok(()) => reverted += 1,
+                // the game may simply have exited in the meantime
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if reverted == 0 && !errors.is_empty() {
+            return Err(errors.join("; "));
+        }
+
+        Ok(reverted)
+    }
+}
+
+// State wrapper for the game process boost
+#[derive(Default)]
+pub struct GameBoostState(pub Mutex<GameBoost>);
+
+fn boost_process(
+    process_id: u32,
+    priority: GamePriority,
+) -> Result<OriginalProcessSettings, String> {
+    unsafe {
+        let handle = OpenProcess(
+            PROCESS_SET_INFORMATION | PROCESS_QUERY_INFORMATION,
+            false,
+            process_id,
+        )
+        .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let result = read_process_settings(handle)
+            .ok_or_else(|| format!("Failed to read settings of process {}", process_id))
+            .and_then(|original| {
+                let mut process_mask = 0usize;
+                let mut system_mask = 0usize;
+                SetPriorityClass(handle, priority.priority_class())
+                    .and_then(|_| {
+                        GetProcessAffinityMask(handle, &mut process_mask, &mut system_mask)
+                    })
+                    .and_then(|_| SetProcessAffinityMask(handle, system_mask))
+                    .map(|_| original)
+                    .map_err(|e| format!("Failed to boost process {}: {:?}", process_id, e))
+            });
+
+        let _ = CloseHandle(handle);
+        result
+    }
+}
+
+fn restore_settings(process_id: u32, original: OriginalProcessSettings) -> Result<(), String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_SET_INFORMATION, false, process_id)
+            .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let result = SetPriorityClass(handle, PROCESS_CREATION_FLAGS(original.priority_class))
+            .and_then(|_| SetProcessAffinityMask(handle, original.affinity_mask as usize))
+            .map_err(|e| format!("Failed to revert boost of process {}: {:?}", process_id, e));
+
+        let _ = CloseHandle(handle);
+        result
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    consts,
+    windows::{
+        registry::{read_dword, read_string},
+        utils::get_process_path,
+    },
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use windows::{
+    core::{HSTRING, PCWSTR, PWSTR},
+    Win32::{
+        Foundation::ERROR_SUCCESS,
+        System::Registry::{
+            RegCloseKey, RegEnumKeyExW, RegOpenKeyExW, HKEY, HKEY_CURRENT_USER, KEY_READ,
+        },
+        UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId},
+    },
+};
+
+const GAME_BAR_KEY: &str = r"Software\Microsoft\GameBar";
+const AUTO_GAME_MODE_VALUE: &str = "AutoGameModeEnabled";
+/// Games detected by the Game Bar are stored as children of this key
+const GAME_CONFIG_STORE_KEY: &str = r"System\GameConfigStore\Children";
+const MATCHED_EXE_VALUE: &str = "MatchedExeFullPath";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GameModeStatus {
+    /// Game Mode is on unless the user switched it off in the settings app
+    pub enabled: bool,
+    pub foreground_process_name: Option<String>,
+    pub foreground_process_path: Option<String>,
+    /// The foreground process is one of the games we know ACE is shipped with
+    pub foreground_is_known_game: bool,
+    /// Windows has the foreground executable registered as a game
+    pub foreground_recognized: bool,
+}
+
+/// Executable paths of all games the Game Bar has recognized
+fn recognized_game_paths() -> Vec<String> {
+    let mut paths = Vec::new();
+    let mut key = HKEY::default();
+    let subkey = HSTRING::from(GAME_CONFIG_STORE_KEY);
+
+    unsafe {
+        if RegOpenKeyExW(
+            HKEY_CURRENT_USER,
+            PCWSTR(subkey.as_ptr()),
+            None,
+            KEY_READ,
+            &mut key,
+        ) != ERROR_SUCCESS
+        {
+            return paths;
+        }
+
+        let mut index = 0;
+        loop {
+            let mut name = [0u16; 256];
+            let mut name_length = name.len() as u32;
+
+            if RegEnumKeyExW(
+                key,
+                index,
+                Some(PWSTR(name.as_mut_ptr())),
+                &mut name_length,
+                None,
+                None,
+                None,
+                None,
+            ) != ERROR_SUCCESS
+            {
+                break;
+            }
+            index += 1;
+
+            let child = String::from_utf16_lossy(&name[..name_length as usize]);
+            if let Some(path) = read_string(key, &child, MATCHED_EXE_VALUE) {
+                paths.push(path);
+            }
+        }
+
+        let _ = RegCloseKey(key);
+    }
+
+    paths
+}
+
+/// Get the Game Mode setting and whether the foreground application is treated as a game
+pub fn get_game_mode_status() -> GameModeStatus {
+    // the value is missing until the toggle was changed once, Game Mode defaults to on
+    let enabled = read_dword(HKEY_CURRENT_USER, GAME_BAR_KEY, AUTO_GAME_MODE_VALUE)
+        .map(|value| value != 0)
+        .unwrap_or(true);
+
+    let foreground_process_path = unsafe {
+        let window = GetForegroundWindow();
+        let mut process_id = 0u32;
+        GetWindowThreadProcessId(window, Some(&mut process_id));
+
+        if process_id == 0 {
+            None
+        } else {
+            get_process_path(process_id).ok()
+        }
+    };
+
+    let foreground_process_name = foreground_process_path.as_ref().and_then(|path| {
+        Path::new(path)
+            .file_name()
+            .and_then(|f| f.to_str())
+            .map(|f| f.to_string())
+    });
+
+    let foreground_is_known_game = foreground_process_name
+        .as_ref()
+        .map(|name| {
+            consts::KNOWN_GAMES
+                .iter()
+                .any(|(_, executable)| executable.eq_ignore_ascii_case(name))
+        })
+        .unwrap_or(false);
+
+    let foreground_recognized = foreground_process_path
+        .as_ref()
+        .map(|path| {
+            recognized_game_paths()
+                .iter()
+                .any(|recognized| recognized.eq_ignore_ascii_case(path))
+        })
+        .unwrap_or(false);
+
+    GameModeStatus {
+        enabled,
+        foreground_process_name,
+        foreground_process_path,
+        foreground_is_known_game,
+        foreground_recognized,
+    }
+}
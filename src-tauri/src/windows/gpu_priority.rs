@@ -0,0 +1,24 @@
+use windows::Win32::Foundation::{HANDLE, NTSTATUS};
+
+/// `D3DKMT_SCHEDULINGPRIORITYCLASS_IDLE`
+pub const GPU_PRIORITY_IDLE: i32 = 0;
+/// `D3DKMT_SCHEDULINGPRIORITYCLASS_NORMAL`, the default of every process
+pub const GPU_PRIORITY_NORMAL: i32 = 2;
+
+#[link(name = "gdi32")]
+extern "system" {
+    fn D3DKMTSetProcessSchedulingPriorityClass(process: HANDLE, priority: i32) -> NTSTATUS;
+}
+
+/// Set the GPU scheduling priority class of another process,
+/// the handle needs `PROCESS_SET_INFORMATION` access
+pub fn set_gpu_priority(handle: HANDLE, priority: i32) -> Result<(), String> {
+    let status = unsafe { D3DKMTSetProcessSchedulingPriorityClass(handle, priority) };
+
+    // negative values are error codes
+    if status.0 < 0 {
+        Err(format!("Failed to set GPU priority: 0x{:08X}", status.0))
+    } else {
+        Ok(())
+    }
+}
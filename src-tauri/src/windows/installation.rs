@@ -0,0 +1,277 @@
+use crate::{
+    consts,
+    windows::{
+        process_list::find_processes_by_name,
+        registry,
+        signature::{verify_file_signature, SignatureInfo},
+        utils::get_process_path,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Component, Path, PathBuf};
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::{
+        Storage::FileSystem::{
+            GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW, VS_FIXEDFILEINFO,
+        },
+        System::Registry::HKEY_LOCAL_MACHINE,
+    },
+};
+
+/// Service keys whose `ImagePath` points into the ACE installation
+const ACE_SERVICE_KEYS: &[&str] = &[
+    r"SYSTEM\CurrentControlSet\Services\AntiCheatExpert Service",
+    r"SYSTEM\CurrentControlSet\Services\ACE-BASE",
+];
+/// Environment variables of the program folders ACE is installed to globally
+const PROGRAM_FILES_VARS: &[&str] = &["ProgramFiles", "ProgramFiles(x86)"];
+/// Only binaries are reported, ACE also ships data files that carry no version
+const REPORTED_EXTENSIONS: &[&str] = &["exe", "dll", "sys"];
+/// Keeps the walk bounded in case the folder is a game directory by mistake
+const MAX_DIRECTORY_DEPTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum InstallationSource {
+    /// `ImagePath` of the ACE service or driver
+    Registry,
+    /// Path of a running ACE process
+    RunningProcess,
+    /// One of the default install locations
+    KnownPath,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AceFileInfo {
+    pub path: String,
+    /// File version from the version resource, e.g. `5.4.12.1`
+    pub version: Option<String>,
+    pub size: u64,
+    pub modified: Option<DateTime<Utc>>,
+    pub signature: SignatureInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AceInstallationInfo {
+    pub install_dir: Option<String>,
+    pub source: Option<InstallationSource>,
+    /// Version of `SGuard64.exe`, the closest thing to an ACE version
+    pub version: Option<String>,
+    pub files: Vec<AceFileInfo>,
+    /// Problems found, an empty list means the installation looks healthy
+    pub issues: Vec<String>,
+}
+
+/// Cut a path after its AntiCheatExpert folder, tolerating quotes, arguments
+/// and the `\??\` prefix found in service image paths
+fn install_dir_from_path(image_path: &str) -> Option<PathBuf> {
+    let image_path = image_path.trim().trim_start_matches(r"\??\");
+    let image_path = match image_path.strip_prefix('"') {
+        Some(quoted) => quoted.split('"').next().unwrap_or_default(),
+        None => image_path,
+    };
+
+    let mut install_dir = PathBuf::new();
+    for component in Path::new(image_path).components() {
+        install_dir.push(component);
+        if let Component::Normal(part) = component {
+            if part
+                .to_str()
+                .is_some_and(|p| p.eq_ignore_ascii_case(consts::ACE_INSTALL_DIR_NAME))
+            {
+                return Some(install_dir);
+            }
+        }
+    }
+
+    None
+}
+
+fn find_install_dir() -> Option<(PathBuf, InstallationSource)> {
+    let from_registry = ACE_SERVICE_KEYS.iter().find_map(|key| {
+        registry::read_string(HKEY_LOCAL_MACHINE, key, "ImagePath")
+            .and_then(|path| install_dir_from_path(&path))
+    });
+    if let Some(dir) = from_registry.filter(|dir| dir.is_dir()) {
+        return Some((dir, InstallationSource::Registry));
+    }
+
+    let from_process = find_processes_by_name(consts::ACE_GUARD_64_PROCESS_NAME)
+        .ok()
+        .and_then(|processes| {
+            processes.iter().find_map(|p| {
+                get_process_path(p.process_id)
+                    .ok()
+                    .and_then(|path| install_dir_from_path(&path))
+            })
+        });
+    if let Some(dir) = from_process.filter(|dir| dir.is_dir()) {
+        return Some((dir, InstallationSource::RunningProcess));
+    }
+
+    PROGRAM_FILES_VARS
+        .iter()
+        .filter_map(|var| std::env::var_os(var))
+        .map(|dir| PathBuf::from(dir).join(consts::ACE_INSTALL_DIR_NAME))
+        .find(|dir| dir.is_dir())
+        .map(|dir| (dir, InstallationSource::KnownPath))
+}
+
+fn collect_binaries(dir: &Path, depth: usize, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if depth < MAX_DIRECTORY_DEPTH {
+                collect_binaries(&path, depth + 1, files);
+            }
+        } else if path.extension().and_then(|e| e.to_str()).is_some_and(|e| {
+            REPORTED_EXTENSIONS
+                .iter()
+                .any(|r| r.eq_ignore_ascii_case(e))
+        }) {
+            files.push(path);
+        }
+    }
+}
+
+/// Read the file version from the version resource of a binary
+fn get_file_version(path: &Path) -> Option<String> {
+    let path = HSTRING::from(path.as_os_str());
+
+    unsafe {
+        let size = GetFileVersionInfoSizeW(PCWSTR(path.as_ptr()), None);
+        if size == 0 {
+            return None;
+        }
+
+        let mut data = vec![0u8; size as usize];
+        GetFileVersionInfoW(
+            PCWSTR(path.as_ptr()),
+            None,
+            size,
+            data.as_mut_ptr() as *mut _,
+        )
+        .ok()?;
+
+        let mut info: *mut core::ffi::c_void = std::ptr::null_mut();
+        let mut length = 0u32;
+        let root = HSTRING::from("\\");
+        if !VerQueryValueW(
+            data.as_ptr() as *const _,
+            PCWSTR(root.as_ptr()),
+            &mut info,
+            &mut length,
+        )
+        .as_bool()
+            || info.is_null()
+        {
+            return None;
+        }
+
+        let info = &*(info as *const VS_FIXEDFILEINFO);
+        Some(format!(
+            "{}.{}.{}.{}",
+            info.dwFileVersionMS >> 16,
+            info.dwFileVersionMS & 0xFFFF,
+            info.dwFileVersionLS >> 16,
+            info.dwFileVersionLS & 0xFFFF
+        ))
+    }
+}
+
+fn get_file_info(path: &Path) -> AceFileInfo {
+    let metadata = std::fs::metadata(path).ok();
+    let path_string = path.to_string_lossy().to_string();
+
+    AceFileInfo {
+        version: get_file_version(path),
+        size: metadata.as_ref().map(|m| m.len()).unwrap_or(0),
+        modified: metadata
+            .and_then(|m| m.modified().ok())
+            .map(DateTime::<Utc>::from),
+        signature: verify_file_signature(&path_string),
+        path: path_string,
+    }
+}
+
+/// Locate the ACE installation and check its binaries for support and diagnostics
+pub fn get_ace_installation_info() -> AceInstallationInfo {
+    let Some((install_dir, source)) = find_install_dir() else {
+        return AceInstallationInfo {
+            install_dir: None,
+            source: None,
+            version: None,
+            files: Vec::new(),
+            issues: vec!["No AntiCheatExpert installation found".to_string()],
+        };
+    };
+
+    let mut paths = Vec::new();
+    collect_binaries(&install_dir, 0, &mut paths);
+    paths.sort();
+    let files: Vec<AceFileInfo> = paths.iter().map(|path| get_file_info(path)).collect();
+
+    let mut issues = Vec::new();
+    for name in consts::ACE_ALLOWED_PROCESS_NAMES {
+        if !files.iter().any(|f| {
+            Path::new(&f.path)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+        }) {
+            issues.push(format!("{} is missing", name));
+        }
+    }
+    for file in files.iter().filter(|f| !f.signature.is_trusted()) {
+        issues.push(format!(
+            "{} has signature status {:?}",
+            file.path, file.signature.status
+        ));
+    }
+
+    let version = files
+        .iter()
+        .find(|f| {
+            f.path
+                .to_lowercase()
+                .ends_with(&consts::ACE_GUARD_64_PROCESS_NAME.to_lowercase())
+        })
+        .and_then(|f| f.version.clone());
+
+    AceInstallationInfo {
+        install_dir: Some(install_dir.to_string_lossy().to_string()),
+        source: Some(source),
+        version,
+        files,
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_dir_from_path() {
+        assert_eq!(
+            install_dir_from_path(
+                r#""C:\Program Files\AntiCheatExpert\SGuard\x64\SGuardSvc64.exe" -service"#
+            ),
+            Some(PathBuf::from(r"C:\Program Files\AntiCheatExpert"))
+        );
+        assert_eq!(
+            install_dir_from_path(r"\??\D:\Games\anticheatexpert\ACE-BASE.sys"),
+            Some(PathBuf::from(r"D:\Games\anticheatexpert"))
+        );
+        assert_eq!(
+            install_dir_from_path(r"C:\Windows\System32\svchost.exe"),
+            None
+        );
+    }
+}
@@ -5,6 +5,44 @@ pub mod ace_tools;
 pub mod utils;
 pub mod screenshot;
 pub mod ocr;
+pub mod usage;
+pub mod threads;
+pub mod timer_resolution;
+pub mod cpu_alert;
+pub mod cpu_rate_limit;
+pub mod monitor;
+pub mod topology;
+pub mod affinity;
+pub mod allowlist;
+pub mod anti_cheat;
+pub mod autostart;
+pub mod background_pause;
+pub mod clipboard;
+pub mod signature;
+pub mod suspend;
+pub mod elevation;
+pub mod process_list;
+pub mod process_matcher;
+pub mod process_tree;
+pub mod protection;
+pub mod auto_optimize;
+pub mod background_mode;
+pub mod efficiency_mode;
+pub mod game_boost;
+pub mod game_mode;
+pub mod installation;
+pub mod power;
+pub mod registry;
+pub mod system_info;
+pub mod priority;
+pub mod target_config;
+pub mod watchdog;
+pub mod saved_state;
+pub mod scheduled_task;
+pub mod scheduler;
+pub mod status_events;
+pub mod services;
+pub mod gpu_priority;
 
 // State wrapper for AceProcessController
 pub struct AceProcessControllerState(pub Mutex<AceProcessController>);
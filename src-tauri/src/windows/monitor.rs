@@ -0,0 +1,253 @@
+use crate::{
+    history::HistoryState,
+    windows::{
+        background_pause,
+        usage::{get_process_memory, CpuUsageSampler},
+        AceProcessControllerState,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::Duration,
+};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+/// Ten minutes of history per process at the default interval
+const MAX_SAMPLES_PER_PROCESS: usize = 600;
+/// Persist every n-th sample to the history database
+const HISTORY_SAMPLE_STRIDE: u64 = 30;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProcessUsageSample {
+    pub timestamp: DateTime<Utc>,
+    pub cpu_usage: Option<f32>,
+    pub working_set: u64,
+    pub private_bytes: u64,
+    /// Whether the process was optimized when the sample was taken
+    pub is_optimized: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProcessUsageHistory {
+    pub process_id: u32,
+    pub process_name: String,
+    pub samples: Vec<ProcessUsageSample>,
+    /// Average CPU usage before and after optimization, to tell whether it helps
+    pub average_cpu_unoptimized: Option<f32>,
+    pub average_cpu_optimized: Option<f32>,
+}
+
+/// Latest sample of one process, part of a `ProcessUsageEvent`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProcessUsagePoint {
+    pub process_id: u32,
+    pub process_name: String,
+    pub sample: ProcessUsageSample,
+}
+
+// Emitted after every sampling round, feeds the live CPU usage charts
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct ProcessUsageEvent {
+    pub points: Vec<ProcessUsagePoint>,
+}
+
+fn average_cpu_usage<'a>(samples: impl Iterator<Item = &'a ProcessUsageSample>) -> Option<f32> {
+    let (sum, count) = samples
+        .filter_map(|s| s.cpu_usage)
+        .fold((0.0f32, 0u32), |(sum, count), usage| {
+            (sum + usage, count + 1)
+        });
+
+    (count > 0).then(|| sum / count as f32)
+}
+
+/// Ring buffers of usage samples for every tracked ACE process
+#[derive(Debug, Default)]
+pub struct UsageMonitor {
+    histories: HashMap<u32, (String, VecDeque<ProcessUsageSample>)>,
+}
+
+impl UsageMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, process_id: u32, process_name: &str, sample: ProcessUsageSample) {
+        let (_, samples) = self
+            .histories
+            .entry(process_id)
+            .or_insert_with(|| (process_name.to_string(), VecDeque::new()));
+
+        if samples.len() >= MAX_SAMPLES_PER_PROCESS {
+            samples.pop_front();
+        }
+        samples.push_back(sample);
+    }
+
+    /// Forget processes that have exited
+    pub fn retain(&mut self, process_ids: &[u32]) {
+        self.histories
+            .retain(|process_id, _| process_ids.contains(process_id));
+    }
+
+    pub fn get_history(&self, process_id: Option<u32>) -> Vec<ProcessUsageHistory> {
+        self.histories
+            .iter()
+            .filter(|(pid, _)| process_id.is_none_or(|id| id == **pid))
+            .map(|(pid, (name, samples))| ProcessUsageHistory {
+                process_id: *pid,
+                process_name: name.clone(),
+                samples: samples.iter().cloned().collect(),
+                average_cpu_unoptimized: average_cpu_usage(
+                    samples.iter().filter(|s| !s.is_optimized),
+                ),
+                average_cpu_optimized: average_cpu_usage(samples.iter().filter(|s| s.is_optimized)),
+            })
+            .collect()
+    }
+}
+
+// State wrapper for UsageMonitor
+#[derive(Default)]
+pub struct UsageMonitorState(pub Mutex<UsageMonitor>);
+
+/// Spawn a background thread that records cpu and memory usage of ACE processes.
+/// Only the processes already known to the controller are sampled, finding new
+/// ones is left to the scans of the watchers and the frontend
+pub fn spawn_usage_monitor(app: AppHandle) {
+    std::thread::spawn(move || {
+        let mut sampler = CpuUsageSampler::new();
+        let mut iteration = 0u64;
+
+        loop {
+            std::thread::sleep(SAMPLE_INTERVAL);
+
+            if background_pause::is_paused(&app) {
+                continue;
+            }
+            iteration += 1;
+
+            let processes = {
+                let controller_state = app.state::<AceProcessControllerState>();
+                let Ok(controller) = controller_state.0.lock() else {
+                    continue;
+                };
+
+                controller.get_processes().to_vec()
+            };
+
+            // a process that cannot be queried has exited since the last scan
+            let processes: Vec<_> = processes
+                .into_iter()
+                .filter_map(|process| {
+                    get_process_memory(process.process_id)
+                        .ok()
+                        .map(|memory| (process, memory))
+                })
+                .collect();
+
+            let process_ids: Vec<u32> = processes.iter().map(|(p, _)| p.process_id).collect();
+            sampler.retain(&process_ids);
+
+            let monitor_state = app.state::<UsageMonitorState>();
+            let Ok(mut monitor) = monitor_state.0.lock() else {
+                continue;
+            };
+
+            monitor.retain(&process_ids);
+
+            let history_state = app.state::<HistoryState>();
+            let history = history_state.0.lock().ok();
+            let persist = iteration % HISTORY_SAMPLE_STRIDE == 0;
+            let mut points = Vec::with_capacity(processes.len());
+
+            for (process, memory) in &processes {
+                let cpu_usage = sampler.sample(process.process_id);
+                let sample = ProcessUsageSample {
+                    timestamp: Utc::now(),
+                    cpu_usage,
+                    working_set: memory.working_set,
+                    private_bytes: memory.private_bytes,
+                    is_optimized: process.is_optimized,
+                };
+
+                if let Some(store) = history
+                    .as_ref()
+                    .and_then(|h| h.as_ref())
+                    .filter(|_| persist)
+                {
+                    if let Err(e) = store.record_usage_sample(
+                        sample.timestamp,
+                        process.process_id,
+                        &process.process_name,
+                        sample.cpu_usage,
+                        sample.working_set,
+                        sample.private_bytes,
+                    ) {
+                        tracing::warn!("{}", e);
+                    }
+                }
+
+                points.push(ProcessUsagePoint {
+                    process_id: process.process_id,
+                    process_name: process.process_name.clone(),
+                    sample: sample.clone(),
+                });
+                monitor.push(process.process_id, &process.process_name, sample);
+            }
+
+            // release the locks before handing the samples to the frontend
+            drop(history);
+            drop(monitor);
+            let _ = ProcessUsageEvent { points }.emit(&app);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(cpu_usage: Option<f32>, is_optimized: bool) -> ProcessUsageSample {
+        ProcessUsageSample {
+            timestamp: Utc::now(),
+            cpu_usage,
+            working_set: 0,
+            private_bytes: 0,
+            is_optimized,
+        }
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut monitor = UsageMonitor::new();
+        for _ in 0..MAX_SAMPLES_PER_PROCESS + 10 {
+            monitor.push(1, "SGuard64.exe", sample(Some(1.0), false));
+        }
+
+        let history = monitor.get_history(Some(1));
+        assert_eq!(history[0].samples.len(), MAX_SAMPLES_PER_PROCESS);
+    }
+
+    #[test]
+    fn test_averages_split_by_optimization() {
+        let mut monitor = UsageMonitor::new();
+        monitor.push(1, "SGuard64.exe", sample(None, false));
+        monitor.push(1, "SGuard64.exe", sample(Some(10.0), false));
+        monitor.push(1, "SGuard64.exe", sample(Some(20.0), false));
+        monitor.push(1, "SGuard64.exe", sample(Some(1.0), true));
+
+        let history = &monitor.get_history(None)[0];
+        assert_eq!(history.average_cpu_unoptimized, Some(15.0));
+        assert_eq!(history.average_cpu_optimized, Some(1.0));
+
+        monitor.retain(&[]);
+        assert!(monitor.get_history(None).is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::windows::screenshot::{CaptureOptions, ScreenshotCapture};
 use image::ImageFormat;
 use serde::{Deserialize, Serialize};
 use specta::Type;
@@ -209,6 +210,28 @@ pub fn ocr_image_region(image_data: &[u8], region: OcrRegion) -> Result<OcrRespo
     })
 }
 
+/// Capture a window and run OCR on `region` of it, or on all of it without a region.
+/// The result regions are relative to the captured image
+pub fn ocr_window(
+    window_id: u32,
+    region: Option<OcrRegion>,
+    options: CaptureOptions,
+) -> Result<OcrResponse, String> {
+    tracing::debug!("OCR window {} region: {:?}", window_id, region);
+
+    let screenshot = ScreenshotCapture::capture_window_with_options(window_id, options)
+        .map_err(|e| format!("Screenshot failed: {}", e))?;
+
+    let region = region.unwrap_or(OcrRegion {
+        x: 0,
+        y: 0,
+        width: screenshot.width as i32,
+        height: screenshot.height as i32,
+    });
+
+    ocr_image_region(&screenshot.image_data, region)
+}
+
 /// OCR full screen recognition
 pub fn ocr_full_screen() -> Result<OcrResponse, String> {
     tracing::debug!("OCR full screen");
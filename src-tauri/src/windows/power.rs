@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::{
+    core::GUID,
+    Win32::{
+        Foundation::{LocalFree, ERROR_SUCCESS, HLOCAL},
+        System::{
+            Power::{
+                CallNtPowerInformation, PowerGetActiveScheme, PowerReadACValueIndex,
+                ProcessorInformation, PROCESSOR_POWER_INFORMATION,
+            },
+            SystemServices::{
+                GUID_PROCESSOR_CORE_PARKING_MIN_CORES, GUID_PROCESSOR_PERF_BOOST_MODE,
+                GUID_PROCESSOR_SETTINGS_SUBGROUP,
+            },
+        },
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum BoostMode {
+    Disabled,
+    Enabled,
+    Aggressive,
+    EfficientEnabled,
+    EfficientAggressive,
+    AggressiveAtGuaranteed,
+    EfficientAggressiveAtGuaranteed,
+    Unknown,
+}
+
+impl From<u32> for BoostMode {
+    fn from(index: u32) -> Self {
+        match index {
+            0 => Self::Disabled,
+            1 => Self::Enabled,
+            2 => Self::Aggressive,
+            3 => Self::EfficientEnabled,
+            4 => Self::EfficientAggressive,
+            5 => Self::AggressiveAtGuaranteed,
+            6 => Self::EfficientAggressiveAtGuaranteed,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProcessorFrequency {
+    pub processor: u32,
+    pub max_mhz: u32,
+    pub current_mhz: u32,
+    pub mhz_limit: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PowerDiagnostics {
+    /// Minimum percentage of cores the active power plan keeps unparked (AC),
+    /// `None` when the setting could not be read
+    pub core_parking_min_cores_percent: Option<u32>,
+    /// Processor performance boost mode of the active power plan (AC)
+    pub boost_mode: Option<BoostMode>,
+    pub frequencies: Vec<ProcessorFrequency>,
+}
+
+impl PowerDiagnostics {
+    /// Parked cores are woken on demand, pinning ACE to one of them
+    /// ("last core") wakes it up or leaves ACE waiting for it
+    pub fn core_parking_enabled(&self) -> bool {
+        self.core_parking_min_cores_percent
+            .map(|percent| percent < 100)
+            .unwrap_or(false)
+    }
+}
+
+/// Read a processor power setting of the active power plan
+fn read_processor_setting(setting: &GUID) -> Result<u32, String> {
+    unsafe {
+        let mut scheme: *mut GUID = std::ptr::null_mut();
+        let result = PowerGetActiveScheme(None, &mut scheme);
+        if result != ERROR_SUCCESS {
+            return Err(format!("Failed to get active power scheme: {:?}", result));
+        }
+
+        let mut value = 0u32;
+        let result = PowerReadACValueIndex(
+            None,
+            Some(scheme),
+            Some(&GUID_PROCESSOR_SETTINGS_SUBGROUP),
+            Some(setting),
+            &mut value,
+        );
+
+        let _ = LocalFree(Some(HLOCAL(scheme as *mut _)));
+
+        if result != ERROR_SUCCESS {
+            return Err(format!("Failed to read power setting: {:?}", result));
+        }
+
+        Ok(value)
+    }
+}
+
+/// Get the current and maximum frequency of all logical processors
+fn get_processor_frequencies() -> Result<Vec<ProcessorFrequency>, String> {
+    let count = num_cpus::get();
+    let mut information = vec![PROCESSOR_POWER_INFORMATION::default(); count];
+
+    let status = unsafe {
+        CallNtPowerInformation(
+            ProcessorInformation,
+            None,
+            0,
+            Some(information.as_mut_ptr() as *mut _),
+            (std::mem::size_of::<PROCESSOR_POWER_INFORMATION>() * count) as u32,
+        )
+    };
+
+    // negative values are error codes
+    if status.0 < 0 {
+        return Err(format!(
+            "Failed to query processor power information: 0x{:08X}",
+            status.0
+        ));
+    }
+
+    Ok(information
+        .into_iter()
+        .map(|info| ProcessorFrequency {
+            processor: info.Number,
+            max_mhz: info.MaxMhz,
+            current_mhz: info.CurrentMhz,
+            mhz_limit: info.MhzLimit,
+        })
+        .collect())
+}
+
+/// Collect core parking and frequency boost state of the active power plan
+pub fn get_power_diagnostics() -> PowerDiagnostics {
+    let core_parking_min_cores_percent =
+        read_processor_setting(&GUID_PROCESSOR_CORE_PARKING_MIN_CORES)
+            .inspect_err(|e| tracing::debug!("{}", e))
+            .ok();
+
+    let boost_mode = read_processor_setting(&GUID_PROCESSOR_PERF_BOOST_MODE)
+        .inspect_err(|e| tracing::debug!("{}", e))
+        .ok()
+        .map(BoostMode::from);
+
+    let frequencies = get_processor_frequencies()
+        .inspect_err(|e| tracing::warn!("{}", e))
+        .unwrap_or_default();
+
+    PowerDiagnostics {
+        core_parking_min_cores_percent,
+        boost_mode,
+        frequencies,
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::Win32::System::Threading::{
+    BELOW_NORMAL_PRIORITY_CLASS, IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS, PROCESS_CREATION_FLAGS,
+};
+
+/// Priority class applied to optimized processes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Type)]
+pub enum TargetPriority {
+    /// Only runs when no other thread wants the CPU
+    #[default]
+    Idle,
+    BelowNormal,
+    /// Leaves the priority untouched in practice, useful together with affinity only
+    Normal,
+}
+
+impl TargetPriority {
+    pub fn priority_class(self) -> PROCESS_CREATION_FLAGS {
+        match self {
+            Self::Idle => IDLE_PRIORITY_CLASS,
+            Self::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+            Self::Normal => NORMAL_PRIORITY_CLASS,
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::time::Instant;
+use windows::{
+    Wdk::System::SystemInformation::{NtQuerySystemInformation, SystemProcessInformation},
+    Win32::{
+        Foundation::{CloseHandle, STATUS_INFO_LENGTH_MISMATCH},
+        System::{
+            Diagnostics::ToolHelp::{
+                CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+                TH32CS_SNAPPROCESS,
+            },
+            WindowsProgramming::SYSTEM_PROCESS_INFORMATION,
+        },
+    },
+};
+
+/// Initial buffer size for `NtQuerySystemInformation`, grown on demand
+const INITIAL_BUFFER_SIZE: usize = 512 * 1024;
+/// Extra space requested on retries since processes may start between two calls
+const BUFFER_SLACK: usize = 64 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct ProcessEntry {
+    pub process_id: u32,
+    pub parent_process_id: u32,
+    pub process_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ScanBenchmark {
+    pub iterations: u32,
+    /// Average duration of a Toolhelp snapshot in microseconds
+    pub toolhelp_micros: f64,
+    /// Average duration of a `NtQuerySystemInformation` scan in microseconds
+    pub nt_query_micros: f64,
+}
+
+/// Take a snapshot of all running processes using the Toolhelp API
+pub fn snapshot_processes() -> Result<Vec<ProcessEntry>, String> {
+    let mut entries = Vec::new();
+
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)
+            .map_err(|e| format!("Failed to create process snapshot: {:?}", e))?;
+
+        let mut process_entry = PROCESSENTRY32W {
+            dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
+            ..Default::default()
+        };
+
+        if Process32FirstW(snapshot, &mut process_entry).is_ok() {
+            loop {
+                let process_name_raw = String::from_utf16_lossy(&process_entry.szExeFile);
+
+                entries.push(ProcessEntry {
+                    process_id: process_entry.th32ProcessID,
+                    parent_process_id: process_entry.th32ParentProcessID,
+                    process_name: process_name_raw.trim_end_matches('\0').to_string(),
+                });
+
+                if Process32NextW(snapshot, &mut process_entry).is_err() {
+                    break;
+                }
+            }
+        }
+
+        let _ = CloseHandle(snapshot);
+    }
+
+    Ok(entries)
+}
+
+/// Read the raw `SystemProcessInformation` buffer, u64 elements keep the entries aligned
+fn query_process_buffer() -> Result<Vec<u64>, String> {
+    let mut buffer: Vec<u64> = vec![0; INITIAL_BUFFER_SIZE / 8];
+
+    loop {
+        let mut return_length = 0u32;
+        let status = unsafe {
+            NtQuerySystemInformation(
+                SystemProcessInformation,
+                buffer.as_mut_ptr() as *mut _,
+                (buffer.len() * 8) as u32,
+                &mut return_length,
+            )
+        };
+
+        if status == STATUS_INFO_LENGTH_MISMATCH {
+            let required = return_length as usize + BUFFER_SLACK;
+            buffer.resize(required.div_ceil(8), 0);
+            continue;
+        }
+
+        // negative values are error codes
+        if status.0 < 0 {
+            return Err(format!(
+                "Failed to query system process information: 0x{:08X}",
+                status.0
+            ));
+        }
+
+        return Ok(buffer);
+    }
+}
+
+/// Walk the entries of a `SystemProcessInformation` buffer, `filter` receives the UTF-16 image name
+fn parse_process_buffer(
+    buffer: &[u64],
+    mut filter: impl FnMut(&[u16]) -> bool,
+) -> Vec<ProcessEntry> {
+    let mut entries = Vec::new();
+    let base = buffer.as_ptr() as *const u8;
+    let mut offset = 0usize;
+
+    loop {
+        let info = unsafe { &*(base.add(offset) as *const SYSTEM_PROCESS_INFORMATION) };
+
+        // the idle process has no image name
+        let name: &[u16] = if info.ImageName.Buffer.is_null() {
+            &[]
+        } else {
+            unsafe {
+                std::slice::from_raw_parts(
+                    info.ImageName.Buffer.0,
+                    info.ImageName.Length as usize / 2,
+                )
+            }
+        };
+
+        if filter(name) {
+            entries.push(ProcessEntry {
+                process_id: info.UniqueProcessId.0 as usize as u32,
+                // Reserved2 holds InheritedFromUniqueProcessId
+                parent_process_id: info.Reserved2 as usize as u32,
+                process_name: String::from_utf16_lossy(name),
+            });
+        }
+
+        if info.NextEntryOffset == 0 {
+            break;
+        }
+        offset += info.NextEntryOffset as usize;
+    }
+
+    entries
+}
+
+/// List all running processes with a single `NtQuerySystemInformation` call,
+/// considerably cheaper than a Toolhelp snapshot for frequent polling
+pub fn query_processes() -> Result<Vec<ProcessEntry>, String> {
+    let buffer = query_process_buffer()?;
+    Ok(parse_process_buffer(&buffer, |_| true))
+}
+
+/// Find processes by name (case insensitive) without decoding the names of all other processes
+pub fn find_processes_by_name(process_name: &str) -> Result<Vec<ProcessEntry>, String> {
+    let target: Vec<u16> = process_name.encode_utf16().collect();
+    let buffer = query_process_buffer()?;
+
+    Ok(parse_process_buffer(&buffer, |name| {
+        name.len() == target.len()
+            && name.iter().zip(&target).all(|(a, b)| {
+                // ASCII case folding is enough for executable names we look for
+                let fold = |c: u16| match c {
+                    0x41..=0x5A => c + 0x20,
+                    _ => c,
+                };
+                fold(*a) == fold(*b)
+            })
+    }))
+}
+
+/// Compare the average duration of both scan implementations
+pub fn benchmark_scan(iterations: u32) -> Result<ScanBenchmark, String> {
+    let iterations = iterations.max(1);
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        snapshot_processes()?;
+    }
+    let toolhelp = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        query_processes()?;
+    }
+    let nt_query = start.elapsed();
+
+    let benchmark = ScanBenchmark {
+        iterations,
+        toolhelp_micros: toolhelp.as_secs_f64() * 1_000_000.0 / iterations as f64,
+        nt_query_micros: nt_query.as_secs_f64() * 1_000_000.0 / iterations as f64,
+    };
+
+    tracing::info!(
+        "Process scan benchmark ({} iterations): Toolhelp {:.0}us, NtQuerySystemInformation {:.0}us",
+        benchmark.iterations,
+        benchmark.toolhelp_micros,
+        benchmark.nt_query_micros
+    );
+
+    Ok(benchmark)
+}
@@ -0,0 +1,135 @@
+use crate::{
+    consts,
+    windows::allowlist::{is_protected_process, PROTECTED_SYSTEM_PROCESSES},
+};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A configured target process name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ProcessNamePattern {
+    /// Case insensitive exact name, e.g. `SGuard64.exe`
+    Exact(String),
+    /// `*` matches any run of characters and `?` a single one, e.g. `SGuard*`
+    Glob(String),
+    /// Case insensitive regular expression matched against the whole name
+    Regex(String),
+}
+
+impl ProcessNamePattern {
+    fn to_regex(&self) -> Result<Regex, String> {
+        let source = match self {
+            Self::Exact(name) => regex::escape(name),
+            Self::Glob(glob) => glob_to_regex(glob),
+            Self::Regex(regex) => regex.clone(),
+        };
+
+        RegexBuilder::new(&format!("^(?:{})$", source))
+            .case_insensitive(true)
+            .build()
+            .map_err(|e| format!("Invalid process name pattern {:?}: {}", self, e))
+    }
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::new();
+    for c in glob.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex
+}
+
+/// Compiled set of target process name patterns
+#[derive(Debug, Clone)]
+pub struct ProcessMatcher {
+    patterns: Vec<ProcessNamePattern>,
+    compiled: Vec<Regex>,
+}
+
+impl Default for ProcessMatcher {
+    fn default() -> Self {
+        Self::new(vec![ProcessNamePattern::Exact(
+            consts::ACE_GUARD_64_PROCESS_NAME.to_string(),
+        )])
+        .expect("default process pattern is valid")
+    }
+}
+
+impl ProcessMatcher {
+    /// Compile the patterns, refusing any that would match a protected system process
+    pub fn new(patterns: Vec<ProcessNamePattern>) -> Result<Self, String> {
+        if patterns.is_empty() {
+            return Err("At least one target process pattern is required".to_string());
+        }
+
+        let compiled = patterns
+            .iter()
+            .map(|pattern| pattern.to_regex())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (pattern, regex) in patterns.iter().zip(&compiled) {
+            if let Some(name) = PROTECTED_SYSTEM_PROCESSES
+                .iter()
+                .find(|name| regex.is_match(name))
+            {
+                return Err(format!(
+                    "Pattern {:?} matches the protected system process {}",
+                    pattern, name
+                ));
+            }
+        }
+
+        Ok(Self { patterns, compiled })
+    }
+
+    pub fn patterns(&self) -> &[ProcessNamePattern] {
+        &self.patterns
+    }
+
+    pub fn is_match(&self, process_name: &str) -> bool {
+        !is_protected_process(process_name)
+            && self
+                .compiled
+                .iter()
+                .any(|regex| regex.is_match(process_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_and_regex_patterns() {
+        let matcher = ProcessMatcher::new(vec![
+            ProcessNamePattern::Glob("SGuard*".to_string()),
+            ProcessNamePattern::Regex(r"ACE-\w+\.exe".to_string()),
+        ])
+        .unwrap();
+
+        assert!(matcher.is_match("SGuard64.exe"));
+        assert!(matcher.is_match("sguardsvc64.exe"));
+        assert!(matcher.is_match("ACE-Tray.exe"));
+        assert!(!matcher.is_match("xSGuard64.exe"));
+        assert!(!matcher.is_match("ACE-Tray.exe.bak"));
+    }
+
+    #[test]
+    fn test_exact_pattern_escapes_name() {
+        let matcher = ProcessMatcher::default();
+        assert!(matcher.is_match("SGUARD64.EXE"));
+        assert!(!matcher.is_match("SGuard64xexe"));
+    }
+
+    #[test]
+    fn test_rejects_patterns_matching_system_processes() {
+        assert!(ProcessMatcher::new(vec![ProcessNamePattern::Glob("*".to_string())]).is_err());
+        assert!(ProcessMatcher::new(vec![ProcessNamePattern::Regex("(".to_string())]).is_err());
+        assert!(ProcessMatcher::new(Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::windows::{ace_tools::ProcessInfo, process_list::ProcessEntry};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashSet;
+
+/// An ACE process together with the helpers it spawned
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AceProcessNode {
+    pub process_id: u32,
+    pub process_name: String,
+    pub is_optimized: bool,
+    pub children: Vec<AceProcessNode>,
+}
+
+/// Collect the pids of all processes started by one of the roots, directly or further down
+pub fn find_descendants(entries: &[ProcessEntry], roots: &[u32]) -> Vec<u32> {
+    let mut known: HashSet<u32> = roots.iter().copied().collect();
+    let mut descendants = Vec::new();
+    let mut pending = roots.to_vec();
+
+    while let Some(parent_id) = pending.pop() {
+        // pid 0 is the idle process, the parent of several system processes
+        if parent_id == 0 {
+            continue;
+        }
+
+        for entry in entries.iter().filter(|e| e.parent_process_id == parent_id) {
+            // the known set stops cycles caused by pid reuse
+            if known.insert(entry.process_id) {
+                descendants.push(entry.process_id);
+                pending.push(entry.process_id);
+            }
+        }
+    }
+
+    descendants
+}
+
+/// Arrange scanned processes by their parent, processes whose parent was not scanned become roots
+pub fn build_process_tree(processes: &[ProcessInfo]) -> Vec<AceProcessNode> {
+    let scanned: HashSet<u32> = processes.iter().map(|p| p.process_id).collect();
+
+    processes
+        .iter()
+        .filter(|p| !scanned.contains(&p.parent_process_id))
+        .map(|p| build_node(processes, p, &mut HashSet::new()))
+        .collect()
+}
+
+fn build_node(
+    processes: &[ProcessInfo],
+    process: &ProcessInfo,
+    visited: &mut HashSet<u32>,
+) -> AceProcessNode {
+    visited.insert(process.process_id);
+
+    let children = processes
+        .iter()
+        .filter(|p| p.parent_process_id == process.process_id && !visited.contains(&p.process_id))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|child| build_node(processes, child, visited))
+        .collect();
+
+    AceProcessNode {
+        process_id: process.process_id,
+        process_name: process.process_name.clone(),
+        is_optimized: process.is_optimized,
+        children,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(process_id: u32, parent_process_id: u32, process_name: &str) -> ProcessEntry {
+        ProcessEntry {
+            process_id,
+            parent_process_id,
+            process_name: process_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_find_descendants() {
+        let entries = vec![
+            entry(4, 0, "System"),
+            entry(100, 4, "SGuard64.exe"),
+            entry(200, 100, "SGuardSvc64.exe"),
+            entry(300, 200, "conhost.exe"),
+            entry(400, 4, "explorer.exe"),
+            entry(500, 400, "game.exe"),
+        ];
+
+        let mut descendants = find_descendants(&entries, &[100]);
+        descendants.sort();
+        assert_eq!(descendants, vec![200, 300]);
+
+        assert!(find_descendants(&entries, &[300]).is_empty());
+    }
+
+    #[test]
+    fn test_find_descendants_ignores_cycles() {
+        let entries = vec![
+            entry(100, 200, "SGuard64.exe"),
+            entry(200, 100, "helper.exe"),
+        ];
+
+        assert_eq!(find_descendants(&entries, &[100]), vec![200]);
+    }
+}
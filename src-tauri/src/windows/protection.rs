@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::Win32::{
+    Foundation::{CloseHandle, HANDLE},
+    Security::{
+        GetSidSubAuthority, GetSidSubAuthorityCount, GetTokenInformation, TokenIntegrityLevel,
+        TOKEN_MANDATORY_LABEL, TOKEN_QUERY,
+    },
+    System::Threading::{
+        GetProcessInformation, OpenProcess, OpenProcessToken, ProcessProtectionLevelInfo,
+        PROCESS_PROTECTION_LEVEL_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION,
+        PROTECTION_LEVEL_ANTIMALWARE_LIGHT, PROTECTION_LEVEL_AUTHENTICODE,
+        PROTECTION_LEVEL_CODEGEN_LIGHT, PROTECTION_LEVEL_LSA_LIGHT, PROTECTION_LEVEL_NONE,
+        PROTECTION_LEVEL_PPL_APP, PROTECTION_LEVEL_WINDOWS, PROTECTION_LEVEL_WINDOWS_LIGHT,
+        PROTECTION_LEVEL_WINTCB, PROTECTION_LEVEL_WINTCB_LIGHT,
+    },
+};
+
+/// Protected process (light) signer level of a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ProtectionLevel {
+    None,
+    WinTcbLight,
+    Windows,
+    WindowsLight,
+    AntimalwareLight,
+    LsaLight,
+    WinTcb,
+    CodeGenLight,
+    Authenticode,
+    PplApp,
+    Unknown,
+}
+
+impl ProtectionLevel {
+    pub fn is_protected(self) -> bool {
+        self != Self::None
+    }
+}
+
+/// Mandatory integrity level of a process token
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Type)]
+pub enum IntegrityLevel {
+    Untrusted,
+    Low,
+    Medium,
+    MediumPlus,
+    High,
+    System,
+    Protected,
+}
+
+impl IntegrityLevel {
+    /// Map the relative id of a mandatory label SID, e.g. `0x3000` for high
+    fn from_rid(rid: u32) -> Self {
+        match rid {
+            0..0x1000 => Self::Untrusted,
+            0x1000..0x2000 => Self::Low,
+            0x2000..0x2100 => Self::Medium,
+            0x2100..0x3000 => Self::MediumPlus,
+            0x3000..0x4000 => Self::High,
+            0x4000..0x5000 => Self::System,
+            _ => Self::Protected,
+        }
+    }
+}
+
+/// Explain why a process could not be opened for modification
+pub fn access_denied_reason(
+    protection: Option<ProtectionLevel>,
+    integrity: Option<IntegrityLevel>,
+    is_elevated: bool,
+) -> String {
+    match (protection, integrity) {
+        (Some(level), _) if level.is_protected() => format!(
+            "the process is a protected process ({:?}), even administrators cannot modify it",
+            level
+        ),
+        (_, Some(level)) if level >= IntegrityLevel::High && !is_elevated => format!(
+            "the process runs at {:?} integrity, restart the tool as administrator",
+            level
+        ),
+        (None, None) => "the process could not be queried at all, it may have exited".to_string(),
+        _ => "access was denied, the process may be guarded by its driver".to_string(),
+    }
+}
+
+fn with_process<T>(process_id: u32, query: impl FnOnce(HANDLE) -> Option<T>) -> Option<T> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+        let result = query(handle);
+        let _ = CloseHandle(handle);
+        result
+    }
+}
+
+pub fn get_protection_level(process_id: u32) -> Option<ProtectionLevel> {
+    with_process(process_id, |handle| {
+        let mut information = PROCESS_PROTECTION_LEVEL_INFORMATION::default();
+        unsafe {
+            GetProcessInformation(
+                handle,
+                ProcessProtectionLevelInfo,
+                &mut information as *mut PROCESS_PROTECTION_LEVEL_INFORMATION as *mut _,
+                std::mem::size_of::<PROCESS_PROTECTION_LEVEL_INFORMATION>() as u32,
+            )
+        }
+        .ok()?;
+
+        Some(match information.ProtectionLevel {
+            PROTECTION_LEVEL_NONE => ProtectionLevel::None,
+            PROTECTION_LEVEL_WINTCB_LIGHT => ProtectionLevel::WinTcbLight,
+            PROTECTION_LEVEL_WINDOWS => ProtectionLevel::Windows,
+            PROTECTION_LEVEL_WINDOWS_LIGHT => ProtectionLevel::WindowsLight,
+            PROTECTION_LEVEL_ANTIMALWARE_LIGHT => ProtectionLevel::AntimalwareLight,
+            PROTECTION_LEVEL_LSA_LIGHT => ProtectionLevel::LsaLight,
+            PROTECTION_LEVEL_WINTCB => ProtectionLevel::WinTcb,
+            PROTECTION_LEVEL_CODEGEN_LIGHT => ProtectionLevel::CodeGenLight,
+            PROTECTION_LEVEL_AUTHENTICODE => ProtectionLevel::Authenticode,
+            PROTECTION_LEVEL_PPL_APP => ProtectionLevel::PplApp,
+            _ => ProtectionLevel::Unknown,
+        })
+    })
+}
+
+pub fn get_integrity_level(process_id: u32) -> Option<IntegrityLevel> {
+    with_process(process_id, |handle| unsafe {
+        let mut token = HANDLE::default();
+        OpenProcessToken(handle, TOKEN_QUERY, &mut token).ok()?;
+
+        // the first call only reports the required size
+        let mut size = 0u32;
+        let _ = GetTokenInformation(token, TokenIntegrityLevel, None, 0, &mut size);
+
+        let mut buffer: Vec<u64> = vec![0; (size as usize).div_ceil(8)];
+        let result = GetTokenInformation(
+            token,
+            TokenIntegrityLevel,
+            Some(buffer.as_mut_ptr() as *mut _),
+            size,
+            &mut size,
+        );
+
+        let _ = CloseHandle(token);
+        result.ok()?;
+
+        let label = &*(buffer.as_ptr() as *const TOKEN_MANDATORY_LABEL);
+        let count = *GetSidSubAuthorityCount(label.Label.Sid);
+        if count == 0 {
+            return None;
+        }
+        let rid = *GetSidSubAuthority(label.Label.Sid, count as u32 - 1);
+
+        Some(IntegrityLevel::from_rid(rid))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrity_level_from_rid() {
+        assert_eq!(IntegrityLevel::from_rid(0x0000), IntegrityLevel::Untrusted);
+        assert_eq!(IntegrityLevel::from_rid(0x2000), IntegrityLevel::Medium);
+        assert_eq!(IntegrityLevel::from_rid(0x2100), IntegrityLevel::MediumPlus);
+        assert_eq!(IntegrityLevel::from_rid(0x3000), IntegrityLevel::High);
+        assert_eq!(IntegrityLevel::from_rid(0x4000), IntegrityLevel::System);
+        assert_eq!(IntegrityLevel::from_rid(0x5000), IntegrityLevel::Protected);
+    }
+
+    #[test]
+    fn test_access_denied_reason() {
+        let reason = access_denied_reason(Some(ProtectionLevel::AntimalwareLight), None, true);
+        assert!(reason.contains("protected process"));
+
+        let reason = access_denied_reason(
+            Some(ProtectionLevel::None),
+            Some(IntegrityLevel::System),
+            false,
+        );
+        assert!(reason.contains("administrator"));
+
+        let reason = access_denied_reason(
+            Some(ProtectionLevel::None),
+            Some(IntegrityLevel::System),
+            true,
+        );
+        assert!(reason.contains("driver"));
+    }
+}
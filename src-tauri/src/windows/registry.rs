@@ -0,0 +1,106 @@
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::{
+        Foundation::{ERROR_FILE_NOT_FOUND, ERROR_SUCCESS},
+        System::Registry::{
+            RegDeleteKeyValueW, RegGetValueW, RegSetKeyValueW, HKEY, REG_SZ, RRF_RT_REG_DWORD,
+            RRF_RT_REG_SZ,
+        },
+    },
+};
+
+/// Read a `REG_DWORD` value, `None` when the key or value does not exist
+pub fn read_dword(key: HKEY, subkey: &str, value: &str) -> Option<u32> {
+    let mut data = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+
+    let subkey = HSTRING::from(subkey);
+    let value = HSTRING::from(value);
+
+    let result = unsafe {
+        RegGetValueW(
+            key,
+            PCWSTR(subkey.as_ptr()),
+            PCWSTR(value.as_ptr()),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut data as *mut u32 as *mut _),
+            Some(&mut size),
+        )
+    };
+
+    (result == ERROR_SUCCESS).then_some(data)
+}
+
+/// Read a `REG_SZ` value, `None` when the key or value does not exist
+pub fn read_string(key: HKEY, subkey: &str, value: &str) -> Option<String> {
+    let mut buffer = [0u16; 1024];
+    let mut size = std::mem::size_of_val(&buffer) as u32;
+
+    let subkey = HSTRING::from(subkey);
+    let value = HSTRING::from(value);
+
+    let result = unsafe {
+        RegGetValueW(
+            key,
+            PCWSTR(subkey.as_ptr()),
+            PCWSTR(value.as_ptr()),
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr() as *mut _),
+            Some(&mut size),
+        )
+    };
+
+    if result != ERROR_SUCCESS {
+        return None;
+    }
+
+    // size is in bytes and includes the terminating null
+    let length = (size as usize / 2).saturating_sub(1);
+    Some(String::from_utf16_lossy(&buffer[..length]))
+}
+
+/// Write a `REG_SZ` value, creating the key when it does not exist
+pub fn write_string(key: HKEY, subkey: &str, value: &str, data: &str) -> Result<(), String> {
+    let subkey = HSTRING::from(subkey);
+    let name = HSTRING::from(value);
+    let data: Vec<u16> = data.encode_utf16().chain(std::iter::once(0)).collect();
+
+    let result = unsafe {
+        RegSetKeyValueW(
+            key,
+            PCWSTR(subkey.as_ptr()),
+            PCWSTR(name.as_ptr()),
+            REG_SZ.0,
+            Some(data.as_ptr() as *const _),
+            (data.len() * 2) as u32,
+        )
+    };
+
+    if result != ERROR_SUCCESS {
+        return Err(format!(
+            "Failed to write registry value {}: {:?}",
+            value, result
+        ));
+    }
+
+    Ok(())
+}
+
+/// Delete a value, a value that does not exist counts as deleted
+pub fn delete_value(key: HKEY, subkey: &str, value: &str) -> Result<(), String> {
+    let subkey = HSTRING::from(subkey);
+    let name = HSTRING::from(value);
+
+    let result = unsafe { RegDeleteKeyValueW(key, PCWSTR(subkey.as_ptr()), PCWSTR(name.as_ptr())) };
+
+    if result != ERROR_SUCCESS && result != ERROR_FILE_NOT_FOUND {
+        return Err(format!(
+            "Failed to delete registry value {}: {:?}",
+            value, result
+        ));
+    }
+
+    Ok(())
+}
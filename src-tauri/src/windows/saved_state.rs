@@ -0,0 +1,99 @@
+use crate::{
+    consts,
+    windows::{ace_tools::OriginalProcessSettings, usage::get_process_start_time},
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+
+pub const PROCESS_STATE_FILE: &str = "process_state.json";
+
+/// Settings of a modified process saved to disk, so they survive a crash of the tool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct SavedProcessState {
+    pub process_id: u32,
+    pub process_name: String,
+    /// Creation time of the process, a pid can be reused once the process exited
+    pub start_time: u64,
+    pub original_settings: OriginalProcessSettings,
+}
+
+impl SavedProcessState {
+    /// Whether the saved pid still belongs to the process the settings were recorded for
+    pub fn is_running(&self) -> bool {
+        get_process_start_time(self.process_id).is_ok_and(|time| time == self.start_time)
+    }
+}
+
+/// Location of the state file in the local app data directory,
+/// for callers that run outside of the Tauri app like the CLI
+pub fn default_path() -> Option<PathBuf> {
+    std::env::var_os("LOCALAPPDATA").map(|dir| {
+        PathBuf::from(dir)
+            .join(consts::APP_IDENTIFIER)
+            .join(PROCESS_STATE_FILE)
+    })
+}
+
+/// Read the saved states, a missing file means nothing is left to revert
+pub fn load(path: &Path) -> Result<Vec<SavedProcessState>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+/// Write the saved states, the file is removed once nothing is modified anymore
+pub fn save(path: &Path, states: &[SavedProcessState]) -> Result<(), String> {
+    if states.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(path)
+                .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+        }
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let content = serde_json::to_string_pretty(states)
+        .map_err(|e| format!("Failed to serialize process state: {}", e))?;
+    std::fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("ace-tools-state-{}", std::process::id()));
+        let path = dir.join(PROCESS_STATE_FILE);
+
+        assert!(load(&path).unwrap().is_empty());
+
+        let states = vec![SavedProcessState {
+            process_id: 1234,
+            process_name: "SGuard64.exe".to_string(),
+            start_time: 133_000_000_000_000_000,
+            original_settings: OriginalProcessSettings {
+                priority_class: 0x20,
+                affinity_mask: 0xFF,
+            },
+        }];
+        save(&path, &states).unwrap();
+        assert_eq!(load(&path).unwrap(), states);
+
+        // nothing left to revert removes the file
+        save(&path, &[]).unwrap();
+        assert!(!path.exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    os::windows::process::CommandExt,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Name of the task in the Task Scheduler library
+pub const LOGON_TASK_NAME: &str = "TencentAceTools Watch";
+/// Executable name of the CLI, installed next to the GUI
+pub const CLI_EXECUTABLE: &str = "ace-cli.exe";
+
+/// `CREATE_NO_WINDOW`, keeps schtasks from flashing a console
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+/// Command line the task runs, the watch mode never asks for confirmation
+fn task_command(cli_path: &Path) -> String {
+    format!("\"{}\" optimize --watch --yes", cli_path.display())
+}
+
+fn cli_path() -> Result<PathBuf, String> {
+    let executable =
+        std::env::current_exe().map_err(|e| format!("Failed to get current executable: {}", e))?;
+    let path = executable.with_file_name(CLI_EXECUTABLE);
+
+    if !path.exists() {
+        return Err(format!(
+            "{} not found next to {}",
+            CLI_EXECUTABLE,
+            executable.display()
+        ));
+    }
+
+    Ok(path)
+}
+
+fn schtasks(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("schtasks.exe")
+        .args(args)
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|e| format!("Failed to run schtasks: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Register a task that starts the CLI watch mode elevated at every logon,
+/// creating a task with the highest run level needs administrator rights once
+pub fn install_logon_task() -> Result<(), String> {
+    let command = task_command(&cli_path()?);
+
+    schtasks(&[
+        "/Create",
+        "/TN",
+        LOGON_TASK_NAME,
+        "/TR",
+        &command,
+        "/SC",
+        "ONLOGON",
+        "/RL",
+        "HIGHEST",
+        "/F",
+    ])
+    .map_err(|e| format!("Failed to create scheduled task: {}", e))?;
+
+    tracing::info!("Created scheduled task {}: {}", LOGON_TASK_NAME, command);
+    Ok(())
+}
+
+pub fn uninstall_logon_task() -> Result<(), String> {
+    schtasks(&["/Delete", "/TN", LOGON_TASK_NAME, "/F"])
+        .map_err(|e| format!("Failed to delete scheduled task: {}", e))?;
+
+    tracing::info!("Deleted scheduled task {}", LOGON_TASK_NAME);
+    Ok(())
+}
+
+pub fn is_logon_task_installed() -> bool {
+    schtasks(&["/Query", "/TN", LOGON_TASK_NAME]).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_command_quotes_path() {
+        assert_eq!(
+            task_command(Path::new(r"C:\Program Files\Tencent Ace Tools\ace-cli.exe")),
+            r#""C:\Program Files\Tencent Ace Tools\ace-cli.exe" optimize --watch --yes"#
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::windows::auto_optimize::{request_optimization, OptimizationRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{collections::HashSet, sync::Mutex};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ScheduleTrigger {
+    /// When the app starts, i.e. at logon while autostart is enabled
+    Logon,
+    /// When a process with this executable name starts
+    GameStart(String),
+    /// When the last process with this executable name exits
+    GameExit(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ScheduleAction {
+    Optimize,
+    Revert,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct ScheduleRule {
+    pub trigger: ScheduleTrigger,
+    pub action: ScheduleAction,
+    pub enabled: bool,
+}
+
+impl ScheduleRule {
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.trigger {
+            ScheduleTrigger::GameStart(game) | ScheduleTrigger::GameExit(game)
+                if game.trim().is_empty() =>
+            {
+                Err("Scheduled rules need a game executable name".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn game(&self) -> Option<&str> {
+        match &self.trigger {
+            ScheduleTrigger::GameStart(game) | ScheduleTrigger::GameExit(game) => Some(game),
+            ScheduleTrigger::Logon => None,
+        }
+    }
+}
+
+// Emitted after a scheduled rule ran
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct ScheduleRuleEvent {
+    pub rule: ScheduleRule,
+    pub success: bool,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Rules of the scheduler and the games seen running at the last poll
+#[derive(Debug, Default)]
+pub struct Scheduler {
+    rules: Vec<ScheduleRule>,
+    running: HashSet<String>,
+}
+
+impl Scheduler {
+    pub fn rules(&self) -> &[ScheduleRule] {
+        &self.rules
+    }
+
+    pub fn set_rules(&mut self, rules: Vec<ScheduleRule>) {
+        self.rules = rules;
+    }
+
+    /// Executable names watched by the enabled rules, lower case
+    pub fn watched_games(&self) -> HashSet<String> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .filter_map(|rule| rule.game())
+            .map(|game| game.to_lowercase())
+            .collect()
+    }
+
+    /// Compare with the games running now and return the rules triggered by starts and exits
+    pub fn take_triggered(&mut self, running: HashSet<String>) -> Vec<ScheduleRule> {
+        let triggered = self
+            .rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .filter(|rule| match &rule.trigger {
+                ScheduleTrigger::GameStart(game) => {
+                    let game = game.to_lowercase();
+                    running.contains(&game) && !self.running.contains(&game)
+                }
+                ScheduleTrigger::GameExit(game) => {
+                    let game = game.to_lowercase();
+                    !running.contains(&game) && self.running.contains(&game)
+                }
+                ScheduleTrigger::Logon => false,
+            })
+            .cloned()
+            .collect();
+
+        self.running = running;
+        triggered
+    }
+}
+
+// State wrapper for the rule scheduler
+#[derive(Default)]
+pub struct SchedulerState(pub Mutex<Scheduler>);
+
+/// Run a rule, an optimization waits for ACE in the background and reports
+/// through `emit_rule_result` once it is done
+pub fn run_rule(app: &AppHandle, rule: &ScheduleRule) {
+    tracing::info!(
+        "Running scheduled rule {:?} on {:?}",
+        rule.action,
+        rule.trigger
+    );
+
+    match rule.action {
+        ScheduleAction::Optimize => {
+            request_optimization(app, OptimizationRequest::Rule(rule.clone()));
+        }
+        // records the revert in the history as well
+        ScheduleAction::Revert => {
+            let (success, message) =
+                match crate::command::restore_all_ace_guard_processes(app.state(), app.state()) {
+                    Ok(message) => (true, message),
+                    Err(e) => (false, e),
+                };
+            emit_rule_result(app, rule, success, &message);
+        }
+    }
+}
+
+pub fn emit_rule_result(app: &AppHandle, rule: &ScheduleRule, success: bool, message: &str) {
+    if success {
+        tracing::info!("Scheduled rule: {}", message);
+    } else {
+        tracing::warn!("Scheduled rule: {}", message);
+    }
+
+    let _ = ScheduleRuleEvent {
+        rule: rule.clone(),
+        success,
+        message: message.to_string(),
+        timestamp: Utc::now(),
+    }
+    .emit(app);
+}
+
+/// Run the logon rules once, game starts and exits are picked up by the game
+/// launch detector
+pub fn run_logon_rules(app: &AppHandle) {
+    let logon_rules: Vec<ScheduleRule> = match app.state::<SchedulerState>().0.lock() {
+        Ok(scheduler) => scheduler
+            .rules()
+            .iter()
+            .filter(|rule| rule.enabled && rule.trigger == ScheduleTrigger::Logon)
+            .cloned()
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    for rule in &logon_rules {
+        run_rule(app, rule);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(trigger: ScheduleTrigger, action: ScheduleAction) -> ScheduleRule {
+        ScheduleRule {
+            trigger,
+            action,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_game_start_and_exit_trigger_rules() {
+        let start = rule(
+            ScheduleTrigger::GameStart("UAGame.exe".to_string()),
+            ScheduleAction::Optimize,
+        );
+        let exit = rule(
+            ScheduleTrigger::GameExit("UAGame.exe".to_string()),
+            ScheduleAction::Revert,
+        );
+        let mut scheduler = Scheduler::default();
+        scheduler.set_rules(vec![
+            start.clone(),
+            exit.clone(),
+            rule(ScheduleTrigger::Logon, ScheduleAction::Optimize),
+        ]);
+
+        let games = scheduler.watched_games();
+        assert_eq!(games, HashSet::from(["uagame.exe".to_string()]));
+
+        assert_eq!(scheduler.take_triggered(games.clone()), vec![start]);
+        assert!(scheduler.take_triggered(games).is_empty());
+        assert_eq!(scheduler.take_triggered(HashSet::new()), vec![exit]);
+        assert!(scheduler.take_triggered(HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_game_rules_need_a_game() {
+        let rule = rule(
+            ScheduleTrigger::GameExit(" ".to_string()),
+            ScheduleAction::Revert,
+        );
+        assert!(rule.validate().is_err());
+    }
+}
@@ -1,7 +1,7 @@
-use image::RgbaImage;
+use image::{codecs::jpeg::JpegEncoder, RgbaImage};
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use std::io::Cursor;
+use std::{io::Cursor, path::Path};
 use win_screenshot::prelude::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -12,12 +12,74 @@ pub struct ScreenShot {
     pub format: String,
 }
 
+impl ScreenShot {
+    /// Write the screenshot to `path`, re-encoding it when the extension asks
+    /// for another format than PNG, e.g. `.jpg` or `.webp`
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let format = image::ImageFormat::from_path(path).unwrap_or(image::ImageFormat::Png);
+
+        if format.extensions_str().contains(&self.format.as_str()) {
+            return std::fs::write(path, &self.image_data)
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e));
+        }
+
+        let image = image::load_from_memory(&self.image_data)
+            .map_err(|e| format!("Failed to decode screenshot: {}", e))?;
+        // JPEG has no alpha channel
+        let image = match format {
+            image::ImageFormat::Jpeg => image::DynamicImage::ImageRgb8(image.to_rgb8()),
+            _ => image,
+        };
+
+        image
+            .save_with_format(path, format)
+            .map_err(|e| format!("Failed to save screenshot to {}: {}", path.display(), e))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct WindowInfo {
     pub title: String,
     pub process_id: u32,
 }
 
+/// Window picker entry with a small preview
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WindowThumbnail {
+    pub window: WindowInfo,
+    /// JPEG preview, `None` when the window could not be captured, e.g. while minimized
+    pub thumbnail: Option<ScreenShot>,
+}
+
+/// Quality of the JPEG thumbnails, they only need to be recognizable
+const THUMBNAIL_QUALITY: u8 = 70;
+
+/// Rectangle cut out of the captured window, relative to its top left corner
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type)]
+pub struct CaptureCrop {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, Type)]
+pub struct CaptureOptions {
+    /// Copy the window from the screen with BitBlt instead of asking it to draw itself
+    /// with PrintWindow, which leaves DirectX windows black
+    pub use_bitblt: bool,
+    /// Leave out the title bar and borders
+    pub client_only: bool,
+    pub crop: Option<CaptureCrop>,
+}
+
+/// What to capture when the caller does not need the picked window or region
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type)]
+pub enum ScreenshotSource {
+    Window(u32),
+    FullScreen,
+}
+
 pub struct ScreenshotCapture;
 
 impl ScreenshotCapture {
@@ -38,6 +100,30 @@ impl ScreenshotCapture {
         Ok(window_infos)
     }
 
+    /// Get all windows together with a JPEG thumbnail at most `max_width` pixels wide
+    pub fn get_all_windows_with_thumbnails(max_width: u32) -> Result<Vec<WindowThumbnail>, String> {
+        let windows = window_list().map_err(|e| format!("Failed to get windows: {:?}", e))?;
+
+        let thumbnails: Vec<WindowThumbnail> = windows
+            .iter()
+            .filter(|w| !w.window_name.is_empty())
+            .map(|w| WindowThumbnail {
+                window: WindowInfo {
+                    title: w.window_name.clone(),
+                    process_id: w.hwnd as u32,
+                },
+                thumbnail: capture_window(w.hwnd)
+                    .map_err(|e| format!("Failed to capture window {}: {:?}", w.hwnd, e))
+                    .and_then(|buf| Self::encode_thumbnail(buf, max_width))
+                    .inspect_err(|e| tracing::debug!("{}", e))
+                    .ok(),
+            })
+            .collect();
+
+        tracing::debug!("Captured thumbnails of {} windows", thumbnails.len());
+        Ok(thumbnails)
+    }
+
     /// Capture entire screen
     pub fn capture_display() -> Result<ScreenShot, String> {
         let buf = capture_display()
@@ -46,6 +132,37 @@ impl ScreenshotCapture {
         Self::encode_buffer_to_png(buf)
     }
 
+    /// Cut a rectangle out of a screenshot
+    pub fn crop(
+        screenshot: &ScreenShot,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> Result<ScreenShot, String> {
+        let image = image::load_from_memory(&screenshot.image_data)
+            .map_err(|e| format!("Failed to decode screenshot: {}", e))?;
+
+        if width == 0
+            || height == 0
+            || x.saturating_add(width) > image.width()
+            || y.saturating_add(height) > image.height()
+        {
+            return Err(format!(
+                "Region {}x{} at ({}, {}) is outside of the {}x{} screenshot",
+                width,
+                height,
+                x,
+                y,
+                image.width(),
+                image.height()
+            ));
+        }
+
+        let cropped = image.crop_imm(x, y, width, height).to_rgba8();
+        Self::encode_data_to_png(cropped.as_raw(), width, height)
+    }
+
     /// Capture window by process ID (hwnd)
     pub fn capture_by_window_id(window_id: u32) -> Result<ScreenShot, String> {
         let buf = capture_window(window_id as isize)
@@ -108,6 +225,27 @@ impl ScreenshotCapture {
         Self::encode_buffer_to_png(buf)
     }
 
+    pub fn capture_source(source: ScreenshotSource) -> Result<ScreenShot, String> {
+        match source {
+            ScreenshotSource::Window(window_id) => Self::capture_by_window_id(window_id),
+            ScreenshotSource::FullScreen => Self::capture_display(),
+        }
+    }
+
+    /// Advanced window capture driven by [`CaptureOptions`]
+    pub fn capture_window_with_options(
+        window_id: u32,
+        options: CaptureOptions,
+    ) -> Result<ScreenShot, String> {
+        Self::capture_window_advanced(
+            window_id,
+            options.use_bitblt,
+            options.client_only,
+            options.crop.map(|crop| [crop.x, crop.y]),
+            options.crop.map(|crop| [crop.width, crop.height]),
+        )
+    }
+
     /// Encode screenshot buffer to PNG binary data
     fn encode_buffer_to_png(buf: RgbBuf) -> Result<ScreenShot, String> {
         let width = buf.width;
@@ -133,6 +271,31 @@ impl ScreenshotCapture {
         })
     }
 
+    /// Scale a screenshot buffer down to `max_width` and encode it as JPEG
+    fn encode_thumbnail(buf: RgbBuf, max_width: u32) -> Result<ScreenShot, String> {
+        let rgba_image = RgbaImage::from_raw(buf.width, buf.height, buf.pixels)
+            .ok_or_else(|| "Failed to create RGBA image from buffer".to_string())?;
+
+        let mut image = image::DynamicImage::ImageRgba8(rgba_image);
+        if max_width > 0 && image.width() > max_width {
+            image = image.thumbnail(max_width, u32::MAX);
+        }
+        // JPEG has no alpha channel
+        let image = image::DynamicImage::ImageRgb8(image.to_rgb8());
+
+        let mut jpeg_bytes = Vec::new();
+        image
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut jpeg_bytes, THUMBNAIL_QUALITY))
+            .map_err(|e| format!("Failed to encode thumbnail as JPEG: {}", e))?;
+
+        Ok(ScreenShot {
+            image_data: jpeg_bytes,
+            width: image.width(),
+            height: image.height(),
+            format: "jpeg".to_string(),
+        })
+    }
+
     /// Create a demo screenshot (for testing purposes)
     pub fn create_demo_screenshot() -> Result<ScreenShot, String> {
         // Create a simple 100x100 red rectangle as a demo
@@ -215,6 +378,18 @@ mod tests {
                 screenshot.width, screenshot.height, screenshot.format);
     }
 
+    #[test]
+    fn test_save_screenshot_as_jpeg() {
+        let screenshot = ScreenshotCapture::create_demo_screenshot().unwrap();
+        let path = std::env::temp_dir().join(format!("ace-tools-shot-{}.jpg", std::process::id()));
+
+        screenshot.save(&path).unwrap();
+        let saved = image::open(&path).unwrap();
+        assert_eq!((saved.width(), saved.height()), (100, 100));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn test_window_info_serialization() {
         let window_info = WindowInfo {
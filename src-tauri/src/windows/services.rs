@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::{
+    core::{PCWSTR, PWSTR},
+    Win32::System::Services::{
+        CloseServiceHandle, EnumServicesStatusExW, OpenSCManagerW, OpenServiceW,
+        QueryServiceConfigW, ENUM_SERVICE_STATUS_PROCESSW, ENUM_SERVICE_TYPE,
+        QUERY_SERVICE_CONFIGW, SC_ENUM_PROCESS_INFO, SC_HANDLE, SC_MANAGER_ENUMERATE_SERVICE,
+        SERVICE_AUTO_START, SERVICE_BOOT_START, SERVICE_DEMAND_START, SERVICE_DISABLED,
+        SERVICE_DRIVER, SERVICE_PAUSED, SERVICE_QUERY_CONFIG, SERVICE_RUNNING,
+        SERVICE_START_PENDING, SERVICE_STATE_ALL, SERVICE_STATUS_CURRENT_STATE, SERVICE_STOPPED,
+        SERVICE_STOP_PENDING, SERVICE_SYSTEM_START, SERVICE_WIN32,
+    },
+};
+
+/// Name prefixes of the ACE drivers, e.g. `ACE-BASE`
+const ACE_DRIVER_PREFIX: &str = "ACE-";
+/// Found in the name or display name of the ACE user mode service
+const ACE_SERVICE_MARKER: &str = "AntiCheatExpert";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ServiceState {
+    Stopped,
+    StartPending,
+    StopPending,
+    Running,
+    Paused,
+    Other,
+}
+
+impl From<SERVICE_STATUS_CURRENT_STATE> for ServiceState {
+    fn from(state: SERVICE_STATUS_CURRENT_STATE) -> Self {
+        match state {
+            SERVICE_STOPPED => Self::Stopped,
+            SERVICE_START_PENDING => Self::StartPending,
+            SERVICE_STOP_PENDING => Self::StopPending,
+            SERVICE_RUNNING => Self::Running,
+            SERVICE_PAUSED => Self::Paused,
+            _ => Self::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ServiceStartType {
+    Boot,
+    System,
+    Automatic,
+    Manual,
+    Disabled,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AceServiceStatus {
+    pub name: String,
+    pub display_name: String,
+    /// Kernel drivers like `ACE-BASE` as opposed to user mode services
+    pub is_driver: bool,
+    pub state: ServiceState,
+    pub start_type: ServiceStartType,
+    /// Process hosting a running user mode service
+    pub process_id: Option<u32>,
+}
+
+/// Whether a service or driver belongs to the ACE anti-cheat stack
+fn is_ace_service(name: &str, display_name: &str) -> bool {
+    let has_driver_prefix = name
+        .get(..ACE_DRIVER_PREFIX.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(ACE_DRIVER_PREFIX));
+    let marker = ACE_SERVICE_MARKER.to_ascii_lowercase();
+
+    has_driver_prefix
+        || name.to_ascii_lowercase().contains(&marker)
+        || display_name.to_ascii_lowercase().contains(&marker)
+}
+
+unsafe fn pwstr_to_string(value: PWSTR) -> String {
+    if value.is_null() {
+        String::new()
+    } else {
+        value.to_string().unwrap_or_default()
+    }
+}
+
+unsafe fn query_start_type(manager: SC_HANDLE, name: PWSTR) -> ServiceStartType {
+    let Ok(service) = OpenServiceW(manager, PCWSTR(name.0), SERVICE_QUERY_CONFIG) else {
+        return ServiceStartType::Unknown;
+    };
+
+    // the first call only reports the required size
+    let mut size = 0u32;
+    let _ = QueryServiceConfigW(service, None, 0, &mut size);
+
+    let mut buffer: Vec<u64> = vec![0; (size as usize).div_ceil(8)];
+    let config = buffer.as_mut_ptr() as *mut QUERY_SERVICE_CONFIGW;
+    let result = QueryServiceConfigW(service, Some(config), size, &mut size);
+
+    let _ = CloseServiceHandle(service);
+
+    if result.is_err() {
+        return ServiceStartType::Unknown;
+    }
+
+    match (*config).dwStartType {
+        SERVICE_BOOT_START => ServiceStartType::Boot,
+        SERVICE_SYSTEM_START => ServiceStartType::System,
+        SERVICE_AUTO_START => ServiceStartType::Automatic,
+        SERVICE_DEMAND_START => ServiceStartType::Manual,
+        SERVICE_DISABLED => ServiceStartType::Disabled,
+        _ => ServiceStartType::Unknown,
+    }
+}
+
+/// Query the service control manager for the ACE services and drivers
+pub fn get_ace_service_status() -> Result<Vec<AceServiceStatus>, String> {
+    let mut services = Vec::new();
+
+    unsafe {
+        let manager = OpenSCManagerW(PCWSTR::null(), PCWSTR::null(), SC_MANAGER_ENUMERATE_SERVICE)
+            .map_err(|e| format!("Failed to open service control manager: {:?}", e))?;
+
+        let service_type = ENUM_SERVICE_TYPE(SERVICE_WIN32.0 | SERVICE_DRIVER.0);
+
+        // the first call only reports the required size
+        let mut size = 0u32;
+        let mut count = 0u32;
+        let _ = EnumServicesStatusExW(
+            manager,
+            SC_ENUM_PROCESS_INFO,
+            service_type,
+            SERVICE_STATE_ALL,
+            None,
+            &mut size,
+            &mut count,
+            None,
+            PCWSTR::null(),
+        );
+
+        // u64 elements keep the entries aligned
+        let mut buffer: Vec<u64> = vec![0; (size as usize).div_ceil(8)];
+        let bytes = std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, size as usize);
+        let result = EnumServicesStatusExW(
+            manager,
+            SC_ENUM_PROCESS_INFO,
+            service_type,
+            SERVICE_STATE_ALL,
+            Some(bytes),
+            &mut size,
+            &mut count,
+            None,
+            PCWSTR::null(),
+        );
+
+        if let Err(e) = result {
+            let _ = CloseServiceHandle(manager);
+            return Err(format!("Failed to enumerate services: {:?}", e));
+        }
+
+        let entries = std::slice::from_raw_parts(
+            buffer.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW,
+            count as usize,
+        );
+
+        for entry in entries {
+            let name = pwstr_to_string(entry.lpServiceName);
+            let display_name = pwstr_to_string(entry.lpDisplayName);
+            if !is_ace_service(&name, &display_name) {
+                continue;
+            }
+
+            let status = &entry.ServiceStatusProcess;
+            services.push(AceServiceStatus {
+                start_type: query_start_type(manager, entry.lpServiceName),
+                is_driver: status.dwServiceType.0 & SERVICE_DRIVER.0 != 0,
+                state: status.dwCurrentState.into(),
+                process_id: (status.dwProcessId != 0).then_some(status.dwProcessId),
+                name,
+                display_name,
+            });
+        }
+
+        let _ = CloseServiceHandle(manager);
+    }
+
+    Ok(services)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_ace_service() {
+        assert!(is_ace_service("ACE-BASE", "ACE-BASE"));
+        assert!(is_ace_service("ace-game", ""));
+        assert!(is_ace_service(
+            "AntiCheatExpert Service",
+            "AntiCheatExpert Service"
+        ));
+        assert!(is_ace_service("ACE-GUARD", "AntiCheatExpert Protection"));
+
+        assert!(!is_ace_service("AudioSrv", "Windows Audio"));
+        assert!(!is_ace_service("Ace", "Some Ace service"));
+    }
+}
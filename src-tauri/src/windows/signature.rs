@@ -0,0 +1,211 @@
+use crate::consts;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use windows::{
+    core::PCWSTR,
+    Win32::{
+        Foundation::{
+            CERT_E_REVOCATION_FAILURE, CRYPT_E_NO_REVOCATION_CHECK, CRYPT_E_REVOCATION_OFFLINE,
+            HANDLE, HWND, TRUST_E_NOSIGNATURE,
+        },
+        Security::{
+            Cryptography::{CertGetNameStringW, CERT_NAME_SIMPLE_DISPLAY_TYPE},
+            WinTrust::{
+                WTHelperGetProvCertFromChain, WTHelperGetProvSignerFromChain,
+                WTHelperProvDataFromStateData, WinVerifyTrust, WINTRUST_ACTION_GENERIC_VERIFY_V2,
+                WINTRUST_DATA, WINTRUST_DATA_0, WINTRUST_DATA_REVOCATION_CHECKS,
+                WINTRUST_FILE_INFO, WTD_CACHE_ONLY_URL_RETRIEVAL, WTD_CHOICE_FILE,
+                WTD_REVOCATION_CHECK_CHAIN_EXCLUDE_ROOT, WTD_REVOKE_NONE, WTD_REVOKE_WHOLECHAIN,
+                WTD_STATEACTION_CLOSE, WTD_STATEACTION_VERIFY, WTD_UI_NONE,
+            },
+        },
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum SignatureStatus {
+    /// Valid signature from a known ACE publisher
+    Trusted,
+    /// Valid signature from an unexpected publisher
+    UntrustedSigner,
+    /// The executable is not signed at all
+    Unsigned,
+    /// The signature is present but broken, expired or revoked. Revocation is only
+    /// known when the revocation list of the chain is in the local cache
+    Invalid,
+    /// The executable could not be checked, e.g. the path is unknown
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SignatureInfo {
+    pub status: SignatureStatus,
+    pub signer: Option<String>,
+}
+
+impl SignatureInfo {
+    pub fn is_trusted(&self) -> bool {
+        self.status == SignatureStatus::Trusted
+    }
+}
+
+/// Check whether a signer name is exactly one of the known ACE publishers
+pub fn is_trusted_signer(signer: &str) -> bool {
+    consts::ACE_TRUSTED_SIGNERS.contains(&signer.trim())
+}
+
+/// Results of `WinVerifyTrust` telling that the revocation status could not be read
+fn is_revocation_unknown(result: i32) -> bool {
+    [
+        CRYPT_E_REVOCATION_OFFLINE,
+        CERT_E_REVOCATION_FAILURE,
+        CRYPT_E_NO_REVOCATION_CHECK,
+    ]
+    .iter()
+    .any(|unknown| unknown.0 == result)
+}
+
+/// Verify the Authenticode signature of an executable and check its publisher
+pub fn verify_file_signature(file_path: &str) -> SignatureInfo {
+    if !Path::new(file_path).is_absolute() {
+        return SignatureInfo {
+            status: SignatureStatus::Unknown,
+            signer: None,
+        };
+    }
+
+    let wide_path: Vec<u16> = file_path.encode_utf16().chain(std::iter::once(0)).collect();
+
+    unsafe {
+        // revocation lists are only read from the cache, a scan never waits on the network
+        let (mut result, mut signer) = verify_trust(&wide_path, WTD_REVOKE_WHOLECHAIN);
+        if is_revocation_unknown(result) {
+            // nothing cached for this chain, the rest of the signature can still be checked
+            (result, signer) = verify_trust(&wide_path, WTD_REVOKE_NONE);
+        }
+
+        let status = if result == 0 {
+            match &signer {
+                Some(name) if is_trusted_signer(name) => SignatureStatus::Trusted,
+                _ => SignatureStatus::UntrustedSigner,
+            }
+        } else if result == TRUST_E_NOSIGNATURE.0 {
+            SignatureStatus::Unsigned
+        } else {
+            SignatureStatus::Invalid
+        };
+
+        tracing::debug!(
+            "Signature of {}: {:?} (signer: {:?}, result: {:#x})",
+            file_path,
+            status,
+            signer,
+            result
+        );
+
+        SignatureInfo { status, signer }
+    }
+}
+
+/// Run `WinVerifyTrust` on a file, returns its result and the signer when it succeeded
+unsafe fn verify_trust(
+    wide_path: &[u16],
+    revocation_checks: WINTRUST_DATA_REVOCATION_CHECKS,
+) -> (i32, Option<String>) {
+    let mut file_info = WINTRUST_FILE_INFO {
+        cbStruct: std::mem::size_of::<WINTRUST_FILE_INFO>() as u32,
+        pcwszFilePath: PCWSTR(wide_path.as_ptr()),
+        ..Default::default()
+    };
+
+    let mut trust_data = WINTRUST_DATA {
+        cbStruct: std::mem::size_of::<WINTRUST_DATA>() as u32,
+        dwUIChoice: WTD_UI_NONE,
+        fdwRevocationChecks: revocation_checks,
+        dwUnionChoice: WTD_CHOICE_FILE,
+        Anonymous: WINTRUST_DATA_0 {
+            pFile: &mut file_info,
+        },
+        dwStateAction: WTD_STATEACTION_VERIFY,
+        dwProvFlags: WTD_CACHE_ONLY_URL_RETRIEVAL | WTD_REVOCATION_CHECK_CHAIN_EXCLUDE_ROOT,
+        ..Default::default()
+    };
+
+    let mut action = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+    let result = WinVerifyTrust(
+        HWND::default(),
+        &mut action,
+        &mut trust_data as *mut WINTRUST_DATA as *mut _,
+    );
+
+    let signer = if result == 0 {
+        get_signer_name(trust_data.hWVTStateData)
+    } else {
+        None
+    };
+
+    // release the state data allocated by the verify action
+    trust_data.dwStateAction = WTD_STATEACTION_CLOSE;
+    WinVerifyTrust(
+        HWND::default(),
+        &mut action,
+        &mut trust_data as *mut WINTRUST_DATA as *mut _,
+    );
+
+    (result, signer)
+}
+
+/// Read the subject name of the leaf signing certificate
+unsafe fn get_signer_name(state_data: HANDLE) -> Option<String> {
+    let provider_data = WTHelperProvDataFromStateData(state_data);
+    if provider_data.is_null() {
+        return None;
+    }
+
+    let signer = WTHelperGetProvSignerFromChain(provider_data, 0, false, 0);
+    if signer.is_null() {
+        return None;
+    }
+
+    let certificate = WTHelperGetProvCertFromChain(signer, 0);
+    if certificate.is_null() || (*certificate).pCert.is_null() {
+        return None;
+    }
+
+    let cert_context = (*certificate).pCert;
+    let length = CertGetNameStringW(cert_context, CERT_NAME_SIMPLE_DISPLAY_TYPE, 0, None, None);
+    if length <= 1 {
+        return None;
+    }
+
+    let mut buffer = vec![0u16; length as usize];
+    CertGetNameStringW(
+        cert_context,
+        CERT_NAME_SIMPLE_DISPLAY_TYPE,
+        0,
+        None,
+        Some(&mut buffer),
+    );
+
+    Some(String::from_utf16_lossy(&buffer[..length as usize - 1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trusted_signer_is_an_exact_match() {
+        assert!(is_trusted_signer(
+            "Tencent Technology (Shenzhen) Company Limited"
+        ));
+        assert!(!is_trusted_signer(
+            "Not Tencent Technology (Shenzhen) Company Limited"
+        ));
+        assert!(!is_trusted_signer("Tencent"));
+        assert!(!is_trusted_signer(
+            "tencent technology (shenzhen) company limited"
+        ));
+    }
+}
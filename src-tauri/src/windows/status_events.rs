@@ -0,0 +1,237 @@
+use crate::windows::{ace_tools::ProcessInfo, background_pause, AceProcessControllerState};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{sync::Mutex, time::Duration};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+const MIN_RESCAN_INTERVAL_SECS: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct ProcessStatusConfig {
+    pub enabled: bool,
+    pub rescan_interval_secs: u32,
+}
+
+impl Default for ProcessStatusConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            // a rescan walks every process of the system, keep it infrequent
+            rescan_interval_secs: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ProcessChangeKind {
+    Found,
+    Optimized,
+    Exited,
+    /// ACE restored its own priority or affinity
+    Reset,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct ProcessStatusChange {
+    pub process_id: u32,
+    pub process_name: String,
+    pub kind: ProcessChangeKind,
+}
+
+// Emitted when the scanned ACE processes or their optimization state change
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct ProcessStatusEvent {
+    pub processes: Vec<ProcessInfo>,
+    /// What happened since the last event, empty for changes like a new priority
+    pub changes: Vec<ProcessStatusChange>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// The parts of a process the frontend displays, usage figures are left out on purpose
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProcessStatus {
+    process_id: u32,
+    process_name: String,
+    is_optimized: bool,
+    is_suspended: bool,
+    reset_count: u32,
+    current_priority: String,
+    current_affinity: String,
+}
+
+impl From<&ProcessInfo> for ProcessStatus {
+    fn from(process: &ProcessInfo) -> Self {
+        Self {
+            process_id: process.process_id,
+            process_name: process.process_name.clone(),
+            is_optimized: process.is_optimized,
+            is_suspended: process.is_suspended,
+            reset_count: process.reset_count,
+            current_priority: process.current_priority.clone(),
+            current_affinity: process.current_affinity.clone(),
+        }
+    }
+}
+
+/// Remembers the last published process list
+#[derive(Debug, Default)]
+pub struct ProcessStatusWatcher {
+    config: ProcessStatusConfig,
+    last_status: Option<Vec<ProcessStatus>>,
+}
+
+impl ProcessStatusWatcher {
+    pub fn config(&self) -> &ProcessStatusConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, mut config: ProcessStatusConfig) {
+        config.rescan_interval_secs = config.rescan_interval_secs.max(MIN_RESCAN_INTERVAL_SECS);
+        self.config = config;
+    }
+
+    /// Store the new list and return what changed, `None` when it equals the last one
+    fn update(&mut self, processes: &[ProcessInfo]) -> Option<Vec<ProcessStatusChange>> {
+        let status: Vec<ProcessStatus> = processes.iter().map(ProcessStatus::from).collect();
+        if self.last_status.as_ref() == Some(&status) {
+            return None;
+        }
+
+        let changes = diff_status(self.last_status.as_deref().unwrap_or_default(), &status);
+        self.last_status = Some(status);
+        Some(changes)
+    }
+}
+
+fn diff_status(last: &[ProcessStatus], current: &[ProcessStatus]) -> Vec<ProcessStatusChange> {
+    let change = |status: &ProcessStatus, kind| ProcessStatusChange {
+        process_id: status.process_id,
+        process_name: status.process_name.clone(),
+        kind,
+    };
+    let mut changes = Vec::new();
+
+    for status in current {
+        match last.iter().find(|s| s.process_id == status.process_id) {
+            None => changes.push(change(status, ProcessChangeKind::Found)),
+            Some(previous) => {
+                if status.reset_count > previous.reset_count {
+                    changes.push(change(status, ProcessChangeKind::Reset));
+                }
+                if status.is_optimized && !previous.is_optimized {
+                    changes.push(change(status, ProcessChangeKind::Optimized));
+                }
+            }
+        }
+    }
+
+    changes.extend(
+        last.iter()
+            .filter(|s| !current.iter().any(|c| c.process_id == s.process_id))
+            .map(|status| change(status, ProcessChangeKind::Exited)),
+    );
+
+    changes
+}
+
+// State wrapper for the process status watcher
+#[derive(Default)]
+pub struct ProcessStatusState(pub Mutex<ProcessStatusWatcher>);
+
+/// Spawn a background thread that rescans ACE processes and pushes changes to the frontend
+pub fn spawn_status_watcher(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        let config = match app.state::<ProcessStatusState>().0.lock() {
+            Ok(watcher) => watcher.config().clone(),
+            Err(_) => ProcessStatusConfig::default(),
+        };
+
+        std::thread::sleep(Duration::from_secs(config.rescan_interval_secs as u64));
+
+        if !config.enabled || background_pause::is_paused(&app) {
+            continue;
+        }
+
+        let processes = {
+            let controller_state = app.state::<AceProcessControllerState>();
+            let Ok(mut controller) = controller_state.0.lock() else {
+                continue;
+            };
+
+            // a quiet rescan, no processes is a state worth publishing as well
+            match controller.refresh_processes() {
+                Ok(processes) => processes.to_vec(),
+                Err(_) => continue,
+            }
+        };
+
+        let changes = match app.state::<ProcessStatusState>().0.lock() {
+            Ok(mut watcher) => watcher.update(&processes),
+            Err(_) => continue,
+        };
+
+        if let Some(changes) = changes {
+            tracing::debug!(
+                "ACE process status changed, {} processes, {} changes",
+                processes.len(),
+                changes.len()
+            );
+            let _ = ProcessStatusEvent {
+                processes,
+                changes,
+                timestamp: Utc::now(),
+            }
+            .emit(&app);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(process_id: u32, is_optimized: bool, reset_count: u32) -> ProcessStatus {
+        ProcessStatus {
+            process_id,
+            process_name: "SGuard64.exe".to_string(),
+            is_optimized,
+            is_suspended: false,
+            reset_count,
+            current_priority: "Normal".to_string(),
+            current_affinity: "0xFF".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diff_status_reports_process_changes() {
+        let kinds = |last: &[ProcessStatus], current: &[ProcessStatus]| {
+            diff_status(last, current)
+                .into_iter()
+                .map(|change| (change.process_id, change.kind))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kinds(&[], &[status(1, false, 0)]),
+            vec![(1, ProcessChangeKind::Found)]
+        );
+        assert_eq!(
+            kinds(&[status(1, false, 0)], &[status(1, true, 0)]),
+            vec![(1, ProcessChangeKind::Optimized)]
+        );
+        assert_eq!(
+            kinds(&[status(1, true, 0)], &[status(1, false, 1)]),
+            vec![(1, ProcessChangeKind::Reset)]
+        );
+        assert_eq!(
+            kinds(
+                &[status(1, true, 0), status(2, true, 0)],
+                &[status(2, true, 0)]
+            ),
+            vec![(1, ProcessChangeKind::Exited)]
+        );
+    }
+}
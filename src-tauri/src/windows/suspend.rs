@@ -0,0 +1,45 @@
+use windows::Win32::{
+    Foundation::{CloseHandle, HANDLE, NTSTATUS},
+    System::Threading::{OpenProcess, PROCESS_SUSPEND_RESUME},
+};
+
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtSuspendProcess(process_handle: HANDLE) -> NTSTATUS;
+    fn NtResumeProcess(process_handle: HANDLE) -> NTSTATUS;
+}
+
+fn call_with_process(
+    process_id: u32,
+    action: &str,
+    call: unsafe extern "system" fn(HANDLE) -> NTSTATUS,
+) -> Result<(), String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_SUSPEND_RESUME, false, process_id)
+            .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let status = call(handle);
+
+        let _ = CloseHandle(handle);
+
+        // negative values are error codes
+        if status.0 < 0 {
+            return Err(format!(
+                "Failed to {} process {}: 0x{:08X}",
+                action, process_id, status.0
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Suspend every thread of a process. Suspensions nest, each one needs a matching resume
+pub fn suspend_process(process_id: u32) -> Result<(), String> {
+    call_with_process(process_id, "suspend", NtSuspendProcess)
+}
+
+/// Undo one suspension of a process
+pub fn resume_process(process_id: u32) -> Result<(), String> {
+    call_with_process(process_id, "resume", NtResumeProcess)
+}
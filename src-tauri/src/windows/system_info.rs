@@ -0,0 +1,202 @@
+use crate::windows::{registry, topology::get_cpu_topology, utils::is_running_as_admin};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::{
+    core::{PCWSTR, PWSTR},
+    Win32::{
+        Foundation::{CloseHandle, HANDLE},
+        Graphics::Gdi::{EnumDisplayDevicesW, DISPLAY_DEVICEW, DISPLAY_DEVICE_PRIMARY_DEVICE},
+        Security::{
+            GetTokenInformation, LookupPrivilegeNameW, TokenPrivileges, SE_PRIVILEGE_ENABLED,
+            TOKEN_PRIVILEGES, TOKEN_QUERY,
+        },
+        System::{
+            Registry::HKEY_LOCAL_MACHINE,
+            SystemInformation::{GlobalMemoryStatusEx, MEMORYSTATUSEX},
+            Threading::{GetActiveProcessorGroupCount, GetCurrentProcess, OpenProcessToken},
+        },
+    },
+};
+
+const CURRENT_VERSION_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+const CENTRAL_PROCESSOR_KEY: &str = r"HARDWARE\DESCRIPTION\System\CentralProcessor\0";
+/// First build of Windows 11, whose ProductName still reads Windows 10
+const WINDOWS_11_BUILD: u32 = 22000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SystemInfo {
+    /// e.g. `Windows 10 Pro 23H2`
+    pub os_name: String,
+    /// Build number including the update revision, e.g. `22631.3880`
+    pub os_build: String,
+    pub is_windows_11: bool,
+    pub cpu_model: String,
+    pub physical_cores: u32,
+    pub logical_processors: u32,
+    /// On non-hybrid CPUs every core counts as a performance core
+    pub performance_cores: u32,
+    pub efficiency_cores: u32,
+    /// More than one on machines with over 64 logical processors
+    pub processor_groups: u32,
+    /// Installed physical memory in bytes
+    pub total_memory: u64,
+    /// Name of the primary display adapter
+    pub gpu_name: Option<String>,
+    pub is_elevated: bool,
+    /// Privileges currently enabled on the process token
+    pub enabled_privileges: Vec<String>,
+}
+
+fn utf16_to_string(buffer: &[u16]) -> String {
+    let length = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..length])
+}
+
+fn get_os_version() -> (String, String) {
+    let read = |value| registry::read_string(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, value);
+
+    let product_name = read("ProductName").unwrap_or_else(|| "Windows".to_string());
+    let os_name = match read("DisplayVersion") {
+        Some(version) => format!("{} {}", product_name, version),
+        None => product_name,
+    };
+
+    let build = read("CurrentBuild").unwrap_or_else(|| "unknown".to_string());
+    let os_build = match registry::read_dword(HKEY_LOCAL_MACHINE, CURRENT_VERSION_KEY, "UBR") {
+        Some(revision) => format!("{}.{}", build, revision),
+        None => build,
+    };
+
+    (os_name, os_build)
+}
+
+fn is_windows_11(os_build: &str) -> bool {
+    os_build
+        .split('.')
+        .next()
+        .and_then(|build| build.parse::<u32>().ok())
+        .is_some_and(|build| build >= WINDOWS_11_BUILD)
+}
+
+fn get_total_memory() -> u64 {
+    let mut status = MEMORYSTATUSEX {
+        dwLength: std::mem::size_of::<MEMORYSTATUSEX>() as u32,
+        ..Default::default()
+    };
+
+    unsafe { GlobalMemoryStatusEx(&mut status) }
+        .map(|_| status.ullTotalPhys)
+        .unwrap_or(0)
+}
+
+fn get_gpu_name() -> Option<String> {
+    let mut fallback = None;
+
+    for index in 0.. {
+        let mut device = DISPLAY_DEVICEW {
+            cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+            ..Default::default()
+        };
+
+        if !unsafe { EnumDisplayDevicesW(PCWSTR::null(), index, &mut device, 0) }.as_bool() {
+            break;
+        }
+
+        let name = utf16_to_string(&device.DeviceString);
+        if device.StateFlags.0 & DISPLAY_DEVICE_PRIMARY_DEVICE.0 != 0 {
+            return Some(name);
+        }
+        fallback.get_or_insert(name);
+    }
+
+    fallback
+}
+
+fn get_enabled_privileges() -> Result<Vec<String>, String> {
+    unsafe {
+        let mut token = HANDLE::default();
+        OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token)
+            .map_err(|e| format!("Failed to open process token: {:?}", e))?;
+
+        // the first call only reports the required size
+        let mut size = 0u32;
+        let _ = GetTokenInformation(token, TokenPrivileges, None, 0, &mut size);
+
+        let mut buffer: Vec<u64> = vec![0; (size as usize).div_ceil(8)];
+        let result = GetTokenInformation(
+            token,
+            TokenPrivileges,
+            Some(buffer.as_mut_ptr() as *mut _),
+            size,
+            &mut size,
+        );
+
+        let _ = CloseHandle(token);
+        result.map_err(|e| format!("Failed to get token privileges: {:?}", e))?;
+
+        let privileges = &*(buffer.as_ptr() as *const TOKEN_PRIVILEGES);
+        let entries = std::slice::from_raw_parts(
+            privileges.Privileges.as_ptr(),
+            privileges.PrivilegeCount as usize,
+        );
+
+        let mut names = Vec::new();
+        for entry in entries
+            .iter()
+            .filter(|e| e.Attributes.0 & SE_PRIVILEGE_ENABLED.0 != 0)
+        {
+            let mut name = [0u16; 64];
+            let mut length = name.len() as u32;
+
+            if LookupPrivilegeNameW(
+                PCWSTR::null(),
+                &entry.Luid,
+                Some(PWSTR(name.as_mut_ptr())),
+                &mut length,
+            )
+            .is_ok()
+            {
+                names.push(String::from_utf16_lossy(&name[..length as usize]));
+            }
+        }
+
+        Ok(names)
+    }
+}
+
+/// Collect OS, hardware and process token information for diagnostics
+pub fn get_system_info() -> SystemInfo {
+    let (os_name, os_build) = get_os_version();
+
+    let cpu_model = registry::read_string(
+        HKEY_LOCAL_MACHINE,
+        CENTRAL_PROCESSOR_KEY,
+        "ProcessorNameString",
+    )
+    .map(|name| name.trim().to_string())
+    .unwrap_or_else(|| "unknown".to_string());
+
+    let physical_cores = num_cpus::get_physical() as u32;
+    let efficiency_cores = get_cpu_topology()
+        .inspect_err(|e| tracing::warn!("{}", e))
+        .map(|topology| topology.efficiency_core_count())
+        .unwrap_or(0);
+
+    SystemInfo {
+        is_windows_11: is_windows_11(&os_build),
+        os_name,
+        os_build,
+        cpu_model,
+        physical_cores,
+        logical_processors: num_cpus::get() as u32,
+        performance_cores: physical_cores.saturating_sub(efficiency_cores),
+        efficiency_cores,
+        processor_groups: unsafe { GetActiveProcessorGroupCount() } as u32,
+        total_memory: get_total_memory(),
+        gpu_name: get_gpu_name(),
+        is_elevated: is_running_as_admin().unwrap_or(false),
+        enabled_privileges: get_enabled_privileges()
+            .inspect_err(|e| tracing::warn!("{}", e))
+            .unwrap_or_default(),
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    config,
+    windows::process_matcher::{ProcessMatcher, ProcessNamePattern},
+};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// Target list of earlier versions, the list is part of `config.toml` now
+pub const LEGACY_TARGET_CONFIG_FILE: &str = "targets.json";
+
+#[derive(Debug, Deserialize)]
+struct LegacyTargetConfig {
+    patterns: Vec<ProcessNamePattern>,
+}
+
+/// Move the target list of a legacy `targets.json` into the settings and delete the
+/// legacy file, returns whether there was anything to migrate
+pub fn migrate(legacy_path: &Path, settings_path: &Path) -> Result<bool, String> {
+    if !legacy_path.exists() {
+        return Ok(false);
+    }
+
+    let content = std::fs::read_to_string(legacy_path)
+        .map_err(|e| format!("Failed to read {}: {}", legacy_path.display(), e))?;
+    let legacy: LegacyTargetConfig = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse {}: {}", legacy_path.display(), e))?;
+
+    // reject the file as a whole instead of silently dropping entries
+    ProcessMatcher::new(legacy.patterns.clone())?;
+
+    // the legacy file was written last by both setters, so its list wins
+    config::update_at(settings_path, |settings| {
+        settings.target_patterns = legacy.patterns;
+        Ok(())
+    })?;
+
+    std::fs::remove_file(legacy_path)
+        .map_err(|e| format!("Failed to remove {}: {}", legacy_path.display(), e))?;
+
+    Ok(true)
+}
+
+fn legacy_config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(LEGACY_TARGET_CONFIG_FILE))
+        .map_err(|e| format!("Failed to resolve app config directory: {}", e))
+}
+
+/// Migrate the legacy target list at the default locations, run before the settings are read
+pub fn migrate_legacy_config(app: &AppHandle) {
+    let result = legacy_config_path(app)
+        .and_then(|legacy_path| migrate(&legacy_path, &config::config_path()?));
+
+    match result {
+        Ok(true) => tracing::info!(
+            "Moved the target list from {} into the config",
+            LEGACY_TARGET_CONFIG_FILE
+        ),
+        Ok(false) => {}
+        Err(e) => tracing::warn!("Failed to migrate the legacy target list: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Settings;
+
+    #[test]
+    fn test_migrate_moves_patterns_into_settings() {
+        let dir = config::tests::temp_config_dir("targets");
+        let legacy_path = dir.join(LEGACY_TARGET_CONFIG_FILE);
+        let settings_path = dir.join(config::CONFIG_FILE);
+
+        assert!(!migrate(&legacy_path, &settings_path).unwrap());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&legacy_path, r#"{"patterns":[{"Exact":"csrss.exe"}]}"#).unwrap();
+        assert!(migrate(&legacy_path, &settings_path).is_err());
+        assert!(legacy_path.exists());
+
+        std::fs::write(&legacy_path, r#"{"patterns":[{"Glob":"SGuard*"}]}"#).unwrap();
+        assert!(migrate(&legacy_path, &settings_path).unwrap());
+        assert!(!legacy_path.exists());
+        assert_eq!(
+            Settings::load(&settings_path).unwrap().target_patterns,
+            vec![ProcessNamePattern::Glob("SGuard*".to_string())]
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::Win32::{
+    Foundation::CloseHandle,
+    System::{
+        Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+        },
+        Threading::{
+            GetThreadPriority, OpenThread, SetThreadPriority, THREAD_PRIORITY,
+            THREAD_PRIORITY_IDLE, THREAD_QUERY_LIMITED_INFORMATION, THREAD_SET_LIMITED_INFORMATION,
+        },
+    },
+};
+
+/// Returned by `GetThreadPriority` on failure
+const THREAD_PRIORITY_ERROR_RETURN: i32 = 0x7FFF_FFFF;
+
+/// Priority of a single thread before it was lowered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct ThreadPriority {
+    pub thread_id: u32,
+    pub priority: i32,
+}
+
+/// Ids of all threads owned by a process
+pub fn list_threads(process_id: u32) -> Result<Vec<u32>, String> {
+    let mut threads = Vec::new();
+
+    unsafe {
+        // the snapshot always contains the threads of every process
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0)
+            .map_err(|e| format!("Failed to create thread snapshot: {:?}", e))?;
+
+        let mut thread_entry = THREADENTRY32 {
+            dwSize: std::mem::size_of::<THREADENTRY32>() as u32,
+            ..Default::default()
+        };
+
+        if Thread32First(snapshot, &mut thread_entry).is_ok() {
+            loop {
+                if thread_entry.th32OwnerProcessID == process_id {
+                    threads.push(thread_entry.th32ThreadID);
+                }
+
+                if Thread32Next(snapshot, &mut thread_entry).is_err() {
+                    break;
+                }
+            }
+        }
+
+        let _ = CloseHandle(snapshot);
+    }
+
+    Ok(threads)
+}
+
+/// Set every thread of a process to idle priority, threads that refuse are skipped.
+/// Returns the previous priority of each adjusted thread
+pub fn lower_thread_priorities(process_id: u32) -> Result<Vec<ThreadPriority>, String> {
+    let mut adjusted = Vec::new();
+
+    for thread_id in list_threads(process_id)? {
+        unsafe {
+            let Ok(handle) = OpenThread(
+                THREAD_QUERY_LIMITED_INFORMATION | THREAD_SET_LIMITED_INFORMATION,
+                false,
+                thread_id,
+            ) else {
+                tracing::debug!("Failed to open thread {}", thread_id);
+                continue;
+            };
+
+            let priority = GetThreadPriority(handle);
+            if priority != THREAD_PRIORITY_ERROR_RETURN
+                && SetThreadPriority(handle, THREAD_PRIORITY_IDLE).is_ok()
+            {
+                adjusted.push(ThreadPriority {
+                    thread_id,
+                    priority,
+                });
+            }
+
+            let _ = CloseHandle(handle);
+        }
+    }
+
+    Ok(adjusted)
+}
+
+/// Put threads back to their recorded priority, threads that exited are ignored
+pub fn restore_thread_priorities(threads: &[ThreadPriority]) {
+    for thread in threads {
+        unsafe {
+            let Ok(handle) = OpenThread(THREAD_SET_LIMITED_INFORMATION, false, thread.thread_id)
+            else {
+                continue;
+            };
+
+            if let Err(e) = SetThreadPriority(handle, THREAD_PRIORITY(thread.priority)) {
+                tracing::debug!(
+                    "Failed to restore priority of thread {}: {:?}",
+                    thread.thread_id,
+                    e
+                );
+            }
+
+            let _ = CloseHandle(handle);
+        }
+    }
+}
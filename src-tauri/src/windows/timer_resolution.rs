@@ -0,0 +1,173 @@
+use crate::{
+    consts,
+    windows::{process_list::query_processes, AceProcessControllerState},
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{sync::Mutex, time::Duration};
+use tauri::{AppHandle, Manager};
+use windows::Win32::{
+    Foundation::NTSTATUS,
+    Media::{timeBeginPeriod, timeEndPeriod, TIMERR_NOERROR},
+};
+
+/// Timer resolution requested while a game is running
+pub const GAMING_TIMER_RESOLUTION_MS: u32 = 1;
+
+const GAME_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtQueryTimerResolution(
+        maximum_resolution: *mut u32,
+        minimum_resolution: *mut u32,
+        current_resolution: *mut u32,
+    ) -> NTSTATUS;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TimerResolutionStatus {
+    pub enabled: bool,
+    pub active: bool,
+    pub resolution_ms: u32,
+    pub game_running: bool,
+    /// At least one ACE process is currently optimized
+    pub optimizer_active: bool,
+    /// Resolution the system timer currently runs at, in 100ns units
+    pub current_resolution_100ns: Option<u32>,
+}
+
+/// Requests a high system timer resolution while a game is detected
+/// and the optimizer is active, opt-in only
+#[derive(Debug, Default)]
+pub struct TimerResolutionController {
+    enabled: bool,
+    active: bool,
+    game_running: bool,
+    optimizer_active: bool,
+}
+
+impl TimerResolutionController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.release();
+        }
+    }
+
+    /// Request or release the timer resolution depending on whether a game is running
+    /// while ACE is being optimized
+    pub fn update(&mut self, game_running: bool, optimizer_active: bool) {
+        self.game_running = game_running;
+        self.optimizer_active = optimizer_active;
+
+        if self.enabled && game_running && optimizer_active {
+            self.request();
+        } else {
+            self.release();
+        }
+    }
+
+    pub fn status(&self) -> TimerResolutionStatus {
+        TimerResolutionStatus {
+            enabled: self.enabled,
+            active: self.active,
+            resolution_ms: GAMING_TIMER_RESOLUTION_MS,
+            game_running: self.game_running,
+            optimizer_active: self.optimizer_active,
+            current_resolution_100ns: query_current_resolution(),
+        }
+    }
+
+    fn request(&mut self) {
+        if self.active {
+            return;
+        }
+
+        let result = unsafe { timeBeginPeriod(GAMING_TIMER_RESOLUTION_MS) };
+        if result == TIMERR_NOERROR {
+            self.active = true;
+            tracing::info!(
+                "Requested {}ms timer resolution",
+                GAMING_TIMER_RESOLUTION_MS
+            );
+        } else {
+            tracing::warn!("Failed to request timer resolution: {}", result);
+        }
+    }
+
+    fn release(&mut self) {
+        if !self.active {
+            return;
+        }
+
+        unsafe {
+            timeEndPeriod(GAMING_TIMER_RESOLUTION_MS);
+        }
+        self.active = false;
+        tracing::info!("Released {}ms timer resolution", GAMING_TIMER_RESOLUTION_MS);
+    }
+}
+
+impl Drop for TimerResolutionController {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+/// Query the resolution the system timer currently runs at
+fn query_current_resolution() -> Option<u32> {
+    let (mut maximum, mut minimum, mut current) = (0u32, 0u32, 0u32);
+    let status = unsafe { NtQueryTimerResolution(&mut maximum, &mut minimum, &mut current) };
+
+    // negative values are error codes
+    (status.0 >= 0).then_some(current)
+}
+
+/// Whether any known game is running
+fn is_game_running() -> bool {
+    query_processes()
+        .map(|processes| {
+            processes.iter().any(|p| {
+                consts::KNOWN_GAMES
+                    .iter()
+                    .any(|(_, executable)| executable.eq_ignore_ascii_case(&p.process_name))
+            })
+        })
+        .unwrap_or(false)
+}
+
+// State wrapper for TimerResolutionController
+#[derive(Default)]
+pub struct TimerResolutionState(pub Mutex<TimerResolutionController>);
+
+/// Spawn a background thread that keeps the timer resolution in sync with the game process
+pub fn spawn_game_monitor(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(GAME_POLL_INTERVAL);
+
+        let enabled = match app.state::<TimerResolutionState>().0.lock() {
+            Ok(controller) => controller.enabled,
+            Err(_) => continue,
+        };
+        if !enabled {
+            continue;
+        }
+
+        let game_running = is_game_running();
+        let optimizer_active = match app.state::<AceProcessControllerState>().0.lock() {
+            Ok(controller) => controller.get_processes().iter().any(|p| p.is_optimized),
+            Err(_) => false,
+        };
+
+        let state = app.state::<TimerResolutionState>();
+        let Ok(mut controller) = state.0.lock() else {
+            continue;
+        };
+        controller.update(game_running, optimizer_active);
+    });
+}
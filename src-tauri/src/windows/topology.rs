@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use windows::Win32::System::SystemInformation::{
+    GetLogicalProcessorInformationEx, RelationAll, RelationCache, RelationProcessorCore,
+    SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PhysicalCore {
+    /// Logical processor indexes in processor group 0
+    pub logical_processors: Vec<u32>,
+    /// Higher values are more performant, all cores share class 0 on non-hybrid CPUs
+    pub efficiency_class: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CacheGroup {
+    pub level: u8,
+    /// Cache size in bytes
+    pub size: u32,
+    pub logical_processors: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CpuTopology {
+    pub logical_processor_count: u32,
+    pub cores: Vec<PhysicalCore>,
+    pub l3_caches: Vec<CacheGroup>,
+    /// True when the CPU mixes performance and efficiency cores
+    pub is_hybrid: bool,
+}
+
+impl CpuTopology {
+    /// Logical processors of the least performant core class, empty on non-hybrid CPUs
+    pub fn efficiency_processors(&self) -> Vec<u32> {
+        self.efficiency_cores()
+            .flat_map(|c| c.logical_processors.iter().copied())
+            .collect()
+    }
+
+    /// Number of cores in the least performant class, 0 on non-hybrid CPUs
+    pub fn efficiency_core_count(&self) -> u32 {
+        self.efficiency_cores().count() as u32
+    }
+
+    fn efficiency_cores(&self) -> impl Iterator<Item = &PhysicalCore> {
+        let min_class = self
+            .cores
+            .iter()
+            .map(|c| c.efficiency_class)
+            .min()
+            .unwrap_or(0);
+
+        self.cores
+            .iter()
+            .filter(move |c| self.is_hybrid && c.efficiency_class == min_class)
+    }
+}
+
+/// Logical processor indexes set in an affinity mask
+pub fn mask_to_processors(mask: usize) -> Vec<u32> {
+    (0..usize::BITS).filter(|i| mask & (1 << i) != 0).collect()
+}
+
+/// Detect the CPU topology of processor group 0
+pub fn get_cpu_topology() -> Result<CpuTopology, String> {
+    let mut length = 0u32;
+
+    unsafe {
+        // the first call only reports the required buffer size
+        let _ = GetLogicalProcessorInformationEx(RelationAll, None, &mut length);
+        if length == 0 {
+            return Err("Failed to query processor information size".to_string());
+        }
+
+        let mut buffer = vec![0u8; length as usize];
+        GetLogicalProcessorInformationEx(
+            RelationAll,
+            Some(buffer.as_mut_ptr() as *mut SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX),
+            &mut length,
+        )
+        .map_err(|e| format!("Failed to query processor information: {:?}", e))?;
+
+        let mut cores = Vec::new();
+        let mut l3_caches = Vec::new();
+        let mut offset = 0usize;
+
+        while offset < length as usize {
+            let info =
+                &*(buffer.as_ptr().add(offset) as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX);
+
+            if info.Relationship == RelationProcessorCore {
+                let processor = &info.Anonymous.Processor;
+                let group_mask = processor.GroupMask[0];
+                if group_mask.Group == 0 {
+                    cores.push(PhysicalCore {
+                        logical_processors: mask_to_processors(group_mask.Mask),
+                        efficiency_class: processor.EfficiencyClass,
+                    });
+                }
+            } else if info.Relationship == RelationCache {
+                let cache = &info.Anonymous.Cache;
+                let group_mask = cache.Anonymous.GroupMask;
+                if cache.Level == 3 && group_mask.Group == 0 {
+                    l3_caches.push(CacheGroup {
+                        level: cache.Level,
+                        size: cache.CacheSize,
+                        logical_processors: mask_to_processors(group_mask.Mask),
+                    });
+                }
+            }
+
+            if info.Size == 0 {
+                break;
+            }
+            offset += info.Size as usize;
+        }
+
+        let logical_processor_count = cores
+            .iter()
+            .map(|c| c.logical_processors.len() as u32)
+            .sum();
+        let is_hybrid = cores
+            .iter()
+            .any(|c| c.efficiency_class != cores[0].efficiency_class);
+
+        tracing::debug!(
+            "CPU topology: {} cores, {} logical processors, {} L3 caches, hybrid: {}",
+            cores.len(),
+            logical_processor_count,
+            l3_caches.len(),
+            is_hybrid
+        );
+
+        Ok(CpuTopology {
+            logical_processor_count,
+            cores,
+            l3_caches,
+            is_hybrid,
+        })
+    }
+}
@@ -0,0 +1,243 @@
+use crate::windows::threads::list_threads;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{collections::HashMap, sync::Mutex, time::Instant};
+use windows::Win32::{
+    Foundation::{CloseHandle, FILETIME},
+    System::{
+        ProcessStatus::{
+            K32EmptyWorkingSet, K32GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS,
+            PROCESS_MEMORY_COUNTERS_EX,
+        },
+        Threading::{
+            GetProcessHandleCount, GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+            PROCESS_SET_QUOTA,
+        },
+    },
+};
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, Type)]
+pub struct ProcessMemoryInfo {
+    /// Working set size in bytes
+    pub working_set: u64,
+    /// Private bytes (commit charge) in bytes
+    pub private_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type)]
+pub struct ProcessUsage {
+    pub process_id: u32,
+    /// Percent of all logical cores since the previous query of this process,
+    /// `None` on the first one
+    pub cpu_usage: Option<f32>,
+    pub working_set: u64,
+    pub private_bytes: u64,
+    pub handle_count: u32,
+    pub thread_count: u32,
+}
+
+fn filetime_to_u64(filetime: &FILETIME) -> u64 {
+    ((filetime.dwHighDateTime as u64) << 32) | filetime.dwLowDateTime as u64
+}
+
+/// Get the total kernel + user CPU time of a process in 100ns units
+pub fn get_process_cpu_time(process_id: u32) -> Result<u64, String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)
+            .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let mut creation_time = FILETIME::default();
+        let mut exit_time = FILETIME::default();
+        let mut kernel_time = FILETIME::default();
+        let mut user_time = FILETIME::default();
+
+        let result = GetProcessTimes(
+            handle,
+            &mut creation_time,
+            &mut exit_time,
+            &mut kernel_time,
+            &mut user_time,
+        );
+
+        let _ = CloseHandle(handle);
+
+        result.map_err(|e| format!("Failed to get process times for {}: {:?}", process_id, e))?;
+
+        Ok(filetime_to_u64(&kernel_time) + filetime_to_u64(&user_time))
+    }
+}
+
+/// Get the creation time of a process as a FILETIME value, used to tell reused pids apart
+pub fn get_process_start_time(process_id: u32) -> Result<u64, String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)
+            .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let mut creation_time = FILETIME::default();
+        let mut exit_time = FILETIME::default();
+        let mut kernel_time = FILETIME::default();
+        let mut user_time = FILETIME::default();
+
+        let result = GetProcessTimes(
+            handle,
+            &mut creation_time,
+            &mut exit_time,
+            &mut kernel_time,
+            &mut user_time,
+        );
+
+        let _ = CloseHandle(handle);
+
+        result.map_err(|e| format!("Failed to get process times for {}: {:?}", process_id, e))?;
+
+        Ok(filetime_to_u64(&creation_time))
+    }
+}
+
+/// Get the working set and private bytes of a process
+pub fn get_process_memory(process_id: u32) -> Result<ProcessMemoryInfo, String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)
+            .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let mut counters = PROCESS_MEMORY_COUNTERS_EX {
+            cb: std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32,
+            ..Default::default()
+        };
+
+        let result = K32GetProcessMemoryInfo(
+            handle,
+            &mut counters as *mut PROCESS_MEMORY_COUNTERS_EX as *mut PROCESS_MEMORY_COUNTERS,
+            counters.cb,
+        );
+
+        let _ = CloseHandle(handle);
+
+        if !result.as_bool() {
+            return Err(format!(
+                "Failed to get memory info for process {}",
+                process_id
+            ));
+        }
+
+        Ok(ProcessMemoryInfo {
+            working_set: counters.WorkingSetSize as u64,
+            private_bytes: counters.PrivateUsage as u64,
+        })
+    }
+}
+
+/// Get the number of open handles of a process
+pub fn get_process_handle_count(process_id: u32) -> Result<u32, String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)
+            .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let mut count = 0u32;
+        let result = GetProcessHandleCount(handle, &mut count);
+
+        let _ = CloseHandle(handle);
+
+        result.map_err(|e| {
+            format!(
+                "Failed to get handle count for process {}: {:?}",
+                process_id, e
+            )
+        })?;
+
+        Ok(count)
+    }
+}
+
+/// Sample CPU, memory, handle and thread usage of a single process
+pub fn get_process_usage(
+    process_id: u32,
+    sampler: &mut CpuUsageSampler,
+) -> Result<ProcessUsage, String> {
+    let memory = get_process_memory(process_id)?;
+
+    Ok(ProcessUsage {
+        process_id,
+        cpu_usage: sampler.sample(process_id),
+        working_set: memory.working_set,
+        private_bytes: memory.private_bytes,
+        handle_count: get_process_handle_count(process_id)?,
+        thread_count: list_threads(process_id)?.len() as u32,
+    })
+}
+
+/// Remove as many pages as possible from the working set of a process,
+/// they are paged back in on demand
+pub fn trim_working_set(process_id: u32) -> Result<(), String> {
+    unsafe {
+        let handle = OpenProcess(
+            PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SET_QUOTA,
+            false,
+            process_id,
+        )
+        .map_err(|e| format!("Failed to open process {}: {:?}", process_id, e))?;
+
+        let result = K32EmptyWorkingSet(handle);
+
+        let _ = CloseHandle(handle);
+
+        if !result.as_bool() {
+            return Err(format!(
+                "Failed to trim working set of process {}",
+                process_id
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes CPU usage from the difference between two consecutive samples of a process
+#[derive(Debug, Clone, Default)]
+pub struct CpuUsageSampler {
+    last_samples: HashMap<u32, (u64, Instant)>,
+}
+
+impl CpuUsageSampler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sample a process and return its CPU usage in percent of all logical cores,
+    /// `None` on the first sample or when the process cannot be queried
+    pub fn sample(&mut self, process_id: u32) -> Option<f32> {
+        let cpu_time = match get_process_cpu_time(process_id) {
+            Ok(cpu_time) => cpu_time,
+            Err(e) => {
+                tracing::trace!("{}", e);
+                self.last_samples.remove(&process_id);
+                return None;
+            }
+        };
+
+        let now = Instant::now();
+        let previous = self.last_samples.insert(process_id, (cpu_time, now));
+
+        let (previous_cpu_time, previous_instant) = previous?;
+        let elapsed = now.duration_since(previous_instant).as_secs_f64();
+        if elapsed <= 0.0 || cpu_time < previous_cpu_time {
+            return None;
+        }
+
+        // cpu time is in 100ns units
+        let cpu_seconds = (cpu_time - previous_cpu_time) as f64 / 10_000_000.0;
+        let usage = cpu_seconds / elapsed / num_cpus::get() as f64 * 100.0;
+
+        Some(usage.clamp(0.0, 100.0) as f32)
+    }
+
+    /// Drop samples of processes that are no longer tracked
+    pub fn retain(&mut self, process_ids: &[u32]) {
+        self.last_samples
+            .retain(|process_id, _| process_ids.contains(process_id));
+    }
+}
+
+// State wrapper for the sampler behind on-demand usage queries
+#[derive(Default)]
+pub struct ProcessUsageSamplerState(pub Mutex<CpuUsageSampler>);
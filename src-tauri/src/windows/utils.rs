@@ -140,6 +140,19 @@ pub fn enable_single_privilege(privilege_name: &PCWSTR) -> Result<()> {
     }
 }
 
+/// Display name of a priority class value
+pub fn priority_class_name(priority: u32) -> &'static str {
+    match priority {
+        0x40 => "IDLE",
+        0x4000 => "BELOW_NORMAL",
+        0x20 => "NORMAL",
+        0x8000 => "ABOVE_NORMAL",
+        0x80 => "HIGH",
+        0x100 => "REALTIME",
+        _ => "UNKNOWN",
+    }
+}
+
 /// Get current process priority class
 pub fn get_process_priority(process_id: u32) -> Result<String> {
     unsafe {
@@ -151,16 +164,7 @@ pub fn get_process_priority(process_id: u32) -> Result<String> {
                 CloseHandle(handle).ok();
 
                 if priority != 0 {
-                    let priority_class = match priority {
-                        0x40 => "IDLE",
-                        0x4000 => "BELOW_NORMAL", 
-                        0x20 => "NORMAL",
-                        0x8000 => "ABOVE_NORMAL",
-                        0x80 => "HIGH",
-                        0x100 => "REALTIME",
-                        _ => "UNKNOWN",
-                    };
-                    return Ok(priority_class.to_string());
+                    return Ok(priority_class_name(priority).to_string());
                 }
             }
         }
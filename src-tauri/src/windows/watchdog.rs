@@ -0,0 +1,243 @@
+use crate::{
+    history::{self, HistoryKind},
+    notifications::{notify, NotificationKind},
+    windows::{background_pause, AceProcessControllerState},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+    time::Duration,
+};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+const MIN_POLL_INTERVAL_SECS: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct WatchdogConfig {
+    pub enabled: bool,
+    pub poll_interval_secs: u32,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_interval_secs: 3,
+        }
+    }
+}
+
+/// Why the watchdog stepped in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum WatchdogAction {
+    /// A new ACE process showed up, e.g. after ACE restarted, and was optimized
+    ProcessStarted,
+    /// ACE restored its own priority or affinity and the settings were re-applied
+    SettingsReapplied,
+}
+
+// Emitted every time the watchdog optimizes a newly started ACE process
+// or re-applies the settings ACE reset on its own
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct WatchdogEvent {
+    pub action: WatchdogAction,
+    pub process_id: u32,
+    pub process_name: String,
+    pub success: bool,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Remembers which processes the watchdog already handled
+#[derive(Debug, Default)]
+pub struct Watchdog {
+    config: WatchdogConfig,
+    handled: HashSet<u32>,
+    /// Last seen reset count of every handled process
+    reset_counts: HashMap<u32, u32>,
+}
+
+impl Watchdog {
+    pub fn config(&self) -> &WatchdogConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, mut config: WatchdogConfig) {
+        config.poll_interval_secs = config.poll_interval_secs.max(MIN_POLL_INTERVAL_SECS);
+        if !config.enabled {
+            self.handled.clear();
+            self.reset_counts.clear();
+        }
+        self.config = config;
+    }
+
+    pub fn toggle(&mut self) -> bool {
+        let mut config = self.config.clone();
+        config.enabled = !config.enabled;
+        self.set_config(config);
+        self.config.enabled
+    }
+
+    /// Return the processes seen for the first time and forget the ones that exited.
+    /// Each process is only handled once, so a manual restore is not undone
+    pub fn take_new_processes(&mut self, process_ids: &[u32]) -> Vec<u32> {
+        self.handled
+            .retain(|process_id| process_ids.contains(process_id));
+
+        process_ids
+            .iter()
+            .copied()
+            .filter(|process_id| self.handled.insert(*process_id))
+            .collect()
+    }
+
+    /// Return the processes whose reset count grew since the last call, i.e. the ones
+    /// where ACE restored its own settings. A manual restore does not count as a reset
+    pub fn take_reset_processes(&mut self, reset_counts: &[(u32, u32)]) -> Vec<u32> {
+        self.reset_counts
+            .retain(|process_id, _| reset_counts.iter().any(|(id, _)| id == process_id));
+
+        reset_counts
+            .iter()
+            .filter(|(process_id, count)| {
+                let previous = self.reset_counts.insert(*process_id, *count).unwrap_or(0);
+                *count > previous
+            })
+            .map(|(process_id, _)| *process_id)
+            .collect()
+    }
+}
+
+// State wrapper for the ACE restart watchdog
+#[derive(Default)]
+pub struct WatchdogState(pub Mutex<Watchdog>);
+
+/// Spawn a background thread that optimizes ACE processes as soon as they (re)start
+pub fn spawn_watchdog(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        let config = match app.state::<WatchdogState>().0.lock() {
+            Ok(watchdog) => watchdog.config().clone(),
+            Err(_) => WatchdogConfig::default(),
+        };
+
+        std::thread::sleep(Duration::from_secs(config.poll_interval_secs as u64));
+
+        if !config.enabled || background_pause::is_paused(&app) {
+            continue;
+        }
+
+        let controller_state = app.state::<AceProcessControllerState>();
+        let Ok(mut controller) = controller_state.0.lock() else {
+            continue;
+        };
+
+        let _ = controller.scan_ace_guard_processes();
+        let processes: Vec<_> = controller
+            .get_processes()
+            .iter()
+            .filter(|p| controller.is_instance_enabled(p))
+            .cloned()
+            .collect();
+        let process_ids: Vec<u32> = processes.iter().map(|p| p.process_id).collect();
+
+        let reset_counts: Vec<(u32, u32)> = processes
+            .iter()
+            .map(|p| (p.process_id, p.reset_count))
+            .collect();
+
+        let (new_processes, reset_processes) = match app.state::<WatchdogState>().0.lock() {
+            Ok(mut watchdog) => (
+                watchdog.take_new_processes(&process_ids),
+                watchdog.take_reset_processes(&reset_counts),
+            ),
+            Err(_) => continue,
+        };
+
+        for process in processes.iter().filter(|p| {
+            (new_processes.contains(&p.process_id) || reset_processes.contains(&p.process_id))
+                && !p.is_optimized
+        }) {
+            let action = if reset_processes.contains(&process.process_id) {
+                tracing::info!(
+                    "Watchdog: ACE reset the settings of {} (PID: {})",
+                    process.process_name,
+                    process.process_id
+                );
+                WatchdogAction::SettingsReapplied
+            } else {
+                tracing::info!(
+                    "Watchdog: ACE process {} (PID: {}) started",
+                    process.process_name,
+                    process.process_id
+                );
+                WatchdogAction::ProcessStarted
+            };
+            let result = controller.optimize_single_process(process.process_id);
+
+            let (success, message) = match result {
+                Ok(message) => {
+                    tracing::info!("Watchdog: {}", message);
+                    let kind = match action {
+                        WatchdogAction::SettingsReapplied => NotificationKind::SettingsReverted,
+                        WatchdogAction::ProcessStarted => NotificationKind::OptimizationApplied,
+                    };
+                    notify(&app, kind, &message);
+                    (true, message)
+                }
+                Err(e) => {
+                    tracing::warn!("Watchdog: {}", e);
+                    notify(&app, NotificationKind::OptimizationFailed, &e);
+                    (false, e)
+                }
+            };
+
+            history::record(HistoryKind::Optimization, success, &message, None);
+
+            let _ = WatchdogEvent {
+                action,
+                process_id: process.process_id,
+                process_name: process.process_name.clone(),
+                success,
+                message,
+                timestamp: Utc::now(),
+            }
+            .emit(&app);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processes_are_handled_once() {
+        let mut watchdog = Watchdog::default();
+
+        assert_eq!(watchdog.take_new_processes(&[1, 2]), vec![1, 2]);
+        assert!(watchdog.take_new_processes(&[1, 2]).is_empty());
+
+        // a restarted ACE process shows up with a new pid
+        assert_eq!(watchdog.take_new_processes(&[2, 3]), vec![3]);
+        // a reused pid counts as new once the old process was gone
+        assert_eq!(watchdog.take_new_processes(&[1, 2, 3]), vec![1]);
+    }
+
+    #[test]
+    fn test_reset_processes_are_reported_when_the_count_grows() {
+        let mut watchdog = Watchdog::default();
+
+        assert!(watchdog.take_reset_processes(&[(1, 0), (2, 0)]).is_empty());
+        assert_eq!(watchdog.take_reset_processes(&[(1, 1), (2, 0)]), vec![1]);
+        assert!(watchdog.take_reset_processes(&[(1, 1), (2, 0)]).is_empty());
+
+        // an exited process starts over when its pid is reused
+        assert!(watchdog.take_reset_processes(&[(2, 0)]).is_empty());
+        assert_eq!(watchdog.take_reset_processes(&[(1, 1)]), vec![1]);
+    }
+}
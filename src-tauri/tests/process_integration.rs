@@ -0,0 +1,138 @@
+//! End-to-end checks of the process controller against harmless dummy processes.
+//!
+//! A copy of `PING.EXE` is placed below an `AntiCheatExpert` folder so the safety
+//! allowlist accepts it, the scanner is pointed at the copy through a target pattern.
+#![cfg(target_os = "windows")]
+
+use std::{
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    time::Duration,
+};
+use tencent_ace_tools_lib::windows::{
+    ace_tools::AceProcessController, process_matcher::ProcessNamePattern, utils::get_process_status,
+};
+
+const DUMMY_PROCESS_NAME: &str = "AceToolsDummy.exe";
+const DUMMY_PROCESS_COUNT: usize = 3;
+
+/// Dummy child processes, killed and removed from disk on drop
+struct DummyProcesses {
+    directory: PathBuf,
+    children: Vec<Child>,
+}
+
+impl DummyProcesses {
+    /// `name` keeps the copies of tests running in parallel apart
+    fn spawn(name: &str, count: usize) -> Self {
+        let directory = std::env::temp_dir()
+            .join(format!("ace-tools-test-{}-{}", std::process::id(), name))
+            .join("AntiCheatExpert");
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let system_root = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".into());
+        let executable = directory.join(DUMMY_PROCESS_NAME);
+        std::fs::copy(
+            PathBuf::from(system_root).join(r"System32\PING.EXE"),
+            &executable,
+        )
+        .unwrap();
+
+        let children = (0..count)
+            .map(|_| {
+                Command::new(&executable)
+                    .args(["-n", "120", "127.0.0.1"])
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .spawn()
+                    .unwrap()
+            })
+            .collect();
+
+        // give the processes a moment to show up in the process list
+        std::thread::sleep(Duration::from_millis(500));
+
+        Self {
+            directory,
+            children,
+        }
+    }
+
+    fn process_ids(&self) -> Vec<u32> {
+        self.children.iter().map(|c| c.id()).collect()
+    }
+}
+
+impl Drop for DummyProcesses {
+    fn drop(&mut self) {
+        for child in &mut self.children {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+
+        if let Some(parent) = self.directory.parent() {
+            let _ = std::fs::remove_dir_all(parent);
+        }
+    }
+}
+
+fn controller_for_dummies() -> AceProcessController {
+    let mut controller = AceProcessController::new();
+    controller
+        .set_target_patterns(vec![ProcessNamePattern::Exact(
+            DUMMY_PROCESS_NAME.to_string(),
+        )])
+        .unwrap();
+    controller
+}
+
+fn assert_status(process_ids: &[u32], priority: &str, affinity: &str) {
+    for &process_id in process_ids {
+        let (current_priority, current_affinity) = get_process_status(process_id).unwrap();
+        assert_eq!(current_priority, priority, "priority of PID {}", process_id);
+        assert_eq!(current_affinity, affinity, "affinity of PID {}", process_id);
+    }
+}
+
+#[test]
+fn test_optimize_restore_and_reapply() {
+    let dummies = DummyProcesses::spawn("reapply", DUMMY_PROCESS_COUNT);
+    let process_ids = dummies.process_ids();
+    let (_, original_affinity) = get_process_status(process_ids[0]).unwrap();
+    let last_core = format!("Core {}", num_cpus::get() - 1);
+
+    let mut controller = controller_for_dummies();
+    let processes = controller.scan_ace_guard_processes().unwrap();
+    assert_eq!(processes.len(), DUMMY_PROCESS_COUNT);
+
+    // apply
+    tauri::async_runtime::block_on(controller.optimize_all_processes()).unwrap();
+    assert!(controller.get_processes().iter().all(|p| p.is_optimized));
+    assert_status(&process_ids, "IDLE", &last_core);
+
+    // restore
+    let restored = controller.reset_all_processes().unwrap();
+    assert_eq!(restored, DUMMY_PROCESS_COUNT);
+    assert_status(&process_ids, "NORMAL", &original_affinity);
+
+    // re-apply the way the watchdog does for a single process
+    for &process_id in &process_ids {
+        tauri::async_runtime::block_on(controller.optimize_single_process(process_id)).unwrap();
+    }
+    assert_status(&process_ids, "IDLE", &last_core);
+}
+
+#[test]
+fn test_untargeted_processes_are_ignored() {
+    let _dummies = DummyProcesses::spawn("untargeted", 1);
+
+    let mut controller = AceProcessController::new();
+    controller
+        .set_target_patterns(vec![ProcessNamePattern::Exact(
+            "AceToolsMissing.exe".to_string(),
+        )])
+        .unwrap();
+
+    assert!(controller.scan_ace_guard_processes().is_err());
+    assert!(controller.get_processes().is_empty());
+}
@@ -26,92 +26,1342 @@ export const commands = {
       else return { status: "error", error: e as any };
     }
   },
+  /**
+   * Relaunch the app elevated through the UAC prompt, handing over the current settings,
+   * returns false when the user declined the prompt
+   */
+  async restartAsAdmin(): Promise<Result<boolean, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("restart_as_admin") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
   async getAllAceGuardProcesses(): Promise<Result<ProcessInfo[], string>> {
     try {
       return {
         status: "ok",
-        data: await TAURI_INVOKE("get_all_ace_guard_processes"),
+        data: await TAURI_INVOKE("get_all_ace_guard_processes"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getAceProcessTree(): Promise<Result<AceProcessNode[], string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_ace_process_tree") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async detectAntiCheats(): Promise<Result<DetectedAntiCheat[], string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("detect_anti_cheats") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getOptimizedAntiCheats(): Promise<Result<AntiCheatFamily[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_optimized_anti_cheats"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setAntiCheatOptimized(
+    family: AntiCheatFamily,
+    enabled: boolean,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_anti_cheat_optimized", {
+          family,
+          enabled,
+        }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async optimizeAllAceGuardProcesses(): Promise<Result<string, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("optimize_all_ace_guard_processes"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getControllerPrivilegesStatus(): Promise<Result<boolean, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_controller_privileges_status"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getAllWindows(): Promise<Result<WindowInfo[], string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_all_windows") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Windows with a JPEG preview for the window picker, capturing them takes a moment
+   */
+  async getAllWindowsWithThumbnails(
+    maxWidth: number,
+  ): Promise<Result<WindowThumbnail[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_all_windows_with_thumbnails", {
+          maxWidth,
+        }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async tryCaptureImageByWindowId(
+    windowId: number,
+  ): Promise<Result<ScreenShot, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("try_capture_image_by_window_id", {
+          windowId,
+        }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture the window whose title is exactly `window_name`
+   */
+  async captureByWindowName(
+    windowName: string,
+  ): Promise<Result<ScreenShot, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("capture_by_window_name", { windowName }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture the first window whose title matches the regular expression, e.g. `Delta.*Force`
+   */
+  async captureByWindowPattern(
+    pattern: string,
+  ): Promise<Result<ScreenShot, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("capture_by_window_pattern", { pattern }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture a window with BitBlt, client area only or cropped, e.g. when the default
+   * capture of a DirectX window comes out black
+   */
+  async captureWindowAdvanced(
+    windowId: number,
+    options: CaptureOptions,
+  ): Promise<Result<ScreenShot, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("capture_window_advanced", {
+          windowId,
+          options,
+        }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture the whole desktop
+   */
+  async captureFullScreen(): Promise<Result<ScreenShot, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("capture_full_screen") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture and write the screenshot straight to disk, the extension of the path picks PNG,
+   * JPEG or WebP. Without a path a save dialog asks for one, `None` when it was cancelled
+   */
+  async saveScreenshotToFile(
+    source: ScreenshotSource,
+    path: string | null,
+  ): Promise<Result<string | null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("save_screenshot_to_file", { source, path }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async ocrScreenRegion(
+    region: OcrRegion,
+  ): Promise<Result<OcrResponse, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("ocr_screen_region", { region }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async ocrImageRegion(
+    imageData: number[],
+    region: OcrRegion,
+  ): Promise<Result<OcrResponse, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("ocr_image_region", { imageData, region }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture a window and run OCR on it in one call, without sending the image to the frontend
+   */
+  async ocrWindow(
+    windowId: number,
+    region: OcrRegion | null,
+    options: CaptureOptions | null,
+  ): Promise<Result<OcrResponse, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("ocr_window", { windowId, region, options }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async ocrFullScreen(): Promise<Result<OcrResponse, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("ocr_full_screen") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getScreenshotWindow(): Promise<Result<string | null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_screenshot_window"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Remember the window picked for screenshots, `None` captures the whole display
+   */
+  async setScreenshotWindow(
+    title: string | null,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_screenshot_window", { title }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture the window picked for screenshots
+   */
+  async captureScreenshotWindow(): Promise<Result<ScreenShot, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("capture_screenshot_window"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Open a full-screen overlay to drag a region, then capture it and optionally run OCR on it
+   */
+  async pickScreenRegion(
+    ocr: boolean,
+  ): Promise<Result<RegionCapture | null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("pick_screen_region", { ocr }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Called by the overlay with the dragged region, `None` cancels the selection
+   */
+  async submitScreenRegion(
+    region: OcrRegion | null,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("submit_screen_region", { region }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getHotkeyBindings(): Promise<Result<HotkeyBinding[], string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_hotkey_bindings") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setHotkeyBindings(
+    bindings: HotkeyBinding[],
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_hotkey_bindings", { bindings }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async showStatusHud(
+    position: HudPosition,
+    monitor: number | null,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("show_status_hud", { position, monitor }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async hideStatusHud(): Promise<Result<null, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("hide_status_hud") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getHudStatus(): Promise<Result<HudStatus, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_hud_status") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getStatusSummary(): Promise<Result<StatusSummary, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_status_summary") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getTimerResolutionStatus(): Promise<
+    Result<TimerResolutionStatus, string>
+  > {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_timer_resolution_status"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setTimerResolutionEnabled(
+    enabled: boolean,
+  ): Promise<Result<TimerResolutionStatus, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_timer_resolution_enabled", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getCpuAlertConfig(): Promise<Result<CpuAlertConfig, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_cpu_alert_config") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setCpuAlertConfig(
+    config: CpuAlertConfig,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_cpu_alert_config", { config }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getCpuAlertHistory(): Promise<Result<CpuAlertEvent[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_cpu_alert_history"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getAceGuardUsageHistory(
+    processId: number | null,
+  ): Promise<Result<ProcessUsageHistory[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_ace_guard_usage_history", { processId }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Usage of any process, e.g. the game. CPU usage is measured since the previous call
+   * for the same process, so poll this for live charts
+   */
+  async getProcessUsage(
+    processId: number,
+  ): Promise<Result<ProcessUsage, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_process_usage", { processId }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getAceInstanceSettings(): Promise<
+    Result<Partial<{ [key in string]: AceInstanceSettings }>, string>
+  > {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_ace_instance_settings"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setAceInstanceSettings(
+    game: string,
+    settings: AceInstanceSettings,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_ace_instance_settings", {
+          game,
+          settings,
+        }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getAffinityStrategy(): Promise<Result<AffinityStrategy, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_affinity_strategy"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setAffinityStrategy(
+    strategy: AffinityStrategy,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_affinity_strategy", { strategy }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getAffinityProcessors(): Promise<Result<number[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_affinity_processors"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Pin the processes to the logical processors picked in the core picker
+   */
+  async setAffinityProcessors(
+    processors: number[],
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_affinity_processors", { processors }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getNotificationsEnabled(): Promise<boolean> {
+    return await TAURI_INVOKE("get_notifications_enabled");
+  },
+  async setNotificationsEnabled(enabled: boolean): Promise<null> {
+    return await TAURI_INVOKE("set_notifications_enabled", { enabled });
+  },
+  async queryHistory(
+    query: HistoryQuery,
+  ): Promise<Result<HistoryEntry[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("query_history", { query }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getAverageCpuUsage(
+    since: string,
+  ): Promise<Result<number | null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_average_cpu_usage", { since }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async exportProcessReport(
+    format: ReportFormat,
+    path: string,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("export_process_report", { format, path }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async exportSessionReport(path: string): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("export_session_report", { path }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * The newest buffered log events, oldest first, to fill a log view mounted after startup
+   */
+  async getLogHistory(
+    limit: number | null,
+    minLevel: LogLevel | null,
+  ): Promise<LogEvent[]> {
+    return await TAURI_INVOKE("get_log_history", { limit, minLevel });
+  },
+  /**
+   * Search the in-memory log buffer by substring or regular expression
+   */
+  async queryLogs(query: LogQuery): Promise<Result<LogEvent[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("query_logs", { query }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Write the in-memory log buffer to `path`, as JSON lines for a `.jsonl` file
+   */
+  async exportLogs(path: string): Promise<Result<number, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("export_logs", { path }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async copyToClipboard(text: string): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("copy_to_clipboard", { text }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Copy log events as plain text in the export layout, the whole in-memory buffer
+   * without `logs`, returns the number of copied events
+   */
+  async copyLogsToClipboard(
+    logs: LogEvent[] | null,
+  ): Promise<Result<number, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("copy_logs_to_clipboard", { logs }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Capture a window, or the whole display without one, and put the image on the clipboard
+   */
+  async copyScreenshotToClipboard(
+    windowId: number | null,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("copy_screenshot_to_clipboard", { windowId }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async benchmarkProcessScan(
+    iterations: number,
+  ): Promise<Result<ScanBenchmark, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("benchmark_process_scan", { iterations }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getTargetPatterns(): Promise<Result<ProcessNamePattern[], string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_target_patterns") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setTargetPatterns(
+    patterns: ProcessNamePattern[],
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_target_patterns", { patterns }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getGameModeStatus(): Promise<GameModeStatus> {
+    return await TAURI_INVOKE("get_game_mode_status");
+  },
+  async getPowerDiagnostics(): Promise<PowerDiagnostics> {
+    return await TAURI_INVOKE("get_power_diagnostics");
+  },
+  async getSystemInfo(): Promise<SystemInfo> {
+    return await TAURI_INVOKE("get_system_info");
+  },
+  async getAppInfo(): Promise<AppInfo> {
+    return await TAURI_INVOKE("get_app_info");
+  },
+  /**
+   * Compare the running version with the newest GitHub release
+   */
+  async checkForUpdates(): Promise<Result<UpdateStatus, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("check_for_updates") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Download the installer of the newest release, start it and exit so it can replace the app
+   */
+  async installUpdate(): Promise<Result<null, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("install_update") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async cleanupInstallation(): Promise<CleanupReport> {
+    return await TAURI_INVOKE("cleanup_installation");
+  },
+  async restoreAllAceGuardProcesses(): Promise<Result<string, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("restore_all_ace_guard_processes"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Revert all modified processes with the outcome of each one, backs the Undo button
+   */
+  async revertAllAceGuardProcesses(): Promise<
+    Result<ProcessRestoreResult[], string>
+  > {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("revert_all_ace_guard_processes"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getTargetPriority(): Promise<Result<TargetPriority, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_target_priority") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setTargetPriority(
+    priority: TargetPriority,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_target_priority", { priority }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getWatchdogConfig(): Promise<Result<WatchdogConfig, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_watchdog_config") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setWatchdogConfig(
+    config: WatchdogConfig,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_watchdog_config", { config }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getLowerIoPriority(): Promise<Result<boolean, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_lower_io_priority"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setLowerIoPriority(enabled: boolean): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_lower_io_priority", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getLowerMemoryPriority(): Promise<Result<boolean, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_lower_memory_priority"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setLowerMemoryPriority(
+    enabled: boolean,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_lower_memory_priority", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getEfficiencyMode(): Promise<Result<boolean, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_efficiency_mode") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setEfficiencyMode(enabled: boolean): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_efficiency_mode", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async isEfficiencyModeSupported(): Promise<boolean> {
+    return await TAURI_INVOKE("is_efficiency_mode_supported");
+  },
+  async getTrimWorkingSet(): Promise<Result<boolean, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_trim_working_set") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setTrimWorkingSet(enabled: boolean): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_trim_working_set", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async optimizeAceGuardProcess(
+    processId: number,
+  ): Promise<Result<string, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("optimize_ace_guard_process", { processId }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async restoreAceGuardProcess(
+    processId: number,
+  ): Promise<Result<string, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("restore_ace_guard_process", { processId }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async suspendAceGuardProcess(
+    processId: number,
+  ): Promise<Result<string, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("suspend_ace_guard_process", { processId }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async resumeAceGuardProcess(
+    processId: number,
+  ): Promise<Result<string, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("resume_ace_guard_process", { processId }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getCpuTopology(): Promise<Result<CpuTopology, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_cpu_topology") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getGameBoostConfig(): Promise<Result<GameBoostConfig, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_game_boost_config"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setGameBoostConfig(
+    config: GameBoostConfig,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_game_boost_config", { config }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async boostGameProcesses(): Promise<Result<BoostedGame[], string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("boost_game_processes") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async revertGameBoost(): Promise<Result<number, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("revert_game_boost") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getSavedProcessStates(): Promise<Result<SavedProcessState[], string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_saved_process_states"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getCpuRateLimit(): Promise<Result<number | null, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_cpu_rate_limit") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setCpuRateLimit(percent: number | null): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_cpu_rate_limit", { percent }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getLowerThreadPriority(): Promise<Result<boolean, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_lower_thread_priority"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setLowerThreadPriority(
+    enabled: boolean,
+  ): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_lower_thread_priority", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getRequireTrustedSignature(): Promise<Result<boolean, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_require_trusted_signature"),
       };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
     }
   },
-  async optimizeAllAceGuardProcesses(): Promise<Result<string, string>> {
+  async setRequireTrustedSignature(
+    enabled: boolean,
+  ): Promise<Result<null, string>> {
     try {
       return {
         status: "ok",
-        data: await TAURI_INVOKE("optimize_all_ace_guard_processes"),
+        data: await TAURI_INVOKE("set_require_trusted_signature", { enabled }),
       };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
     }
   },
-  async getControllerPrivilegesStatus(): Promise<Result<boolean, string>> {
+  async getAutoOptimizeConfig(): Promise<Result<AutoOptimizeConfig, string>> {
     try {
       return {
         status: "ok",
-        data: await TAURI_INVOKE("get_controller_privileges_status"),
+        data: await TAURI_INVOKE("get_auto_optimize_config"),
       };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
     }
   },
-  async getAllWindows(): Promise<Result<WindowInfo[], string>> {
+  async setAutoOptimizeConfig(
+    config: AutoOptimizeConfig,
+  ): Promise<Result<null, string>> {
     try {
-      return { status: "ok", data: await TAURI_INVOKE("get_all_windows") };
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_auto_optimize_config", { config }),
+      };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
     }
   },
-  async tryCaptureImageByWindowId(
-    windowId: number,
-  ): Promise<Result<ScreenShot, string>> {
+  async getProcessStatusConfig(): Promise<Result<ProcessStatusConfig, string>> {
     try {
       return {
         status: "ok",
-        data: await TAURI_INVOKE("try_capture_image_by_window_id", {
-          windowId,
-        }),
+        data: await TAURI_INVOKE("get_process_status_config"),
       };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
     }
   },
-  async ocrScreenRegion(
-    region: OcrRegion,
-  ): Promise<Result<OcrResponse, string>> {
+  async setProcessStatusConfig(
+    config: ProcessStatusConfig,
+  ): Promise<Result<null, string>> {
     try {
       return {
         status: "ok",
-        data: await TAURI_INVOKE("ocr_screen_region", { region }),
+        data: await TAURI_INVOKE("set_process_status_config", { config }),
       };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
     }
   },
-  async ocrImageRegion(
-    imageData: number[],
-    region: OcrRegion,
-  ): Promise<Result<OcrResponse, string>> {
+  async getAceServiceStatus(): Promise<Result<AceServiceStatus[], string>> {
     try {
       return {
         status: "ok",
-        data: await TAURI_INVOKE("ocr_image_region", { imageData, region }),
+        data: await TAURI_INVOKE("get_ace_service_status"),
       };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
     }
   },
-  async ocrFullScreen(): Promise<Result<OcrResponse, string>> {
+  async getAceInstallationInfo(): Promise<AceInstallationInfo> {
+    return await TAURI_INVOKE("get_ace_installation_info");
+  },
+  async installLogonTask(): Promise<Result<null, string>> {
     try {
-      return { status: "ok", data: await TAURI_INVOKE("ocr_full_screen") };
+      return { status: "ok", data: await TAURI_INVOKE("install_logon_task") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async uninstallLogonTask(): Promise<Result<null, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("uninstall_logon_task") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async isLogonTaskInstalled(): Promise<boolean> {
+    return await TAURI_INVOKE("is_logon_task_installed");
+  },
+  async getAutostartEnabled(): Promise<boolean> {
+    return await TAURI_INVOKE("get_autostart_enabled");
+  },
+  async setAutostartEnabled(enabled: boolean): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_autostart_enabled", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getLowerGpuPriority(): Promise<Result<boolean, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_lower_gpu_priority"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setLowerGpuPriority(enabled: boolean): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_lower_gpu_priority", { enabled }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getThrottleMode(): Promise<Result<ThrottleMode, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_throttle_mode") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setThrottleMode(mode: ThrottleMode): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_throttle_mode", { mode }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getSettings(): Promise<Result<Settings, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_settings") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setSettings(settings: Settings): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_settings", { settings }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getBackgroundPauseStatus(): Promise<
+    Result<BackgroundPauseStatus, string>
+  > {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("get_background_pause_status"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  /**
+   * Pause the background rescan and the watchdog, for `minutes` or until resumed
+   */
+  async pauseBackgroundTasks(
+    minutes: number | null,
+  ): Promise<Result<BackgroundPauseStatus, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("pause_background_tasks", { minutes }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async resumeBackgroundTasks(): Promise<
+    Result<BackgroundPauseStatus, string>
+  > {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("resume_background_tasks"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getScheduleRules(): Promise<Result<ScheduleRule[], string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_schedule_rules") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setScheduleRules(rules: ScheduleRule[]): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("set_schedule_rules", { rules }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getTheme(): Promise<Result<Theme, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("get_theme") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setTheme(theme: Theme): Promise<Result<null, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("set_theme", { theme }) };
     } catch (e) {
       if (e instanceof Error) throw e;
       else return { status: "error", error: e as any };
@@ -123,14 +1373,369 @@ export const commands = {
 
 export const events = __makeEvents__<{
   logEvent: LogEvent;
+  hotkeyEvent: HotkeyEvent;
+  hotkeyCaptureEvent: HotkeyCaptureEvent;
+  cpuAlertEvent: CpuAlertEvent;
+  watchdogEvent: WatchdogEvent;
+  gameLaunchEvent: GameLaunchEvent;
+  processStatusEvent: ProcessStatusEvent;
+  processUsageEvent: ProcessUsageEvent;
+  optimizationProgress: OptimizationProgress;
+  backgroundPauseEvent: BackgroundPauseEvent;
+  scheduleRuleEvent: ScheduleRuleEvent;
+  updateDownloadProgress: UpdateDownloadProgress;
 }>({
   logEvent: "log-event",
+  hotkeyEvent: "hotkey-event",
+  hotkeyCaptureEvent: "hotkey-capture-event",
+  cpuAlertEvent: "cpu-alert-event",
+  watchdogEvent: "watchdog-event",
+  gameLaunchEvent: "game-launch-event",
+  processStatusEvent: "process-status-event",
+  processUsageEvent: "process-usage-event",
+  optimizationProgress: "optimization-progress",
+  backgroundPauseEvent: "background-pause-event",
+  scheduleRuleEvent: "schedule-rule-event",
+  updateDownloadProgress: "update-download-progress",
 });
 
 /** user-defined constants **/
 
 /** user-defined types **/
 
+export type AceFileInfo = {
+  path: string;
+  /**
+   * File version from the version resource, e.g. `5.4.12.1`
+   */
+  version: string | null;
+  size: number;
+  modified: string | null;
+  signature: SignatureInfo;
+};
+export type AceInstallationInfo = {
+  install_dir: string | null;
+  source: InstallationSource | null;
+  /**
+   * Version of `SGuard64.exe`, the closest thing to an ACE version
+   */
+  version: string | null;
+  files: AceFileInfo[];
+  /**
+   * Problems found, an empty list means the installation looks healthy
+   */
+  issues: string[];
+};
+/**
+ * Settings applied to all ACE instances belonging to the same game
+ */
+export type AceInstanceSettings = {
+  enabled: boolean;
+  throttle_mode: ThrottleMode;
+};
+/**
+ * An ACE process together with the helpers it spawned
+ */
+export type AceProcessNode = {
+  process_id: number;
+  process_name: string;
+  is_optimized: boolean;
+  children: AceProcessNode[];
+};
+export type AceServiceStatus = {
+  name: string;
+  display_name: string;
+  /**
+   * Kernel drivers like `ACE-BASE` as opposed to user mode services
+   */
+  is_driver: boolean;
+  state: ServiceState;
+  start_type: ServiceStartType;
+  /**
+   * Process hosting a running user mode service
+   */
+  process_id: number | null;
+};
+export type AffinityStrategy =
+  /**
+   * Pin to the last logical processor
+   */
+  | "LastCore"
+  /**
+   * Pin to the last two logical processors
+   */
+  | "LastTwoCores"
+  /**
+   * Pin to the efficiency cores of a hybrid CPU
+   */
+  | "ECoresOnly"
+  /**
+   * Pin to the CCD with the smallest L3 cache, e.g. the non-X3D die
+   */
+  | "SmallestCacheCcd"
+  /**
+   * Pin to an explicit affinity mask
+   */
+  | { CustomMask: number };
+export type AntiCheatFamily =
+  /**
+   * Tencent AntiCheatExpert, the scanner this tool is built around
+   */
+  | "Ace"
+  | "NProtect"
+  | "BattlEye"
+  | "EasyAntiCheat";
+export type AppInfo = {
+  name: string;
+  version: string;
+  repository: string;
+  /**
+   * `None` while the package does not declare a license
+   */
+  license: string | null;
+};
+export type AutoOptimizeConfig = {
+  enabled: boolean;
+  /**
+   * Executable names of the games that trigger an optimization
+   */
+  games: string[];
+  /**
+   * How long to wait for ACE to start after a game launch or a scheduled optimization
+   */
+  ace_wait_timeout_secs: number;
+};
+export type BackgroundPauseEvent = { status: BackgroundPauseStatus };
+export type BackgroundPauseStatus = {
+  paused: boolean;
+  paused_at: string | null;
+  /**
+   * When the background tasks resume on their own, `None` pauses until resumed
+   */
+  resume_at: string | null;
+};
+export type BoostMode =
+  | "Disabled"
+  | "Enabled"
+  | "Aggressive"
+  | "EfficientEnabled"
+  | "EfficientAggressive"
+  | "AggressiveAtGuaranteed"
+  | "EfficientAggressiveAtGuaranteed"
+  | "Unknown";
+export type BoostedGame = {
+  process_id: number;
+  process_name: string;
+  original_settings: OriginalProcessSettings;
+};
+export type CacheGroup = {
+  level: number;
+  /**
+   * Cache size in bytes
+   */
+  size: number;
+  logical_processors: number[];
+};
+/**
+ * Rectangle cut out of the captured window, relative to its top left corner
+ */
+export type CaptureCrop = {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+};
+export type CaptureOptions = {
+  /**
+   * Copy the window from the screen with BitBlt instead of asking it to draw itself
+   * with PrintWindow, which leaves DirectX windows black
+   */
+  use_bitblt: boolean;
+  /**
+   * Leave out the title bar and borders
+   */
+  client_only: boolean;
+  crop: CaptureCrop | null;
+};
+export type CleanupReport = {
+  /**
+   * ACE processes put back to normal priority and full affinity
+   */
+  restored_processes: number;
+  removed_paths: string[];
+  /**
+   * Steps that failed, cleanup continues past them
+   */
+  errors: string[];
+};
+export type CpuAlertConfig = {
+  enabled: boolean;
+  /**
+   * CPU usage in percent of all logical cores
+   */
+  threshold_percent: number;
+  /**
+   * How long the usage must stay above the threshold before alerting
+   */
+  duration_secs: number;
+  /**
+   * Re-apply the optimization to the offending process
+   */
+  auto_reapply: boolean;
+};
+export type CpuAlertEvent = {
+  process_id: number;
+  process_name: string;
+  cpu_usage: number;
+  threshold_percent: number;
+  reapplied: boolean;
+  timestamp: string;
+};
+export type CpuTopology = {
+  logical_processor_count: number;
+  cores: PhysicalCore[];
+  l3_caches: CacheGroup[];
+  /**
+   * True when the CPU mixes performance and efficiency cores
+   */
+  is_hybrid: boolean;
+};
+/**
+ * An anti-cheat process found on the system
+ */
+export type DetectedAntiCheat = {
+  family: AntiCheatFamily;
+  name: string;
+  process_id: number;
+  process_name: string;
+  process_path: string;
+  /**
+   * Whether the optimizer picks up this process as well
+   */
+  optimization_enabled: boolean;
+};
+export type GameBoostConfig = {
+  /**
+   * Boost the game together with every ACE optimization
+   */
+  enabled: boolean;
+  priority: GamePriority;
+};
+export type GameLaunchEvent = {
+  game: string;
+  process_id: number;
+  stage: GameLaunchStage;
+  message: string;
+  timestamp: string;
+};
+export type GameLaunchStage =
+  /**
+   * The game started, waiting for ACE
+   */
+  | "Detected"
+  | "Optimized"
+  | "Failed"
+  /**
+   * ACE did not show up in time
+   */
+  | "TimedOut";
+export type GameModeStatus = {
+  /**
+   * Game Mode is on unless the user switched it off in the settings app
+   */
+  enabled: boolean;
+  foreground_process_name: string | null;
+  foreground_process_path: string | null;
+  /**
+   * The foreground process is one of the games we know ACE is shipped with
+   */
+  foreground_is_known_game: boolean;
+  /**
+   * Windows has the foreground executable registered as a game
+   */
+  foreground_recognized: boolean;
+};
+/**
+ * Priority class given to the game process
+ */
+export type GamePriority = "AboveNormal" | "High";
+export type HistoryEntry = {
+  id: number;
+  timestamp: string;
+  kind: HistoryKind;
+  success: boolean;
+  summary: string;
+  /**
+   * Arbitrary JSON payload, e.g. the affected processes
+   */
+  details: string | null;
+};
+export type HistoryKind = "Scan" | "Optimization" | "Revert" | "Benchmark";
+export type HistoryQuery = {
+  kind: HistoryKind | null;
+  since: string | null;
+  until: string | null;
+  limit: number | null;
+};
+export type HotkeyAction =
+  | "Optimize"
+  | "Restore"
+  | "Screenshot"
+  | "ToggleWatchdog"
+  /**
+   * Drag a region on screen and recognize its text
+   */
+  | "OcrRegion";
+export type HotkeyBinding = {
+  action: HotkeyAction;
+  shortcut: string;
+  enabled: boolean;
+};
+export type HotkeyCaptureEvent = {
+  action: HotkeyAction;
+  /**
+   * File the screenshot was saved to
+   */
+  path: string | null;
+  /**
+   * Text recognized in the picked region
+   */
+  text: string | null;
+};
+export type HotkeyEvent = { action: HotkeyAction; shortcut: string };
+export type HudPosition = "TopLeft" | "TopRight" | "BottomLeft" | "BottomRight";
+export type HudStatus = {
+  process_count: number;
+  optimized_count: number;
+  /**
+   * Combined CPU usage of all ACE Guard processes, `None` until two samples were taken
+   */
+  cpu_usage: number | null;
+};
+export type InstallationSource =
+  /**
+   * `ImagePath` of the ACE service or driver
+   */
+  | "Registry"
+  /**
+   * Path of a running ACE process
+   */
+  | "RunningProcess"
+  /**
+   * One of the default install locations
+   */
+  | "KnownPath";
+/**
+ * Mandatory integrity level of a process token
+ */
+export type IntegrityLevel =
+  | "Untrusted"
+  | "Low"
+  | "Medium"
+  | "MediumPlus"
+  | "High"
+  | "System"
+  | "Protected";
 export type LogEvent = {
   level: LogLevel;
   target: string;
@@ -139,6 +1744,17 @@ export type LogEvent = {
   fields: Partial<{ [key in string]: string }>;
 };
 export type LogLevel = "TRACE" | "DEBUG" | "INFO" | "WARN" | "ERROR";
+export type LogQuery = {
+  /**
+   * Searched in the message, target and field values
+   */
+  pattern: string;
+  /**
+   * Treat `pattern` as a regular expression instead of a plain substring
+   */
+  regex: boolean;
+  case_sensitive: boolean;
+};
 export type OcrRegion = { x: number; y: number; width: number; height: number };
 export type OcrResponse = {
   results: OcrResult[];
@@ -146,23 +1762,550 @@ export type OcrResponse = {
   success: boolean;
 };
 export type OcrResult = { text: string; confidence: number; region: OcrRegion };
+export type OptimizationProgress = {
+  /**
+   * Number of processes handled so far, including this one
+   */
+  completed: number;
+  total: number;
+  process_id: number;
+  process_name: string;
+  success: boolean;
+};
+/**
+ * Priority class and affinity mask of a process before it was optimized
+ */
+export type OriginalProcessSettings = {
+  priority_class: number;
+  affinity_mask: number;
+};
+export type PhysicalCore = {
+  /**
+   * Logical processor indexes in processor group 0
+   */
+  logical_processors: number[];
+  /**
+   * Higher values are more performant, all cores share class 0 on non-hybrid CPUs
+   */
+  efficiency_class: number;
+};
+export type PowerDiagnostics = {
+  /**
+   * Minimum percentage of cores the active power plan keeps unparked (AC),
+   * `None` when the setting could not be read
+   */
+  core_parking_min_cores_percent: number | null;
+  /**
+   * Processor performance boost mode of the active power plan (AC)
+   */
+  boost_mode: BoostMode | null;
+  frequencies: ProcessorFrequency[];
+};
+export type ProcessChangeKind =
+  | "Found"
+  | "Optimized"
+  | "Exited"
+  /**
+   * ACE restored its own priority or affinity
+   */
+  | "Reset";
 export type ProcessInfo = {
   process_id: number;
   process_name: string;
   process_path: string;
   priority_modified: boolean;
   affinity_modified: boolean;
+  io_priority_modified: boolean;
+  memory_priority_modified: boolean;
+  gpu_priority_modified: boolean;
+  /**
+   * EcoQoS throttling applied, shown as Efficiency mode in Task Manager
+   */
+  efficiency_mode_enabled: boolean;
+  /**
+   * Working set in bytes right before and after the last trim
+   */
+  working_set_before_trim: number | null;
+  working_set_after_trim: number | null;
+  /**
+   * Suspended through this tool, ACE does no work at all until resumed
+   */
+  is_suspended: boolean;
+  /**
+   * Capped through a job object instead of pinned to cores
+   */
+  cpu_rate_limited: boolean;
+  /**
+   * Number of threads set to idle priority
+   */
+  threads_adjusted: number;
   current_priority: string;
   current_affinity: string;
   is_optimized: boolean;
+  parent_process_id: number;
+  parent_process_name: string | null;
+  /**
+   * Discovered as a helper spawned by another ACE process rather than by its name
+   */
+  is_child_process: boolean;
+  /**
+   * Anti-cheat the process belongs to, other families than ACE are opt-in
+   */
+  anti_cheat_family: AntiCheatFamily;
+  /**
+   * The game or launcher this ACE instance was started for, when it can be resolved
+   */
+  associated_game: string | null;
+  signature_status: SignatureStatus;
+  signer: string | null;
+  /**
+   * Protected process light level, `None` when it could not be queried
+   */
+  protection_level: ProtectionLevel | null;
+  /**
+   * Integrity level of the process token, `None` when it could not be queried
+   */
+  integrity_level: IntegrityLevel | null;
+  /**
+   * Times the process put its priority or affinity back after it was optimized
+   */
+  reset_count: number;
+};
+/**
+ * A configured target process name
+ */
+export type ProcessNamePattern =
+  /**
+   * Case insensitive exact name, e.g. `SGuard64.exe`
+   */
+  | { Exact: string }
+  /**
+   * `*` matches any run of characters and `?` a single one, e.g. `SGuard*`
+   */
+  | { Glob: string }
+  /**
+   * Case insensitive regular expression matched against the whole name
+   */
+  | { Regex: string };
+/**
+ * Outcome of reverting a single process
+ */
+export type ProcessRestoreResult = {
+  process_id: number;
+  process_name: string;
+  success: boolean;
+  error: string | null;
+};
+export type ProcessStatusChange = {
+  process_id: number;
+  process_name: string;
+  kind: ProcessChangeKind;
+};
+export type ProcessStatusConfig = {
+  enabled: boolean;
+  rescan_interval_secs: number;
+};
+export type ProcessStatusEvent = {
+  processes: ProcessInfo[];
+  /**
+   * What happened since the last event, empty for changes like a new priority
+   */
+  changes: ProcessStatusChange[];
+  timestamp: string;
+};
+export type ProcessUsage = {
+  process_id: number;
+  /**
+   * Percent of all logical cores since the previous query of this process,
+   * `None` on the first one
+   */
+  cpu_usage: number | null;
+  working_set: number;
+  private_bytes: number;
+  handle_count: number;
+  thread_count: number;
+};
+export type ProcessUsageEvent = { points: ProcessUsagePoint[] };
+export type ProcessUsageHistory = {
+  process_id: number;
+  process_name: string;
+  samples: ProcessUsageSample[];
+  /**
+   * Average CPU usage before and after optimization, to tell whether it helps
+   */
+  average_cpu_unoptimized: number | null;
+  average_cpu_optimized: number | null;
+};
+/**
+ * Latest sample of one process, part of a `ProcessUsageEvent`
+ */
+export type ProcessUsagePoint = {
+  process_id: number;
+  process_name: string;
+  sample: ProcessUsageSample;
+};
+export type ProcessUsageSample = {
+  timestamp: string;
+  cpu_usage: number | null;
+  working_set: number;
+  private_bytes: number;
+  /**
+   * Whether the process was optimized when the sample was taken
+   */
+  is_optimized: boolean;
+};
+export type ProcessorFrequency = {
+  processor: number;
+  max_mhz: number;
+  current_mhz: number;
+  mhz_limit: number;
+};
+/**
+ * Protected process (light) signer level of a process
+ */
+export type ProtectionLevel =
+  | "None"
+  | "WinTcbLight"
+  | "Windows"
+  | "WindowsLight"
+  | "AntimalwareLight"
+  | "LsaLight"
+  | "WinTcb"
+  | "CodeGenLight"
+  | "Authenticode"
+  | "PplApp"
+  | "Unknown";
+/**
+ * A region picked on the primary display, with its capture and optionally its text
+ */
+export type RegionCapture = {
+  region: OcrRegion;
+  screenshot: ScreenShot;
+  ocr: OcrResponse | null;
+};
+export type ReleaseAsset = {
+  name: string;
+  download_url: string;
+  /**
+   * Size in bytes
+   */
+  size: number;
+};
+export type ReleaseInfo = {
+  version: string;
+  name: string | null;
+  /**
+   * Release page on GitHub
+   */
+  url: string;
+  published_at: string | null;
+  /**
+   * Release notes in Markdown
+   */
+  notes: string | null;
+  /**
+   * NSIS installer attached to the release, `None` when it has no Windows build
+   */
+  installer: ReleaseAsset | null;
+};
+export type ReportFormat = "Json" | "Csv" | "Markdown";
+/**
+ * Settings of a modified process saved to disk, so they survive a crash of the tool
+ */
+export type SavedProcessState = {
+  process_id: number;
+  process_name: string;
+  /**
+   * Creation time of the process, a pid can be reused once the process exited
+   */
+  start_time: number;
+  original_settings: OriginalProcessSettings;
+};
+export type ScanBenchmark = {
+  iterations: number;
+  /**
+   * Average duration of a Toolhelp snapshot in microseconds
+   */
+  toolhelp_micros: number;
+  /**
+   * Average duration of a `NtQuerySystemInformation` scan in microseconds
+   */
+  nt_query_micros: number;
+};
+export type ScheduleAction = "Optimize" | "Revert";
+export type ScheduleRule = {
+  trigger: ScheduleTrigger;
+  action: ScheduleAction;
+  enabled: boolean;
+};
+export type ScheduleRuleEvent = {
+  rule: ScheduleRule;
+  success: boolean;
+  message: string;
+  timestamp: string;
 };
+export type ScheduleTrigger =
+  /**
+   * When the app starts, i.e. at logon while autostart is enabled
+   */
+  | "Logon"
+  /**
+   * When a process with this executable name starts
+   */
+  | { GameStart: string }
+  /**
+   * When the last process with this executable name exits
+   */
+  | { GameExit: string };
 export type ScreenShot = {
   image_data: number[];
   width: number;
   height: number;
   format: string;
 };
+/**
+ * What to capture when the caller does not need the picked window or region
+ */
+export type ScreenshotSource = { Window: number } | "FullScreen";
+export type ServiceStartType =
+  | "Boot"
+  | "System"
+  | "Automatic"
+  | "Manual"
+  | "Disabled"
+  | "Unknown";
+export type ServiceState =
+  | "Stopped"
+  | "StartPending"
+  | "StopPending"
+  | "Running"
+  | "Paused"
+  | "Other";
+/**
+ * User settings shared by the GUI and the CLI
+ */
+export type Settings = {
+  priority: TargetPriority;
+  affinity_strategy: AffinityStrategy;
+  throttle_mode: ThrottleMode;
+  /**
+   * Seconds between two scans of the watch mode
+   */
+  watch_interval_secs: number;
+  target_patterns: ProcessNamePattern[];
+  /**
+   * Background rescan that keeps the displayed process status current
+   */
+  process_status: ProcessStatusConfig;
+  /**
+   * UI language as a locale tag, `zh-cn` or `en`, follows the system locale by default
+   */
+  language: string;
+  theme: Theme;
+  /**
+   * Global shortcuts, they work while a game has focus
+   */
+  hotkeys: HotkeyBinding[];
+  /**
+   * Number of log events kept in memory for the log viewer and exports
+   */
+  log_buffer_capacity: number;
+  /**
+   * Hide the main window to the tray when it is closed instead of exiting
+   */
+  close_to_tray: boolean;
+  /**
+   * Rules run by the scheduler on logon, game starts and game exits
+   */
+  schedule: ScheduleRule[];
+  /**
+   * Title of the window picked for screenshots, the whole display without one
+   */
+  screenshot_window: string | null;
+  /**
+   * Main window geometry of the last session, the window opens centered without it
+   */
+  window: WindowGeometry | null;
+  watchdog: WatchdogConfig;
+  game_boost: GameBoostConfig;
+  cpu_alert: CpuAlertConfig;
+};
+export type SignatureInfo = { status: SignatureStatus; signer: string | null };
+export type SignatureStatus =
+  /**
+   * Valid signature from a known ACE publisher
+   */
+  | "Trusted"
+  /**
+   * Valid signature from an unexpected publisher
+   */
+  | "UntrustedSigner"
+  /**
+   * The executable is not signed at all
+   */
+  | "Unsigned"
+  /**
+   * The signature is present but broken, expired or revoked. Revocation is only
+   * known when the revocation list of the chain is in the local cache
+   */
+  | "Invalid"
+  /**
+   * The executable could not be checked, e.g. the path is unknown
+   */
+  | "Unknown";
+/**
+ * Everything shown in the status bar at the bottom of the main window
+ */
+export type StatusSummary = {
+  is_admin: boolean;
+  process_count: number;
+  optimized_count: number;
+  watchdog_enabled: boolean;
+  /**
+   * Background rescan and watchdog are paused from the UI
+   */
+  background_paused: boolean;
+  /**
+   * Newest optimization or revert, `None` before the first one
+   */
+  last_action: HistoryEntry | null;
+};
+export type SystemInfo = {
+  /**
+   * e.g. `Windows 10 Pro 23H2`
+   */
+  os_name: string;
+  /**
+   * Build number including the update revision, e.g. `22631.3880`
+   */
+  os_build: string;
+  is_windows_11: boolean;
+  cpu_model: string;
+  physical_cores: number;
+  logical_processors: number;
+  /**
+   * On non-hybrid CPUs every core counts as a performance core
+   */
+  performance_cores: number;
+  efficiency_cores: number;
+  /**
+   * More than one on machines with over 64 logical processors
+   */
+  processor_groups: number;
+  /**
+   * Installed physical memory in bytes
+   */
+  total_memory: number;
+  /**
+   * Name of the primary display adapter
+   */
+  gpu_name: string | null;
+  is_elevated: boolean;
+  /**
+   * Privileges currently enabled on the process token
+   */
+  enabled_privileges: string[];
+};
+/**
+ * Priority class applied to optimized processes
+ */
+export type TargetPriority =
+  /**
+   * Only runs when no other thread wants the CPU
+   */
+  | "Idle"
+  | "BelowNormal"
+  /**
+   * Leaves the priority untouched in practice, useful together with affinity only
+   */
+  | "Normal";
+/**
+ * Color scheme of the UI, `System` follows the Windows app mode
+ */
+export type Theme = "system" | "light" | "dark";
+/**
+ * How the CPU priority of an ACE process is lowered
+ */
+export type ThrottleMode =
+  /**
+   * Priority class only
+   */
+  | "PriorityOnly"
+  /**
+   * Priority class plus very low I/O and memory priority, like `PROCESS_MODE_BACKGROUND_BEGIN`
+   */
+  | "Background";
+export type TimerResolutionStatus = {
+  enabled: boolean;
+  active: boolean;
+  resolution_ms: number;
+  game_running: boolean;
+  /**
+   * At least one ACE process is currently optimized
+   */
+  optimizer_active: boolean;
+  /**
+   * Resolution the system timer currently runs at, in 100ns units
+   */
+  current_resolution_100ns: number | null;
+};
+export type UpdateDownloadProgress = {
+  downloaded: number;
+  /**
+   * `None` when the server does not report the size
+   */
+  total: number | null;
+};
+export type UpdateStatus = {
+  current_version: string;
+  /**
+   * Newest published release, `None` when nothing was released yet
+   */
+  latest: ReleaseInfo | null;
+  update_available: boolean;
+};
+/**
+ * Why the watchdog stepped in
+ */
+export type WatchdogAction =
+  /**
+   * A new ACE process showed up, e.g. after ACE restarted, and was optimized
+   */
+  | "ProcessStarted"
+  /**
+   * ACE restored its own priority or affinity and the settings were re-applied
+   */
+  | "SettingsReapplied";
+export type WatchdogConfig = { enabled: boolean; poll_interval_secs: number };
+export type WatchdogEvent = {
+  action: WatchdogAction;
+  process_id: number;
+  process_name: string;
+  success: boolean;
+  message: string;
+  timestamp: string;
+};
+/**
+ * Position and size of a window in physical pixels
+ */
+export type WindowGeometry = {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+  maximized: boolean;
+};
 export type WindowInfo = { title: string; process_id: number };
+/**
+ * Window picker entry with a small preview
+ */
+export type WindowThumbnail = {
+  window: WindowInfo;
+  /**
+   * JPEG preview, `None` when the window could not be captured, e.g. while minimized
+   */
+  thumbnail: ScreenShot | null;
+};
 
 type __EventObj__<T> = {
   listen: (
@@ -2,10 +2,15 @@ import {
   BadgeCheckIcon,
   BadgeMinus,
   Loader2,
+  Pause,
+  Play,
   Rocket,
   RotateCcw,
+  Undo2,
 } from "lucide-react";
+import { useState } from "react";
 import { toast } from "sonner";
+import { commands, type ProcessInfo, type Result } from "@/bindings";
 import { useAceProcessController } from "@/hooks/use-ace-process-controller";
 import { formatError } from "@/lib/fmt";
 import { cn } from "@/lib/utils";
@@ -21,6 +26,80 @@ import {
   CardTitle,
 } from "./ui/card";
 
+type ProcessAction = (processId: number) => Promise<Result<string, string>>;
+
+const ProcessActions = ({
+  process,
+  onDone,
+}: {
+  process: ProcessInfo;
+  onDone: () => Promise<unknown>;
+}) => {
+  const [pending, setPending] = useState(false);
+
+  const run = async (action: ProcessAction) => {
+    setPending(true);
+
+    const result = await action(process.process_id);
+
+    if (result.status === "error") {
+      toast.error(result.error);
+    } else {
+      toast.success(result.data);
+    }
+
+    await onDone();
+
+    setPending(false);
+  };
+
+  return (
+    <div className="flex gap-2 pt-1">
+      <Button
+        size="sm"
+        variant="outline"
+        disabled={pending}
+        onClick={() => run(commands.optimizeAceGuardProcess)}
+      >
+        <Rocket />
+        <span>{m.game_tools_ace_process_controller_optimize_process()}</span>
+      </Button>
+
+      <Button
+        size="sm"
+        variant="outline"
+        disabled={pending}
+        onClick={() => run(commands.restoreAceGuardProcess)}
+      >
+        <Undo2 />
+        <span>{m.game_tools_ace_process_controller_revert_process()}</span>
+      </Button>
+
+      {process.is_suspended ? (
+        <Button
+          size="sm"
+          variant="outline"
+          disabled={pending}
+          onClick={() => run(commands.resumeAceGuardProcess)}
+        >
+          <Play />
+          <span>{m.game_tools_ace_process_controller_resume_process()}</span>
+        </Button>
+      ) : (
+        <Button
+          size="sm"
+          variant="outline"
+          disabled={pending}
+          onClick={() => run(commands.suspendAceGuardProcess)}
+        >
+          <Pause />
+          <span>{m.game_tools_ace_process_controller_suspend_process()}</span>
+        </Button>
+      )}
+    </div>
+  );
+};
+
 export default function AceProcessController() {
   const { guard, tryOptimizeProcesses } = useAceProcessController();
 
@@ -30,7 +109,7 @@ export default function AceProcessController() {
     try {
       await tryOptimizeProcesses();
     } catch (error) {
-      toast.error("Failed to optimize processes. Please try again later.", {
+      toast.error(m.game_tools_ace_process_controller_optimize_failed(), {
         description: formatError(error),
       });
     }
@@ -50,13 +129,13 @@ export default function AceProcessController() {
         {guard.isLoading || guard.isFetching ? (
           <div className="flex items-center gap-2">
             <Loader2 className="size-4 animate-spin" />
-            <span>Loading processes...</span>
+            <span>{m.game_tools_ace_process_controller_loading()}</span>
           </div>
         ) : (
           <div className="space-y-2">
             {guard.data && guard.data.length > 0 ? (
-              guard.data.map((process, index) => (
-                <div key={index}>
+              guard.data.map((process) => (
+                <div key={process.process_id}>
                   {Object.entries(process).map(([key, value]) => (
                     <div
                       key={key}
@@ -68,6 +147,11 @@ export default function AceProcessController() {
                       </span>
                     </div>
                   ))}
+
+                  <ProcessActions
+                    process={process}
+                    onDone={() => guard.refetch()}
+                  />
                 </div>
               ))
             ) : (
@@ -2,7 +2,11 @@ import { SquareCode, X } from "lucide-react";
 import { AnimatePresence, motion } from "motion/react";
 import { cn } from "@/lib/utils";
 import { useLogger } from "@/providers/logger-provider";
-import LoggerViewer from "./logger-viewer";
+import LoggerViewer, {
+  LoggerCopyButtons,
+  LoggerLevelFilter,
+  LoggerSearch,
+} from "./logger-viewer";
 import { Button } from "./ui/button";
 
 export const LoggerViewerButton = () => {
@@ -57,24 +61,29 @@ export default function AppFooter() {
           }}
         >
           <div className="flex h-8 items-center justify-between border-b border-zinc-200 pr-1 pl-3 dark:border-zinc-800">
-            <SquareCode className="size-3" />
+            <div className="flex items-center gap-3">
+              <SquareCode className="size-3" />
 
-            <Button
-              className="size-6"
-              size="icon"
-              variant="ghost"
-              onClick={() => setOpenLoggerViewer(false)}
-            >
-              <X />
-            </Button>
-          </div>
+              <LoggerLevelFilter />
 
-          <div
-            className={cn("flex flex-col gap-0.5", "overflow-auto p-2")}
-            style={{ height: "calc(100% - 2rem)" }}
-          >
-            <LoggerViewer />
+              <LoggerSearch />
+            </div>
+
+            <div className="flex items-center gap-1">
+              <LoggerCopyButtons />
+
+              <Button
+                className="size-6"
+                size="icon"
+                variant="ghost"
+                onClick={() => setOpenLoggerViewer(false)}
+              >
+                <X />
+              </Button>
+            </div>
           </div>
+
+          <LoggerViewer />
         </motion.div>
       )}
     </AnimatePresence>
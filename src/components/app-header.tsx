@@ -1,5 +1,6 @@
 import { Separator } from "@radix-ui/react-separator";
 import { cn } from "@/lib/utils";
+import { m } from "@/paraglide/messages";
 import { ThemeSwitcher } from "./theme-switcher";
 import { SidebarTrigger } from "./ui/sidebar";
 
@@ -19,7 +20,7 @@ export default function AppHeader() {
           className="mr-2 data-[orientation=vertical]:h-4"
         />
 
-        <h1 className="text-lg font-semibold">Tencent ACE Tools</h1>
+        <h1 className="text-lg font-semibold">{m.app_title()}</h1>
 
         {/* <Breadcrumb className="flex-1">
           <BreadcrumbList>
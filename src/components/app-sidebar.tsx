@@ -5,6 +5,7 @@ import {
   SidebarFooter,
   SidebarHeader,
 } from "@/components/ui/sidebar";
+import BackgroundPause from "./background-pause";
 import RunningStatus from "./running-status";
 import SideNavigation from "./side-navigation";
 
@@ -20,6 +21,8 @@ export function AppSidebar({ ...props }: React.ComponentProps<typeof Sidebar>) {
       </SidebarContent>
 
       <SidebarFooter>
+        <BackgroundPause />
+
         <RunningStatus />
       </SidebarFooter>
     </Sidebar>
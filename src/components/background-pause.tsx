@@ -0,0 +1,130 @@
+import { useQuery, useQueryClient } from "@tanstack/react-query";
+import dayjs from "dayjs";
+import { Pause, Play } from "lucide-react";
+import { useEffect } from "react";
+import { toast } from "sonner";
+import { commands, events, type BackgroundPauseStatus } from "@/bindings";
+import {
+  BACKGROUND_PAUSE_QUERY_KEY,
+  STATUS_SUMMARY_QUERY_KEY,
+} from "@/hooks/consts";
+import { unwrapResult } from "@/lib/result";
+import { cn } from "@/lib/utils";
+import { m } from "@/paraglide/messages";
+import { Button } from "./ui/button";
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuLabel,
+  DropdownMenuTrigger,
+} from "./ui/dropdown-menu";
+import { useSidebar } from "./ui/sidebar";
+
+// minutes offered in the menu, `null` pauses until resumed
+const PAUSE_DURATIONS: { minutes: number | null; label: () => string }[] = [
+  { minutes: 15, label: m.background_pause_15_minutes },
+  { minutes: 60, label: m.background_pause_1_hour },
+  { minutes: null, label: m.background_pause_until_resumed },
+];
+
+const pausedLabel = (status: BackgroundPauseStatus) =>
+  status.resume_at
+    ? m.background_paused_until({
+        time: dayjs(status.resume_at).format("HH:mm"),
+      })
+    : m.background_paused();
+
+export default function BackgroundPause() {
+  const queryClient = useQueryClient();
+
+  const { open } = useSidebar();
+
+  const { data: status } = useQuery({
+    queryKey: [BACKGROUND_PAUSE_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getBackgroundPauseStatus());
+    },
+  });
+
+  // also emitted when a timed pause expires on its own
+  useEffect(() => {
+    const unlisten = events.backgroundPauseEvent.listen(({ payload }) => {
+      queryClient.setQueryData([BACKGROUND_PAUSE_QUERY_KEY], payload.status);
+      queryClient.invalidateQueries({ queryKey: [STATUS_SUMMARY_QUERY_KEY] });
+    });
+
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }, [queryClient]);
+
+  const handlePause = async (minutes: number | null) => {
+    const result = await commands.pauseBackgroundTasks(minutes);
+
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+  };
+
+  const handleResume = async () => {
+    const result = await commands.resumeBackgroundTasks();
+
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+  };
+
+  if (!status) {
+    return null;
+  }
+
+  if (status.paused) {
+    return (
+      <Button
+        size={open ? "sm" : "icon"}
+        variant="outline"
+        className={cn(
+          "h-6 border-amber-500 text-amber-600 dark:text-amber-500",
+          !open && "size-6",
+        )}
+        title={m.background_resume()}
+        onClick={handleResume}
+      >
+        <Play />
+        {open && <span>{pausedLabel(status)}</span>}
+      </Button>
+    );
+  }
+
+  return (
+    <DropdownMenu>
+      <DropdownMenuTrigger asChild>
+        <Button
+          size={open ? "sm" : "icon"}
+          variant="outline"
+          className={cn("h-6", !open && "size-6")}
+          title={m.background_pause()}
+        >
+          <Pause />
+          {open && <span>{m.background_pause()}</span>}
+        </Button>
+      </DropdownMenuTrigger>
+
+      <DropdownMenuContent side="right" align="end">
+        <DropdownMenuLabel>
+          {m.background_pause_description()}
+        </DropdownMenuLabel>
+
+        {PAUSE_DURATIONS.map(({ minutes, label }) => (
+          <DropdownMenuItem
+            key={minutes ?? "until-resumed"}
+            onClick={() => handlePause(minutes)}
+          >
+            {label()}
+          </DropdownMenuItem>
+        ))}
+      </DropdownMenuContent>
+    </DropdownMenu>
+  );
+}
@@ -0,0 +1,211 @@
+import { useQuery } from "@tanstack/react-query";
+import { Loader2, Save } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { commands, type AffinityStrategy } from "@/bindings";
+import {
+  AFFINITY_PROCESSORS_QUERY_KEY,
+  AFFINITY_STRATEGY_QUERY_KEY,
+  CPU_TOPOLOGY_QUERY_KEY,
+} from "@/hooks/consts";
+import { unwrapResult } from "@/lib/result";
+import { m } from "@/paraglide/messages";
+import { Button } from "./ui/button";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardFooter,
+  CardHeader,
+  CardTitle,
+} from "./ui/card";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "./ui/select";
+
+type PresetStrategy = Exclude<AffinityStrategy, { CustomMask: number }>;
+
+const CUSTOM_STRATEGY = "Custom";
+
+const STRATEGY_LABELS: Record<
+  PresetStrategy | typeof CUSTOM_STRATEGY,
+  () => string
+> = {
+  LastCore: m.core_picker_strategy_last_core,
+  LastTwoCores: m.core_picker_strategy_last_two_cores,
+  ECoresOnly: m.core_picker_strategy_e_cores_only,
+  SmallestCacheCcd: m.core_picker_strategy_smallest_cache_ccd,
+  [CUSTOM_STRATEGY]: m.core_picker_strategy_custom,
+};
+
+const strategyKey = (strategy: AffinityStrategy) =>
+  typeof strategy === "string" ? strategy : CUSTOM_STRATEGY;
+
+export default function CorePicker() {
+  const topology = useQuery({
+    queryKey: [CPU_TOPOLOGY_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getCpuTopology());
+    },
+  });
+
+  const strategy = useQuery({
+    queryKey: [AFFINITY_STRATEGY_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getAffinityStrategy());
+    },
+  });
+
+  const processors = useQuery({
+    queryKey: [AFFINITY_PROCESSORS_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getAffinityProcessors());
+    },
+  });
+
+  // picks stay local until they are saved as a custom mask
+  const [selected, setSelected] = useState<number[]>([]);
+
+  useEffect(() => {
+    if (processors.data) {
+      setSelected(processors.data);
+    }
+  }, [processors.data]);
+
+  const refetch = async () => {
+    await Promise.all([strategy.refetch(), processors.refetch()]);
+  };
+
+  const toggleProcessor = (processor: number) => {
+    setSelected((selected) =>
+      selected.includes(processor)
+        ? selected.filter((p) => p !== processor)
+        : [...selected, processor],
+    );
+  };
+
+  const handleStrategyChange = async (value: string) => {
+    // a custom mask is only set through the picked processors
+    if (value === CUSTOM_STRATEGY) {
+      return;
+    }
+
+    const result = await commands.setAffinityStrategy(value as PresetStrategy);
+
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+
+    await refetch();
+  };
+
+  const handleSave = async () => {
+    const result = await commands.setAffinityProcessors(
+      [...selected].sort((a, b) => a - b),
+    );
+
+    if (result.status === "error") {
+      toast.error(m.core_picker_save_failed(), { description: result.error });
+      return;
+    }
+
+    toast.success(m.core_picker_saved());
+
+    await refetch();
+  };
+
+  // the most performant cores have the highest class, all 0 on non-hybrid CPUs
+  const performanceClass = Math.max(
+    0,
+    ...(topology.data?.cores.map((core) => core.efficiency_class) ?? []),
+  );
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{m.core_picker_title()}</CardTitle>
+
+        <CardDescription>{m.core_picker_description()}</CardDescription>
+      </CardHeader>
+
+      <CardContent className="space-y-4">
+        <Select
+          value={strategy.data ? strategyKey(strategy.data) : undefined}
+          onValueChange={handleStrategyChange}
+        >
+          <SelectTrigger className="w-64">
+            <SelectValue />
+          </SelectTrigger>
+
+          <SelectContent>
+            {Object.entries(STRATEGY_LABELS).map(([value, label]) => (
+              <SelectItem key={value} value={value}>
+                {label()}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+
+        {topology.isLoading ? (
+          <Loader2 className="size-4 animate-spin" />
+        ) : (
+          <div className="flex flex-wrap gap-2">
+            {topology.data?.cores.map((core, index) => {
+              const isEfficiency =
+                topology.data.is_hybrid &&
+                core.efficiency_class < performanceClass;
+
+              return (
+                <div
+                  key={index}
+                  className="flex flex-col items-center gap-1 rounded-md border p-1"
+                  title={
+                    isEfficiency
+                      ? m.core_picker_efficiency_core()
+                      : m.core_picker_performance_core()
+                  }
+                >
+                  <div className="flex gap-1">
+                    {core.logical_processors.map((processor) => (
+                      <Button
+                        key={processor}
+                        className="size-8 font-mono text-xs"
+                        size="icon"
+                        variant={
+                          selected.includes(processor) ? "default" : "outline"
+                        }
+                        onClick={() => toggleProcessor(processor)}
+                      >
+                        {processor}
+                      </Button>
+                    ))}
+                  </div>
+
+                  {topology.data.is_hybrid && (
+                    <span className="text-muted-foreground text-xs">
+                      {isEfficiency
+                        ? m.core_picker_efficiency_core_short()
+                        : m.core_picker_performance_core_short()}
+                    </span>
+                  )}
+                </div>
+              );
+            })}
+          </div>
+        )}
+      </CardContent>
+
+      <CardFooter>
+        <Button onClick={handleSave} disabled={selected.length === 0}>
+          <Save />
+          <span>{m.core_picker_save()}</span>
+        </Button>
+      </CardFooter>
+    </Card>
+  );
+}
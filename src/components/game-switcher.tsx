@@ -22,7 +22,7 @@ const GAME_METADATA = {
     logo: (
       <img
         src="/icons/3rd/delta_force_logo.webp"
-        alt="Delta Force Logo"
+        alt={m.game_switcher_logo({ game: m.game_name_delta_force() })}
         className="size-4"
       />
     ),
@@ -68,7 +68,7 @@ export default function GameSwitcher() {
             sideOffset={4}
           >
             <DropdownMenuLabel className="text-muted-foreground text-xs">
-              Games
+              {m.game_switcher_games()}
             </DropdownMenuLabel>
 
             {Object.entries(GAME_METADATA).map(([gameKey, game]) => (
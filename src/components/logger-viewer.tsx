@@ -1,9 +1,21 @@
 import dayjs from "dayjs";
-import { ComponentProps } from "react";
-import type { LogLevel } from "@/bindings";
+import { ClipboardCopy, Copy, Regex } from "lucide-react";
+import {
+  ComponentProps,
+  ReactNode,
+  UIEvent,
+  useEffect,
+  useRef,
+  useState,
+} from "react";
+import { toast } from "sonner";
+import { commands, type LogEvent, type LogLevel } from "@/bindings";
 import { cn } from "@/lib/utils";
-import { useLogger } from "@/providers/logger-provider";
+import { m } from "@/paraglide/messages";
+import { FILTERABLE_LOG_LEVELS, useLogger } from "@/providers/logger-provider";
 import { Badge as OriginBadge } from "./ui/badge";
+import { Button } from "./ui/button";
+import { Input } from "./ui/input";
 
 const Badge = ({ className, ...props }: ComponentProps<typeof OriginBadge>) => {
   return (
@@ -14,51 +26,262 @@ const Badge = ({ className, ...props }: ComponentProps<typeof OriginBadge>) => {
   );
 };
 
-export default function LoggerViewer() {
-  const { logs } = useLogger();
-
-  const logColors: Record<LogLevel, string> = {
-    TRACE: "bg-gray-400 text-gray-800",
-    ERROR: "bg-red-500 text-white",
-    WARN: "bg-yellow-500 text-black",
-    INFO: "bg-green-500 text-white",
-    DEBUG: "bg-blue-500 text-white",
-  };
+// the darker shades keep white text readable on the light theme
+const logColors: Record<LogLevel, string> = {
+  TRACE: "bg-gray-300 text-gray-800 dark:bg-gray-400",
+  ERROR: "bg-red-600 text-white dark:bg-red-500",
+  WARN: "bg-yellow-400 text-black dark:bg-yellow-500",
+  INFO: "bg-green-700 text-white dark:bg-green-500",
+  DEBUG: "bg-blue-600 text-white dark:bg-blue-500",
+};
+
+export const LoggerLevelFilter = () => {
+  const { enabledLevels, toggleLevel } = useLogger();
 
-  // TODO: virtual scroll for performance
   return (
-    <>
-      {logs.map((log, index) => (
-        <p
-          key={index}
+    <div className="flex items-center gap-1">
+      {FILTERABLE_LOG_LEVELS.map((level) => (
+        <Badge
+          key={level}
           className={cn(
-            "flex w-full items-start gap-1 font-mono text-xs whitespace-nowrap",
+            "cursor-pointer select-none",
+            enabledLevels.includes(level)
+              ? logColors[level]
+              : "bg-transparent text-zinc-500 line-through",
           )}
-          data-log-level={log.level}
-          data-log-timestamp={log.timestamp}
+          variant="outline"
+          role="checkbox"
+          aria-checked={enabledLevels.includes(level)}
+          onClick={() => toggleLevel(level)}
         >
-          <Badge variant="secondary">
-            {dayjs(log.timestamp).format("HH:mm:ss")}
-          </Badge>
+          {level}
+        </Badge>
+      ))}
+    </div>
+  );
+};
+
+export const LoggerSearch = () => {
+  const { search, setSearch, searchRegex, setSearchRegex, isSearchValid } =
+    useLogger();
+
+  return (
+    <div className="flex items-center gap-1">
+      <Input
+        className="h-6 w-48 px-2 text-xs md:text-xs"
+        placeholder={m.logger_search_placeholder()}
+        value={search}
+        aria-invalid={!isSearchValid}
+        onChange={(event) => setSearch(event.target.value)}
+      />
+
+      <Button
+        className={cn("size-6", searchRegex && "bg-accent")}
+        size="icon"
+        variant="ghost"
+        title={m.logger_search_regex()}
+        aria-pressed={searchRegex}
+        onClick={() => setSearchRegex(!searchRegex)}
+      >
+        <Regex />
+      </Button>
+    </div>
+  );
+};
+
+export const LoggerCopyButtons = () => {
+  const { visibleLogs, selectedLogs, clearSelection } = useLogger();
+
+  const copyLogs = async (logs: LogEvent[]) => {
+    // the backend formats the lines like the plain text log export
+    const result = await commands.copyLogsToClipboard(logs);
+
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+
+    toast.success(m.logger_copied({ count: result.data }));
+  };
+
+  const handleCopySelected = async () => {
+    await copyLogs(selectedLogs);
+    clearSelection();
+  };
+
+  return (
+    <div className="flex items-center">
+      <Button
+        className="size-6"
+        size="icon"
+        variant="ghost"
+        title={m.logger_copy_selected()}
+        disabled={!selectedLogs.length}
+        onClick={handleCopySelected}
+      >
+        <Copy />
+      </Button>
+
+      <Button
+        className="size-6"
+        size="icon"
+        variant="ghost"
+        title={m.logger_copy_all()}
+        disabled={!visibleLogs.length}
+        onClick={() => copyLogs(visibleLogs)}
+      >
+        <ClipboardCopy />
+      </Button>
+    </div>
+  );
+};
+
+const Highlight = ({
+  text,
+  matcher,
+}: {
+  text: string;
+  matcher: RegExp | null;
+}) => {
+  if (!matcher) {
+    return text;
+  }
+
+  const parts: ReactNode[] = [];
+
+  let lastIndex = 0;
+
+  for (const match of text.matchAll(new RegExp(matcher.source, "gi"))) {
+    const index = match.index ?? 0;
+
+    // empty matches, e.g. from `a*`, have nothing to highlight
+    if (!match[0]) {
+      continue;
+    }
+
+    parts.push(text.slice(lastIndex, index));
 
-          <Badge className={cn("px-1.5 py-0", logColors[log.level])}>
-            {log.level}
-          </Badge>
+    parts.push(
+      <mark key={index} className="rounded-sm bg-yellow-300 text-black">
+        {match[0]}
+      </mark>,
+    );
 
-          <span className="text-zinc-500">{log.target}:</span>
+    lastIndex = index + match[0].length;
+  }
 
-          <span className="text-zinc-900 dark:text-zinc-100">
-            {log.message}
-          </span>
+  parts.push(text.slice(lastIndex));
 
-          {Object.entries(log.fields).map(([key, value]) => (
-            <span key={key} className="field">
-              <strong>{key}:</strong> {value}
+  return parts;
+};
+
+// every entry is rendered on a single line of the same height
+const ROW_HEIGHT = 20;
+// rows rendered above and below the viewport to avoid flicker while scrolling
+const OVERSCAN = 10;
+
+export default function LoggerViewer() {
+  const { visibleLogs: logs, searchMatcher, isSelected, toggleSelected } =
+    useLogger();
+
+  const containerRef = useRef<HTMLDivElement>(null);
+
+  const [scrollTop, setScrollTop] = useState(0);
+
+  const [viewportHeight, setViewportHeight] = useState(0);
+
+  // follow new entries until the user scrolls up
+  const followRef = useRef(true);
+
+  useEffect(() => {
+    const container = containerRef.current;
+
+    if (!container) {
+      return;
+    }
+
+    const observer = new ResizeObserver(() => {
+      setViewportHeight(container.clientHeight);
+    });
+
+    observer.observe(container);
+
+    return () => observer.disconnect();
+  }, []);
+
+  useEffect(() => {
+    const container = containerRef.current;
+
+    if (container && followRef.current) {
+      container.scrollTop = container.scrollHeight;
+    }
+  }, [logs.length]);
+
+  const handleScroll = (event: UIEvent<HTMLDivElement>) => {
+    const container = event.currentTarget;
+
+    setScrollTop(container.scrollTop);
+
+    const distanceToBottom =
+      container.scrollHeight - container.scrollTop - container.clientHeight;
+
+    followRef.current = distanceToBottom < ROW_HEIGHT;
+  };
+
+  const start = Math.max(0, Math.floor(scrollTop / ROW_HEIGHT) - OVERSCAN);
+
+  const end = Math.min(
+    logs.length,
+    Math.ceil((scrollTop + viewportHeight) / ROW_HEIGHT) + OVERSCAN,
+  );
+
+  return (
+    <div
+      ref={containerRef}
+      className="overflow-auto p-2"
+      style={{ height: "calc(100% - 2rem)" }}
+      onScroll={handleScroll}
+    >
+      <div className="relative" style={{ height: logs.length * ROW_HEIGHT }}>
+        {logs.slice(start, end).map((log, offset) => (
+          <p
+            key={start + offset}
+            className={cn(
+              "absolute left-0 flex w-full items-center gap-1 font-mono text-xs whitespace-nowrap",
+              "cursor-pointer hover:bg-zinc-100 dark:hover:bg-zinc-900",
+              isSelected(log) && "bg-zinc-200 dark:bg-zinc-800",
+            )}
+            style={{ top: (start + offset) * ROW_HEIGHT, height: ROW_HEIGHT }}
+            aria-selected={isSelected(log)}
+            onClick={() => toggleSelected(log)}
+            data-log-level={log.level}
+            data-log-timestamp={log.timestamp}
+          >
+            <Badge variant="secondary">
+              {dayjs(log.timestamp).format("HH:mm:ss")}
+            </Badge>
+
+            <Badge className={cn("px-1.5 py-0", logColors[log.level])}>
+              {log.level}
+            </Badge>
+
+            <span className="text-zinc-600 dark:text-zinc-500">
+              <Highlight text={log.target} matcher={searchMatcher} />:
             </span>
-          ))}
-          {index < logs.length - 1 && <hr className="log-separator" />}
-        </p>
-      ))}
-    </>
+
+            <span className="text-zinc-900 dark:text-zinc-100">
+              <Highlight text={log.message} matcher={searchMatcher} />
+            </span>
+
+            {Object.entries(log.fields).map(([key, value]) => (
+              <span key={key} className="field">
+                <strong>{key}:</strong>{" "}
+                <Highlight text={value ?? ""} matcher={searchMatcher} />
+              </span>
+            ))}
+          </p>
+        ))}
+      </div>
+    </div>
   );
 }
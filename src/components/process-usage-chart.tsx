@@ -0,0 +1,163 @@
+import { useEffect, useState } from "react";
+import { commands, events, type ProcessUsageSample } from "@/bindings";
+import { formatBytes } from "@/lib/fmt";
+import { m } from "@/paraglide/messages";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from "./ui/card";
+
+// matches the ten minutes kept by the backend at one sample per second
+const MAX_SAMPLES = 600;
+
+const CHART_WIDTH = 600;
+
+const CHART_HEIGHT = 120;
+
+type UsageSeries = Record<
+  number,
+  { processName: string; samples: ProcessUsageSample[] }
+>;
+
+const toPolyline = (values: number[], max: number) =>
+  values
+    .map((value, index) => {
+      const x = (index / Math.max(values.length - 1, 1)) * CHART_WIDTH;
+      const y = CHART_HEIGHT - (value / max) * CHART_HEIGHT;
+
+      return `${x.toFixed(1)},${y.toFixed(1)}`;
+    })
+    .join(" ");
+
+const MemoryChart = ({ samples }: { samples: ProcessUsageSample[] }) => {
+  const workingSet = samples.map((sample) => sample.working_set);
+
+  const privateBytes = samples.map((sample) => sample.private_bytes);
+
+  const max = Math.max(1, ...workingSet, ...privateBytes);
+
+  return (
+    <svg
+      className="h-30 w-full"
+      viewBox={`0 0 ${CHART_WIDTH} ${CHART_HEIGHT}`}
+      preserveAspectRatio="none"
+    >
+      <polyline
+        points={toPolyline(workingSet, max)}
+        fill="none"
+        className="stroke-chart-1"
+        strokeWidth={2}
+        vectorEffect="non-scaling-stroke"
+      />
+
+      <polyline
+        points={toPolyline(privateBytes, max)}
+        fill="none"
+        className="stroke-chart-2"
+        strokeWidth={2}
+        vectorEffect="non-scaling-stroke"
+      />
+    </svg>
+  );
+};
+
+export default function ProcessUsageChart() {
+  const [series, setSeries] = useState<UsageSeries>({});
+
+  useEffect(() => {
+    commands.getAceGuardUsageHistory(null).then((result) => {
+      if (result.status === "error") {
+        return;
+      }
+
+      setSeries(
+        Object.fromEntries(
+          result.data.map((history) => [
+            history.process_id,
+            {
+              processName: history.process_name,
+              samples: history.samples.slice(-MAX_SAMPLES),
+            },
+          ]),
+        ),
+      );
+    });
+
+    const unlisten = events.processUsageEvent.listen(({ payload }) => {
+      // exited processes are no longer part of the event and are dropped
+      setSeries((series) =>
+        Object.fromEntries(
+          payload.points.map((point) => [
+            point.process_id,
+            {
+              processName: point.process_name,
+              samples: [
+                ...(series[point.process_id]?.samples ?? []),
+                point.sample,
+              ].slice(-MAX_SAMPLES),
+            },
+          ]),
+        ),
+      );
+    });
+
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }, []);
+
+  const entries = Object.entries(series);
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{m.process_usage_chart_title()}</CardTitle>
+
+        <CardDescription>
+          {m.process_usage_chart_description()}
+        </CardDescription>
+      </CardHeader>
+
+      <CardContent className="space-y-4">
+        {entries.length > 0 ? (
+          entries.map(([processId, { processName, samples }]) => {
+            const latest = samples.at(-1);
+
+            return (
+              <div key={processId} className="space-y-1">
+                <div className="flex justify-between gap-2 text-sm">
+                  <span className="font-medium">
+                    {processName} ({processId})
+                  </span>
+
+                  {latest && (
+                    <span className="text-muted-foreground">
+                      <span className="text-chart-1">
+                        {m.process_usage_chart_working_set()}{" "}
+                        {formatBytes(latest.working_set)}
+                      </span>
+                      {" / "}
+                      <span className="text-chart-2">
+                        {m.process_usage_chart_private_bytes()}{" "}
+                        {formatBytes(latest.private_bytes)}
+                      </span>
+                    </span>
+                  )}
+                </div>
+
+                <MemoryChart samples={samples} />
+              </div>
+            );
+          })
+        ) : (
+          <div className="text-muted-foreground text-sm">
+            {m.process_usage_chart_no_data()}
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -1,6 +1,9 @@
-import { BadgeCheckIcon, BadgeX, Loader2 } from "lucide-react";
+import { BadgeCheckIcon, BadgeX, Loader2, ShieldCheck } from "lucide-react";
 import { ComponentProps } from "react";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
 import { Badge as BadgePrimitive } from "@/components/ui/badge";
+import { Button } from "@/components/ui/button";
 import { useIsRunningAsAdmin } from "@/hooks/use-is-running-as-admin";
 import { cn } from "@/lib/utils";
 import { m } from "@/paraglide/messages";
@@ -32,13 +35,46 @@ const Badge = ({
   );
 };
 
+const RestartAsAdminButton = ({ icon }: { icon?: boolean }) => {
+  const handleClick = async () => {
+    // the elevated instance takes over and this one exits
+    const result = await commands.restartAsAdmin();
+
+    if (result.status === "error") {
+      toast.error(m.restart_as_admin_failed(), { description: result.error });
+    } else if (!result.data) {
+      toast.warning(m.restart_as_admin_cancelled());
+    }
+  };
+
+  return (
+    <Tooltip>
+      <TooltipTrigger asChild>
+        <Button
+          size={icon ? "icon" : "sm"}
+          variant="outline"
+          className={cn("h-6", icon && "size-6")}
+          onClick={handleClick}
+        >
+          <ShieldCheck />
+          {!icon && <span>{m.restart_as_admin()}</span>}
+        </Button>
+      </TooltipTrigger>
+
+      <TooltipContent>
+        <p>{m.restart_as_admin_description()}</p>
+      </TooltipContent>
+    </Tooltip>
+  );
+};
+
 export default function RunningStatus() {
   const { isPending, data: status } = useIsRunningAsAdmin();
 
   const { open } = useSidebar();
 
   return (
-    <div className="flex items-center gap-2">
+    <div className={cn("flex items-center gap-2", !open && "flex-col")}>
       {isPending ? (
         <Badge
           variant="secondary"
@@ -60,14 +96,18 @@ export default function RunningStatus() {
           {open && <span>{m.running_as_admin()}</span>}
         </Badge>
       ) : (
-        <Badge
-          variant="destructive"
-          icon={!open}
-          description={m.not_running_as_admin_description()}
-        >
-          <BadgeX />
-          {open && <span>{m.not_running_as_admin()}</span>}
-        </Badge>
+        <>
+          <Badge
+            variant="destructive"
+            icon={!open}
+            description={m.not_running_as_admin_description()}
+          >
+            <BadgeX />
+            {open && <span>{m.not_running_as_admin()}</span>}
+          </Badge>
+
+          <RestartAsAdminButton icon={!open} />
+        </>
       )}
     </div>
   );
@@ -1,5 +1,12 @@
 import { Link, useLocation } from "@tanstack/react-router";
-import { Bug, ChevronRight, Gamepad2 } from "lucide-react";
+import {
+  Bug,
+  CalendarClock,
+  ChevronRight,
+  Gamepad2,
+  Info,
+  Keyboard,
+} from "lucide-react";
 import { ReactNode } from "react";
 import {
   Collapsible,
@@ -32,6 +39,23 @@ const NAVBAR_LIST: Record<GAME_ENUM, Record<string, NavigationItem[]>> = {
         url: "/",
         icon: <Gamepad2 />,
       },
+      {
+        title: m.navigation_game_tools_scheduler(),
+        url: "/scheduler",
+        icon: <CalendarClock />,
+      },
+    ],
+    [m.navigation_settings()]: [
+      {
+        title: m.navigation_settings_hotkeys(),
+        url: "/hotkeys",
+        icon: <Keyboard />,
+      },
+      {
+        title: m.navigation_settings_about(),
+        url: "/about",
+        icon: <Info />,
+      },
     ],
     [m.navigation_developer_tools()]: [
       {
@@ -0,0 +1,84 @@
+import { useQuery } from "@tanstack/react-query";
+import dayjs from "dayjs";
+import { Pause, ShieldAlert, ShieldCheck } from "lucide-react";
+import { commands, type HistoryKind } from "@/bindings";
+import { STATUS_SUMMARY_QUERY_KEY } from "@/hooks/consts";
+import { unwrapResult } from "@/lib/result";
+import { cn } from "@/lib/utils";
+import { m } from "@/paraglide/messages";
+
+// the summary only reads the last scan, so polling it is cheap
+const STATUS_POLL_INTERVAL = 2000;
+
+const ACTION_LABELS: Record<HistoryKind, () => string> = {
+  Scan: m.status_bar_action_scan,
+  Optimization: m.status_bar_action_optimization,
+  Revert: m.status_bar_action_revert,
+  Benchmark: m.status_bar_action_benchmark,
+};
+
+export default function StatusBar() {
+  const { data: summary } = useQuery({
+    queryKey: [STATUS_SUMMARY_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getStatusSummary());
+    },
+    refetchInterval: STATUS_POLL_INTERVAL,
+  });
+
+  if (!summary) {
+    return null;
+  }
+
+  const lastAction = summary.last_action;
+
+  const lastActionLabel = lastAction
+    ? m.status_bar_last_action({
+        action: ACTION_LABELS[lastAction.kind](),
+        time: dayjs(lastAction.timestamp).format("HH:mm:ss"),
+      })
+    : m.status_bar_no_action();
+
+  return (
+    <div className="text-muted-foreground flex h-6 shrink-0 cursor-default items-center gap-4 border-t border-zinc-200 px-3 text-xs select-none dark:border-zinc-800">
+      <span className="flex items-center gap-1">
+        {summary.is_admin ? (
+          <ShieldCheck className="size-3" />
+        ) : (
+          <ShieldAlert className="size-3 text-red-500" />
+        )}
+
+        {summary.is_admin ? m.running_as_admin() : m.not_running_as_admin()}
+      </span>
+
+      <span>
+        {m.status_bar_processes({
+          optimized: summary.optimized_count,
+          total: summary.process_count,
+        })}
+      </span>
+
+      <span>
+        {summary.watchdog_enabled
+          ? m.status_bar_watchdog_on()
+          : m.status_bar_watchdog_off()}
+      </span>
+
+      {summary.background_paused && (
+        <span className="flex items-center gap-1 text-amber-500">
+          <Pause className="size-3" />
+          {m.status_bar_paused()}
+        </span>
+      )}
+
+      <span
+        className={cn(
+          "ml-auto",
+          lastAction && !lastAction.success && "text-red-500",
+        )}
+      >
+        {lastActionLabel}
+      </span>
+    </div>
+  );
+}
@@ -1,5 +1,6 @@
 import { useEffect, useState } from "react";
 import { cn } from "@/lib/utils";
+import { m } from "@/paraglide/messages";
 
 type TTheme = "system" | "light" | "dark";
 
@@ -32,10 +33,10 @@ export const ThemeSwitcher = () => {
 
   return (
     <fieldset className="border-shadow m-0 flex h-8 rounded-[999999px] border p-0">
-      <legend className="sr-only">Select a display theme:</legend>
+      <legend className="sr-only">{m.theme_switcher_legend()}</legend>
       <div className="mt-[-1px] ml-[-1px]">
         <input
-          aria-label="system"
+          aria-label={m.theme_switcher_system()}
           id="theme-switch-system"
           type="radio"
           value="system"
@@ -51,7 +52,7 @@ export const ThemeSwitcher = () => {
             theme === "system" && "border-shadow border-r",
           )}
         >
-          <span className="sr-only">system</span>
+          <span className="sr-only">{m.theme_switcher_system()}</span>
 
           <svg
             height="16"
@@ -74,7 +75,7 @@ export const ThemeSwitcher = () => {
 
       <div className="mt-[-1px]">
         <input
-          aria-label="light"
+          aria-label={m.theme_switcher_light()}
           id="theme-switch-light"
           type="radio"
           value="light"
@@ -89,7 +90,7 @@ export const ThemeSwitcher = () => {
             theme === "light" && "border-shadow border",
           )}
         >
-          <span className="sr-only">light</span>
+          <span className="sr-only">{m.theme_switcher_light()}</span>
 
           <svg
             height="16"
@@ -112,7 +113,7 @@ export const ThemeSwitcher = () => {
 
       <div className="mt-[-1px] mr-[-1px]">
         <input
-          aria-label="dark"
+          aria-label={m.theme_switcher_dark()}
           id="theme-switch-dark"
           type="radio"
           value="dark"
@@ -127,7 +128,7 @@ export const ThemeSwitcher = () => {
             theme === "dark" && "border-shadow border-l",
           )}
         >
-          <span className="sr-only">dark</span>
+          <span className="sr-only">{m.theme_switcher_dark()}</span>
 
           <svg
             height="16"
@@ -1,3 +1,12 @@
 export const IS_RUNNING_AS_ADMIN_QUERY_KEY = "is-running-as-admin";
 export const ACE_PROCESS_CONTROLLER_QUERY_KEY = "ace-process-controller";
 export const ACE_GUARD_PRIVILEGES_QUERY_KEY = "ace-guard-privileges";
+export const HUD_STATUS_QUERY_KEY = "hud-status";
+export const SCHEDULE_RULES_QUERY_KEY = "schedule-rules";
+export const HOTKEY_BINDINGS_QUERY_KEY = "hotkey-bindings";
+export const STATUS_SUMMARY_QUERY_KEY = "status-summary";
+export const APP_INFO_QUERY_KEY = "app-info";
+export const CPU_TOPOLOGY_QUERY_KEY = "cpu-topology";
+export const AFFINITY_STRATEGY_QUERY_KEY = "affinity-strategy";
+export const AFFINITY_PROCESSORS_QUERY_KEY = "affinity-processors";
+export const BACKGROUND_PAUSE_QUERY_KEY = "background-pause";
@@ -0,0 +1,18 @@
+import { useEffect } from "react";
+
+/**
+ * Overlay windows are transparent, the themed body background would cover
+ * whatever is behind them
+ */
+export function useTransparentBody() {
+  useEffect(() => {
+    const { body } = document;
+    const previous = body.style.background;
+
+    body.style.background = "transparent";
+
+    return () => {
+      body.style.background = previous;
+    };
+  }, []);
+}
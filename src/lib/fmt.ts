@@ -1,3 +1,17 @@
 export function formatError(err: unknown): string {
   return err instanceof Error ? err.message : String(err);
 }
+
+const BYTE_UNITS = ["B", "KB", "MB", "GB"];
+
+export function formatBytes(bytes: number): string {
+  let value = bytes;
+  let unit = 0;
+
+  while (value >= 1024 && unit < BYTE_UNITS.length - 1) {
+    value /= 1024;
+    unit += 1;
+  }
+
+  return `${value.toFixed(unit === 0 ? 0 : 1)} ${BYTE_UNITS[unit]}`;
+}
@@ -1,8 +1,9 @@
-import { createRootRoute, Outlet } from "@tanstack/react-router";
+import { createRootRoute, Outlet, useLocation } from "@tanstack/react-router";
 import { PropsWithChildren } from "react";
 import AppFooter, { LoggerViewerButton } from "@/components/app-footer";
 import AppHeader from "@/components/app-header";
 import { AppSidebar } from "@/components/app-sidebar";
+import StatusBar from "@/components/status-bar";
 import { SidebarInset, SidebarProvider } from "@/components/ui/sidebar";
 import { Toaster } from "@/components/ui/sonner";
 
@@ -10,6 +11,9 @@ export const Route = createRootRoute({
   component: RootComponent,
 });
 
+// overlay windows render their page alone, without the app chrome
+const OVERLAY_ROUTES: string[] = ["/hud", "/region-picker"];
+
 const Layout = ({ children }: PropsWithChildren) => {
   return (
     <SidebarProvider>
@@ -20,6 +24,8 @@ const Layout = ({ children }: PropsWithChildren) => {
 
         <div className="h-full overflow-auto">{children}</div>
 
+        <StatusBar />
+
         <AppFooter />
 
         <LoggerViewerButton />
@@ -29,6 +35,12 @@ const Layout = ({ children }: PropsWithChildren) => {
 };
 
 function RootComponent() {
+  const { pathname } = useLocation();
+
+  if (OVERLAY_ROUTES.includes(pathname)) {
+    return <Outlet />;
+  }
+
   return (
     <>
       <Toaster position="top-center" richColors />
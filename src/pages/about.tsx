@@ -0,0 +1,205 @@
+import { useQuery } from "@tanstack/react-query";
+import { createFileRoute } from "@tanstack/react-router";
+import { openUrl } from "@tauri-apps/plugin-opener";
+import { Download, ExternalLink, Loader2, RefreshCw } from "lucide-react";
+import { ReactNode, useEffect, useState } from "react";
+import { toast } from "sonner";
+import {
+  commands,
+  events,
+  type UpdateDownloadProgress,
+  type UpdateStatus,
+} from "@/bindings";
+import { Button } from "@/components/ui/button";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardFooter,
+  CardHeader,
+  CardTitle,
+} from "@/components/ui/card";
+import { APP_INFO_QUERY_KEY } from "@/hooks/consts";
+import { m } from "@/paraglide/messages";
+
+export const Route = createFileRoute("/about")({
+  component: RouteComponent,
+});
+
+const InfoRow = ({
+  label,
+  children,
+}: {
+  label: string;
+  children: ReactNode;
+}) => {
+  return (
+    <div className="flex items-center justify-between gap-2 text-sm">
+      <span className="text-muted-foreground">{label}</span>
+
+      <span className="font-medium">{children}</span>
+    </div>
+  );
+};
+
+const formatMegabytes = (bytes: number) => (bytes / 1024 / 1024).toFixed(1);
+
+const UpdateCard = () => {
+  const [status, setStatus] = useState<UpdateStatus | null>(null);
+
+  const [checking, setChecking] = useState(false);
+
+  const [installing, setInstalling] = useState(false);
+
+  const [progress, setProgress] = useState<UpdateDownloadProgress | null>(
+    null,
+  );
+
+  useEffect(() => {
+    const unlisten = events.updateDownloadProgress.listen(({ payload }) => {
+      setProgress(payload);
+    });
+
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }, []);
+
+  const handleCheck = async () => {
+    setChecking(true);
+
+    const result = await commands.checkForUpdates();
+
+    setChecking(false);
+
+    if (result.status === "error") {
+      toast.error(m.about_update_check_failed(), {
+        description: result.error,
+      });
+      return;
+    }
+
+    setStatus(result.data);
+  };
+
+  const handleInstall = async () => {
+    setInstalling(true);
+
+    // the app exits once the installer was started
+    const result = await commands.installUpdate();
+
+    setInstalling(false);
+    setProgress(null);
+
+    if (result.status === "error") {
+      toast.error(m.about_update_install_failed(), {
+        description: result.error,
+      });
+    }
+  };
+
+  const latest = status?.latest;
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{m.about_updates_title()}</CardTitle>
+
+        <CardDescription>
+          {!status
+            ? m.about_updates_description()
+            : status.update_available && latest
+              ? m.about_update_available({ version: latest.version })
+              : m.about_up_to_date()}
+        </CardDescription>
+      </CardHeader>
+
+      {progress && (
+        <CardContent className="text-sm">
+          {progress.total
+            ? m.about_update_downloading({
+                downloaded: formatMegabytes(progress.downloaded),
+                total: formatMegabytes(progress.total),
+              })
+            : m.about_update_downloading_unknown_size({
+                downloaded: formatMegabytes(progress.downloaded),
+              })}
+        </CardContent>
+      )}
+
+      <CardFooter className="gap-2">
+        <Button variant="outline" onClick={handleCheck} disabled={checking}>
+          {checking ? <Loader2 className="animate-spin" /> : <RefreshCw />}
+          <span>{m.about_check_for_updates()}</span>
+        </Button>
+
+        {status?.update_available && latest && (
+          <>
+            <Button variant="outline" onClick={() => openUrl(latest.url)}>
+              <ExternalLink />
+              <span>{m.about_release_notes()}</span>
+            </Button>
+
+            {latest.installer && (
+              <Button onClick={handleInstall} disabled={installing}>
+                {installing ? (
+                  <Loader2 className="animate-spin" />
+                ) : (
+                  <Download />
+                )}
+                <span>{m.about_install_update()}</span>
+              </Button>
+            )}
+          </>
+        )}
+      </CardFooter>
+    </Card>
+  );
+};
+
+function RouteComponent() {
+  const { data: info } = useQuery({
+    queryKey: [APP_INFO_QUERY_KEY],
+    queryFn: () => commands.getAppInfo(),
+  });
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      <Card>
+        <CardHeader>
+          <CardTitle>{m.about_title()}</CardTitle>
+
+          <CardDescription>{m.about_description()}</CardDescription>
+        </CardHeader>
+
+        <CardContent className="space-y-2">
+          {info ? (
+            <>
+              <InfoRow label={m.about_name()}>{info.name}</InfoRow>
+
+              <InfoRow label={m.about_version()}>{info.version}</InfoRow>
+
+              <InfoRow label={m.about_license()}>
+                {info.license ?? m.about_license_unknown()}
+              </InfoRow>
+
+              <InfoRow label={m.about_repository()}>
+                <Button
+                  className="h-auto p-0"
+                  variant="link"
+                  onClick={() => openUrl(info.repository)}
+                >
+                  {info.repository}
+                </Button>
+              </InfoRow>
+            </>
+          ) : (
+            <Loader2 className="size-4 animate-spin" />
+          )}
+        </CardContent>
+      </Card>
+
+      <UpdateCard />
+    </div>
+  );
+}
@@ -26,6 +26,7 @@ import {
 import { Input } from "@/components/ui/input";
 import { NumberInput } from "@/components/ui/number-input";
 import { unwrapResult } from "@/lib/result";
+import { m } from "@/paraglide/messages";
 
 export const Route = createFileRoute("/debug")({
   component: RouteComponent,
@@ -39,7 +40,7 @@ const TestGreet = () => {
           commands.greet("mike");
         }}
       >
-        Greet
+        {m.debug_greet()}
       </Button>
     </div>
   );
@@ -131,7 +132,7 @@ const Windows = () => {
       const filePath = await save({
         filters: [
           {
-            name: "PNG 图片",
+            name: m.debug_png_image(),
             extensions: ["png"],
           },
         ],
@@ -148,18 +149,20 @@ const Windows = () => {
 
       // write the file
       await writeFile(filePath, bytes);
-      toast.success("Image saved successfully!", {
+      toast.success(m.debug_image_saved(), {
         description: `${filePath}`,
       });
     } catch (error) {
-      toast.error("Failed to save image");
+      toast.error(m.debug_image_save_failed());
       console.error("Failed to save image:", error);
     }
   }, [screenShotResult]);
 
   return (
     <div className="flex flex-col gap-4">
-      <Button onClick={handleGetAllWindows}>Get All Windows</Button>
+      <Button onClick={handleGetAllWindows}>
+        {m.debug_get_all_windows()}
+      </Button>
 
       <pre className="max-h-96 overflow-auto text-sm">
         {JSON.stringify(windows, null, 2)}
@@ -167,14 +170,14 @@ const Windows = () => {
 
       <div className="flex gap-2">
         <Input
-          placeholder="Enter window title"
+          placeholder={m.debug_window_id_placeholder()}
           value={windowId}
           onChange={(e) => setWindowId(e.target.value)}
         />
 
         <Button onClick={handleCaptureByWindowId} disabled={isPending}>
           {isPending && <Loader2 className="mr-2 animate-spin" />}
-          <span>Capture by Window ID</span>
+          <span>{m.debug_capture_by_window_id()}</span>
         </Button>
       </div>
 
@@ -185,9 +188,9 @@ const Windows = () => {
             name="x"
             render={({ field }) => (
               <FormItem>
-                <FormLabel>X</FormLabel>
+                <FormLabel>{m.debug_region_x()}</FormLabel>
                 <FormControl>
-                  <NumberInput placeholder="X" {...field} />
+                  <NumberInput placeholder={m.debug_region_x()} {...field} />
                 </FormControl>
                 <FormMessage />
               </FormItem>
@@ -199,9 +202,9 @@ const Windows = () => {
             name="y"
             render={({ field }) => (
               <FormItem>
-                <FormLabel>Y</FormLabel>
+                <FormLabel>{m.debug_region_y()}</FormLabel>
                 <FormControl>
-                  <NumberInput placeholder="Y" {...field} />
+                  <NumberInput placeholder={m.debug_region_y()} {...field} />
                 </FormControl>
                 <FormMessage />
               </FormItem>
@@ -213,9 +216,12 @@ const Windows = () => {
             name="width"
             render={({ field }) => (
               <FormItem>
-                <FormLabel>Width</FormLabel>
+                <FormLabel>{m.debug_region_width()}</FormLabel>
                 <FormControl>
-                  <NumberInput placeholder="Width" {...field} />
+                  <NumberInput
+                    placeholder={m.debug_region_width()}
+                    {...field}
+                  />
                 </FormControl>
                 <FormMessage />
               </FormItem>
@@ -227,9 +233,12 @@ const Windows = () => {
             name="height"
             render={({ field }) => (
               <FormItem>
-                <FormLabel>Height</FormLabel>
+                <FormLabel>{m.debug_region_height()}</FormLabel>
                 <FormControl>
-                  <NumberInput placeholder="Height" {...field} />
+                  <NumberInput
+                    placeholder={m.debug_region_height()}
+                    {...field}
+                  />
                 </FormControl>
                 <FormMessage />
               </FormItem>
@@ -245,12 +254,12 @@ const Windows = () => {
         {form.formState.isSubmitting && (
           <Loader2 className="mr-2 animate-spin" />
         )}
-        <span>OCR Screen Region</span>
+        <span>{m.debug_ocr_screen_region()}</span>
       </Button>
 
       {screenShotResult && (
         <div className="flex flex-col gap-2">
-          <Button onClick={handleSaveImage}>Save Image</Button>
+          <Button onClick={handleSaveImage}>{m.debug_save_image()}</Button>
 
           <img
             src={
@@ -262,7 +271,7 @@ const Windows = () => {
                   )
                 : ""
             }
-            alt="Captured"
+            alt={m.debug_captured_image()}
           />
         </div>
       )}
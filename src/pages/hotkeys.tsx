@@ -0,0 +1,166 @@
+import { useQuery } from "@tanstack/react-query";
+import { createFileRoute } from "@tanstack/react-router";
+import { Loader2, Save } from "lucide-react";
+import { KeyboardEvent, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { commands, type HotkeyAction, type HotkeyBinding } from "@/bindings";
+import { Button } from "@/components/ui/button";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardFooter,
+  CardHeader,
+  CardTitle,
+} from "@/components/ui/card";
+import { Input } from "@/components/ui/input";
+import { HOTKEY_BINDINGS_QUERY_KEY } from "@/hooks/consts";
+import { unwrapResult } from "@/lib/result";
+import { m } from "@/paraglide/messages";
+
+export const Route = createFileRoute("/hotkeys")({
+  component: RouteComponent,
+});
+
+const ACTION_LABELS: Record<HotkeyAction, () => string> = {
+  Optimize: m.hotkeys_action_optimize,
+  Restore: m.hotkeys_action_restore,
+  Screenshot: m.hotkeys_action_screenshot,
+  ToggleWatchdog: m.hotkeys_action_toggle_watchdog,
+  OcrRegion: m.hotkeys_action_ocr_region,
+};
+
+const MODIFIER_KEYS = ["Control", "Alt", "Shift", "Meta"];
+
+// the accelerator format of the global shortcut plugin, e.g. Ctrl+Alt+O
+const toShortcut = (event: KeyboardEvent) => {
+  const modifiers = [
+    event.ctrlKey && "Ctrl",
+    event.altKey && "Alt",
+    event.shiftKey && "Shift",
+    event.metaKey && "Super",
+  ].filter(Boolean);
+
+  const key = event.code.replace(/^(Key|Digit)/, "");
+
+  return [...modifiers, key].join("+");
+};
+
+const BindingRow = ({
+  binding,
+  onChange,
+}: {
+  binding: HotkeyBinding;
+  onChange: (binding: HotkeyBinding) => void;
+}) => {
+  const handleKeyDown = (event: KeyboardEvent<HTMLInputElement>) => {
+    // tab keeps moving the focus
+    if (event.key === "Tab") {
+      return;
+    }
+
+    event.preventDefault();
+
+    // wait for the actual key, a shortcut needs more than modifiers
+    if (MODIFIER_KEYS.includes(event.key)) {
+      return;
+    }
+
+    onChange({ ...binding, shortcut: toShortcut(event) });
+  };
+
+  return (
+    <div className="flex items-center gap-2">
+      <input
+        type="checkbox"
+        className="size-4"
+        title={m.hotkeys_binding_enabled()}
+        checked={binding.enabled}
+        onChange={(event) =>
+          onChange({ ...binding, enabled: event.target.checked })
+        }
+      />
+
+      <span className="w-48 text-sm">{ACTION_LABELS[binding.action]()}</span>
+
+      <Input
+        className="flex-1 font-mono"
+        placeholder={m.hotkeys_shortcut_placeholder()}
+        value={binding.shortcut}
+        onKeyDown={handleKeyDown}
+        readOnly
+      />
+    </div>
+  );
+};
+
+function RouteComponent() {
+  const saved = useQuery({
+    queryKey: [HOTKEY_BINDINGS_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getHotkeyBindings());
+    },
+  });
+
+  // edits stay local until they are saved
+  const [bindings, setBindings] = useState<HotkeyBinding[]>([]);
+
+  useEffect(() => {
+    if (saved.data) {
+      setBindings(saved.data);
+    }
+  }, [saved.data]);
+
+  const updateBinding = (index: number, binding: HotkeyBinding) => {
+    setBindings((bindings) =>
+      bindings.map((b, i) => (i === index ? binding : b)),
+    );
+  };
+
+  const handleSave = async () => {
+    // conflicts and shortcuts owned by other applications are reported here
+    const result = await commands.setHotkeyBindings(bindings);
+
+    if (result.status === "error") {
+      toast.error(m.hotkeys_save_failed(), { description: result.error });
+      return;
+    }
+
+    toast.success(m.hotkeys_saved());
+
+    await saved.refetch();
+  };
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      <Card>
+        <CardHeader>
+          <CardTitle>{m.hotkeys_title()}</CardTitle>
+
+          <CardDescription>{m.hotkeys_description()}</CardDescription>
+        </CardHeader>
+
+        <CardContent className="space-y-2">
+          {saved.isLoading ? (
+            <Loader2 className="size-4 animate-spin" />
+          ) : (
+            bindings.map((binding, index) => (
+              <BindingRow
+                key={binding.action}
+                binding={binding}
+                onChange={(binding) => updateBinding(index, binding)}
+              />
+            ))
+          )}
+        </CardContent>
+
+        <CardFooter>
+          <Button onClick={handleSave}>
+            <Save />
+            <span>{m.hotkeys_save()}</span>
+          </Button>
+        </CardFooter>
+      </Card>
+    </div>
+  );
+}
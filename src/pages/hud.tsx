@@ -0,0 +1,47 @@
+import { useQuery } from "@tanstack/react-query";
+import { createFileRoute } from "@tanstack/react-router";
+import { commands } from "@/bindings";
+import { HUD_STATUS_QUERY_KEY } from "@/hooks/consts";
+import { unwrapResult } from "@/lib/result";
+import { m } from "@/paraglide/messages";
+
+export const Route = createFileRoute("/hud")({
+  component: RouteComponent,
+});
+
+// the CPU usage is measured between two polls
+const HUD_POLL_INTERVAL = 1000;
+
+function RouteComponent() {
+  const status = useQuery({
+    queryKey: [HUD_STATUS_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getHudStatus());
+    },
+    refetchInterval: HUD_POLL_INTERVAL,
+  });
+
+  const cpuUsage = status.data?.cpu_usage;
+
+  return (
+    <div className="flex h-screen cursor-default flex-col justify-center gap-1 px-3 text-sm select-none">
+      <div className="flex justify-between gap-2">
+        <span className="text-muted-foreground">{m.hud_processes()}</span>
+
+        <span className="font-medium">
+          {status.data
+            ? `${status.data.optimized_count} / ${status.data.process_count}`
+            : "-"}
+        </span>
+      </div>
+
+      <div className="flex justify-between gap-2">
+        <span className="text-muted-foreground">{m.hud_cpu_usage()}</span>
+
+        <span className="font-medium">
+          {cpuUsage != null ? `${cpuUsage.toFixed(1)}%` : "-"}
+        </span>
+      </div>
+    </div>
+  );
+}
@@ -1,5 +1,7 @@
 import { createFileRoute } from "@tanstack/react-router";
 import AceProcessController from "@/components/ace-process-controller";
+import CorePicker from "@/components/core-picker";
+import ProcessUsageChart from "@/components/process-usage-chart";
 
 export const Route = createFileRoute("/")({
   component: RouteComponent,
@@ -9,6 +11,10 @@ function RouteComponent() {
   return (
     <div className="flex flex-col gap-4 p-4">
       <AceProcessController />
+
+      <CorePicker />
+
+      <ProcessUsageChart />
     </div>
   );
 }
@@ -0,0 +1,127 @@
+import { createFileRoute } from "@tanstack/react-router";
+import { PointerEvent, useCallback, useEffect, useRef, useState } from "react";
+import { commands, type OcrRegion } from "@/bindings";
+import { useTransparentBody } from "@/hooks/use-transparent-body";
+import { m } from "@/paraglide/messages";
+
+export const Route = createFileRoute("/region-picker")({
+  component: RouteComponent,
+});
+
+// smaller drags are treated as a stray click
+const MIN_REGION_SIZE = 4;
+
+type Point = { x: number; y: number };
+
+const toRect = (start: Point, end: Point) => ({
+  left: Math.min(start.x, end.x),
+  top: Math.min(start.y, end.y),
+  width: Math.abs(end.x - start.x),
+  height: Math.abs(end.y - start.y),
+});
+
+// the backend captures the display in physical pixels
+const toRegion = (rect: ReturnType<typeof toRect>): OcrRegion => {
+  const scale = window.devicePixelRatio;
+
+  return {
+    x: Math.round(rect.left * scale),
+    y: Math.round(rect.top * scale),
+    width: Math.round(rect.width * scale),
+    height: Math.round(rect.height * scale),
+  };
+};
+
+function RouteComponent() {
+  useTransparentBody();
+
+  const [start, setStart] = useState<Point | null>(null);
+
+  const [end, setEnd] = useState<Point | null>(null);
+
+  const submitted = useRef(false);
+
+  const submit = useCallback(async (region: OcrRegion | null) => {
+    if (submitted.current) {
+      return;
+    }
+
+    submitted.current = true;
+
+    const result = await commands.submitScreenRegion(region);
+
+    if (result.status === "error") {
+      console.error(result.error);
+    }
+  }, []);
+
+  useEffect(() => {
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (event.key === "Escape") {
+        submit(null);
+      }
+    };
+
+    window.addEventListener("keydown", handleKeyDown);
+
+    return () => {
+      window.removeEventListener("keydown", handleKeyDown);
+    };
+  }, [submit]);
+
+  const handlePointerDown = (event: PointerEvent<HTMLDivElement>) => {
+    if (event.button !== 0) {
+      submit(null);
+      return;
+    }
+
+    event.currentTarget.setPointerCapture(event.pointerId);
+
+    setStart({ x: event.clientX, y: event.clientY });
+    setEnd({ x: event.clientX, y: event.clientY });
+  };
+
+  const handlePointerMove = (event: PointerEvent<HTMLDivElement>) => {
+    if (start) {
+      setEnd({ x: event.clientX, y: event.clientY });
+    }
+  };
+
+  const handlePointerUp = (event: PointerEvent<HTMLDivElement>) => {
+    if (!start) {
+      return;
+    }
+
+    const rect = toRect(start, { x: event.clientX, y: event.clientY });
+
+    setStart(null);
+    setEnd(null);
+
+    if (rect.width >= MIN_REGION_SIZE && rect.height >= MIN_REGION_SIZE) {
+      submit(toRegion(rect));
+    }
+  };
+
+  const rect = start && end ? toRect(start, end) : null;
+
+  return (
+    <div
+      className="fixed inset-0 cursor-crosshair bg-black/30 select-none"
+      onPointerDown={handlePointerDown}
+      onPointerMove={handlePointerMove}
+      onPointerUp={handlePointerUp}
+      onContextMenu={(event) => event.preventDefault()}
+    >
+      {rect ? (
+        <div
+          className="absolute border-2 border-white bg-white/10"
+          style={rect}
+        />
+      ) : (
+        <div className="absolute top-8 left-1/2 -translate-x-1/2 rounded-md bg-black/70 px-4 py-2 text-sm text-white">
+          {m.region_picker_hint()}
+        </div>
+      )}
+    </div>
+  );
+}
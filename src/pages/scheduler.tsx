@@ -0,0 +1,249 @@
+import { useQuery } from "@tanstack/react-query";
+import { createFileRoute } from "@tanstack/react-router";
+import { Loader2, Plus, Save, Trash2 } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import {
+  commands,
+  type ScheduleAction,
+  type ScheduleRule,
+  type ScheduleTrigger,
+} from "@/bindings";
+import { Button } from "@/components/ui/button";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardFooter,
+  CardHeader,
+  CardTitle,
+} from "@/components/ui/card";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SCHEDULE_RULES_QUERY_KEY } from "@/hooks/consts";
+import { unwrapResult } from "@/lib/result";
+import { m } from "@/paraglide/messages";
+
+export const Route = createFileRoute("/scheduler")({
+  component: RouteComponent,
+});
+
+type TriggerKind = "Logon" | "GameStart" | "GameExit";
+
+const triggerKind = (trigger: ScheduleTrigger): TriggerKind =>
+  trigger === "Logon"
+    ? "Logon"
+    : "GameStart" in trigger
+      ? "GameStart"
+      : "GameExit";
+
+const triggerGame = (trigger: ScheduleTrigger) =>
+  trigger === "Logon"
+    ? ""
+    : "GameStart" in trigger
+      ? trigger.GameStart
+      : trigger.GameExit;
+
+const toTrigger = (kind: TriggerKind, game: string): ScheduleTrigger => {
+  switch (kind) {
+    case "Logon":
+      return "Logon";
+    case "GameStart":
+      return { GameStart: game };
+    case "GameExit":
+      return { GameExit: game };
+  }
+};
+
+const TRIGGER_LABELS: Record<TriggerKind, () => string> = {
+  Logon: m.scheduler_trigger_logon,
+  GameStart: m.scheduler_trigger_game_start,
+  GameExit: m.scheduler_trigger_game_exit,
+};
+
+const ACTION_LABELS: Record<ScheduleAction, () => string> = {
+  Optimize: m.scheduler_action_optimize,
+  Revert: m.scheduler_action_revert,
+};
+
+const NEW_RULE: ScheduleRule = {
+  trigger: { GameStart: "DeltaForceClient-Win64-Shipping.exe" },
+  action: "Optimize",
+  enabled: true,
+};
+
+const RuleRow = ({
+  rule,
+  onChange,
+  onRemove,
+}: {
+  rule: ScheduleRule;
+  onChange: (rule: ScheduleRule) => void;
+  onRemove: () => void;
+}) => {
+  const kind = triggerKind(rule.trigger);
+
+  const game = triggerGame(rule.trigger);
+
+  return (
+    <div className="flex items-center gap-2">
+      <input
+        type="checkbox"
+        className="size-4"
+        title={m.scheduler_rule_enabled()}
+        checked={rule.enabled}
+        onChange={(event) =>
+          onChange({ ...rule, enabled: event.target.checked })
+        }
+      />
+
+      <Select
+        value={kind}
+        onValueChange={(value) =>
+          onChange({ ...rule, trigger: toTrigger(value as TriggerKind, game) })
+        }
+      >
+        <SelectTrigger className="w-40">
+          <SelectValue />
+        </SelectTrigger>
+
+        <SelectContent>
+          {Object.entries(TRIGGER_LABELS).map(([value, label]) => (
+            <SelectItem key={value} value={value}>
+              {label()}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+
+      <Input
+        className="flex-1"
+        placeholder={m.scheduler_game_placeholder()}
+        disabled={kind === "Logon"}
+        value={game}
+        onChange={(event) =>
+          onChange({ ...rule, trigger: toTrigger(kind, event.target.value) })
+        }
+      />
+
+      <Select
+        value={rule.action}
+        onValueChange={(value) =>
+          onChange({ ...rule, action: value as ScheduleAction })
+        }
+      >
+        <SelectTrigger className="w-32">
+          <SelectValue />
+        </SelectTrigger>
+
+        <SelectContent>
+          {Object.entries(ACTION_LABELS).map(([value, label]) => (
+            <SelectItem key={value} value={value}>
+              {label()}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+
+      <Button
+        size="icon"
+        variant="ghost"
+        title={m.scheduler_remove_rule()}
+        onClick={onRemove}
+      >
+        <Trash2 />
+      </Button>
+    </div>
+  );
+};
+
+function RouteComponent() {
+  const saved = useQuery({
+    queryKey: [SCHEDULE_RULES_QUERY_KEY],
+    queryFn: async () => {
+      return unwrapResult(await commands.getScheduleRules());
+    },
+  });
+
+  // edits stay local until they are saved
+  const [rules, setRules] = useState<ScheduleRule[]>([]);
+
+  useEffect(() => {
+    if (saved.data) {
+      setRules(saved.data);
+    }
+  }, [saved.data]);
+
+  const updateRule = (index: number, rule: ScheduleRule) => {
+    setRules((rules) => rules.map((r, i) => (i === index ? rule : r)));
+  };
+
+  const removeRule = (index: number) => {
+    setRules((rules) => rules.filter((_, i) => i !== index));
+  };
+
+  const handleSave = async () => {
+    const result = await commands.setScheduleRules(rules);
+
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+
+    toast.success(m.scheduler_saved());
+
+    await saved.refetch();
+  };
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      <Card>
+        <CardHeader>
+          <CardTitle>{m.scheduler_title()}</CardTitle>
+
+          <CardDescription>{m.scheduler_description()}</CardDescription>
+        </CardHeader>
+
+        <CardContent className="space-y-2">
+          {saved.isLoading ? (
+            <Loader2 className="size-4 animate-spin" />
+          ) : rules.length > 0 ? (
+            rules.map((rule, index) => (
+              <RuleRow
+                key={index}
+                rule={rule}
+                onChange={(rule) => updateRule(index, rule)}
+                onRemove={() => removeRule(index)}
+              />
+            ))
+          ) : (
+            <div className="text-muted-foreground text-sm">
+              {m.scheduler_no_rules()}
+            </div>
+          )}
+        </CardContent>
+
+        <CardFooter className="gap-2">
+          <Button
+            variant="outline"
+            onClick={() => setRules((rules) => [...rules, NEW_RULE])}
+          >
+            <Plus />
+            <span>{m.scheduler_add_rule()}</span>
+          </Button>
+
+          <Button onClick={handleSave}>
+            <Save />
+            <span>{m.scheduler_save()}</span>
+          </Button>
+        </CardFooter>
+      </Card>
+    </div>
+  );
+}
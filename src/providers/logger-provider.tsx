@@ -1,20 +1,51 @@
-import { useSet } from "@uidotdev/usehooks";
 import {
   createContext,
   useContext,
   useEffect,
+  useMemo,
+  useRef,
   useState,
   type PropsWithChildren,
 } from "react";
-import { events, type LogEvent } from "@/bindings";
+import { commands, events, type LogEvent, type LogLevel } from "@/bindings";
+
+export const FILTERABLE_LOG_LEVELS = [
+  "ERROR",
+  "WARN",
+  "INFO",
+  "DEBUG",
+] as const;
+
+// used until the configured capacity of the backend log buffer is read
+export const DEFAULT_LOG_ENTRIES = 1000;
+
+export type FilterableLogLevel = (typeof FILTERABLE_LOG_LEVELS)[number];
 
 type LoggerContextType = {
   logs: LogEvent[];
   length: number;
   openLoggerViewer: boolean;
   setOpenLoggerViewer: (open: boolean) => void;
+  enabledLevels: FilterableLogLevel[];
+  toggleLevel: (level: FilterableLogLevel) => void;
+  isLevelEnabled: (level: LogLevel) => boolean;
+  search: string;
+  setSearch: (search: string) => void;
+  searchRegex: boolean;
+  setSearchRegex: (regex: boolean) => void;
+  searchMatcher: RegExp | null;
+  isSearchValid: boolean;
+  matchesSearch: (log: LogEvent) => boolean;
+  visibleLogs: LogEvent[];
+  selectedLogs: LogEvent[];
+  isSelected: (log: LogEvent) => boolean;
+  toggleSelected: (log: LogEvent) => void;
+  clearSelection: () => void;
 };
 
+const escapeRegExp = (value: string) =>
+  value.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+
 const LoggerContext = createContext<LoggerContextType | null>(null);
 
 export const useLogger = () => {
@@ -28,13 +59,103 @@ export const useLogger = () => {
 };
 
 export default function LoggerProvider({ children }: PropsWithChildren) {
-  const logs = useSet<LogEvent>();
+  const [logs, setLogs] = useState<LogEvent[]>([]);
 
   const [openLoggerViewer, setOpenLoggerViewer] = useState(false);
 
+  const [enabledLevels, setEnabledLevels] = useState<FilterableLogLevel[]>([
+    ...FILTERABLE_LOG_LEVELS,
+  ]);
+
+  const toggleLevel = (level: FilterableLogLevel) => {
+    setEnabledLevels((levels) =>
+      levels.includes(level)
+        ? levels.filter((l) => l !== level)
+        : [...levels, level],
+    );
+  };
+
+  // trace output is only useful together with debug output
+  const isLevelEnabled = (level: LogLevel) =>
+    enabledLevels.includes(level === "TRACE" ? "DEBUG" : level);
+
+  const [search, setSearch] = useState("");
+
+  const [searchRegex, setSearchRegex] = useState(false);
+
+  // an invalid regular expression filters nothing until it is fixed
+  const searchMatcher = useMemo(() => {
+    if (!search) {
+      return null;
+    }
+
+    try {
+      return new RegExp(searchRegex ? search : escapeRegExp(search), "i");
+    } catch {
+      return null;
+    }
+  }, [search, searchRegex]);
+
+  const isSearchValid = !search || searchMatcher !== null;
+
+  const matchesSearch = (log: LogEvent) =>
+    !searchMatcher ||
+    searchMatcher.test(log.message) ||
+    searchMatcher.test(log.target) ||
+    Object.values(log.fields).some((value) => searchMatcher.test(value ?? ""));
+
+  // filters are applied at render time, the buffer keeps every entry
+  const visibleLogs = logs.filter(
+    (log) => isLevelEnabled(log.level) && matchesSearch(log),
+  );
+
+  const [selection, setSelection] = useState<LogEvent[]>([]);
+
+  // keep the buffer order and forget entries that were dropped from the buffer
+  const selectedLogs = logs.filter((log) => selection.includes(log));
+
+  const isSelected = (log: LogEvent) => selection.includes(log);
+
+  const toggleSelected = (log: LogEvent) => {
+    setSelection((selection) =>
+      selection.includes(log)
+        ? selection.filter((l) => l !== log)
+        : [...selection, log],
+    );
+  };
+
+  const clearSelection = () => setSelection([]);
+
+  // keep as many entries as the backend buffer, read by the event listener
+  const maxEntries = useRef(DEFAULT_LOG_ENTRIES);
+
   useEffect(() => {
+    commands.getSettings().then((result) => {
+      if (result.status === "error") {
+        return;
+      }
+
+      maxEntries.current = result.data.log_buffer_capacity;
+
+      setLogs((logs) => logs.slice(-maxEntries.current));
+    });
+
+    // events logged before the window was ready only exist in the backend buffer
+    commands.getLogHistory(null, null).then((history) => {
+      setLogs((logs) => {
+        // live events that arrived first are part of the history as well
+        const since = logs.length ? Date.parse(logs[0].timestamp) : Infinity;
+
+        return [
+          ...history.filter((log) => Date.parse(log.timestamp) < since),
+          ...logs,
+        ].slice(-maxEntries.current);
+      });
+    });
+
     const unlisten = events.logEvent.listen(({ payload }) => {
-      logs.add(payload);
+      // drop the oldest entries so long sessions stay cheap to render
+      setLogs((logs) => [...logs, payload].slice(-maxEntries.current));
     });
 
     return () => {
@@ -47,10 +168,25 @@ export default function LoggerProvider({ children }: PropsWithChildren) {
   return (
     <LoggerContext.Provider
       value={{
-        logs: Array.from(logs),
-        length: logs.size,
+        logs,
+        length: logs.length,
         openLoggerViewer,
         setOpenLoggerViewer,
+        enabledLevels,
+        toggleLevel,
+        isLevelEnabled,
+        search,
+        setSearch,
+        searchRegex,
+        setSearchRegex,
+        searchMatcher,
+        isSearchValid,
+        matchesSearch,
+        visibleLogs,
+        selectedLogs,
+        isSelected,
+        toggleSelected,
+        clearSelection,
       }}
     >
       {children}
@@ -9,14 +9,44 @@
 // Additionally, you should also exclude this file from your linter and/or formatter to prevent it from being checked or modified.
 
 import { Route as rootRouteImport } from './pages/__root'
+import { Route as SchedulerRouteImport } from './pages/scheduler'
+import { Route as RegionPickerRouteImport } from './pages/region-picker'
+import { Route as HudRouteImport } from './pages/hud'
+import { Route as HotkeysRouteImport } from './pages/hotkeys'
 import { Route as DebugRouteImport } from './pages/debug'
+import { Route as AboutRouteImport } from './pages/about'
 import { Route as IndexRouteImport } from './pages/index'
 
+const SchedulerRoute = SchedulerRouteImport.update({
+  id: '/scheduler',
+  path: '/scheduler',
+  getParentRoute: () => rootRouteImport,
+} as any)
+const RegionPickerRoute = RegionPickerRouteImport.update({
+  id: '/region-picker',
+  path: '/region-picker',
+  getParentRoute: () => rootRouteImport,
+} as any)
+const HudRoute = HudRouteImport.update({
+  id: '/hud',
+  path: '/hud',
+  getParentRoute: () => rootRouteImport,
+} as any)
+const HotkeysRoute = HotkeysRouteImport.update({
+  id: '/hotkeys',
+  path: '/hotkeys',
+  getParentRoute: () => rootRouteImport,
+} as any)
 const DebugRoute = DebugRouteImport.update({
   id: '/debug',
   path: '/debug',
   getParentRoute: () => rootRouteImport,
 } as any)
+const AboutRoute = AboutRouteImport.update({
+  id: '/about',
+  path: '/about',
+  getParentRoute: () => rootRouteImport,
+} as any)
 const IndexRoute = IndexRouteImport.update({
   id: '/',
   path: '/',
@@ -25,32 +55,80 @@ const IndexRoute = IndexRouteImport.update({
 
 export interface FileRoutesByFullPath {
   '/': typeof IndexRoute
+  '/about': typeof AboutRoute
   '/debug': typeof DebugRoute
+  '/hotkeys': typeof HotkeysRoute
+  '/hud': typeof HudRoute
+  '/region-picker': typeof RegionPickerRoute
+  '/scheduler': typeof SchedulerRoute
 }
 export interface FileRoutesByTo {
   '/': typeof IndexRoute
+  '/about': typeof AboutRoute
   '/debug': typeof DebugRoute
+  '/hotkeys': typeof HotkeysRoute
+  '/hud': typeof HudRoute
+  '/region-picker': typeof RegionPickerRoute
+  '/scheduler': typeof SchedulerRoute
 }
 export interface FileRoutesById {
   __root__: typeof rootRouteImport
   '/': typeof IndexRoute
+  '/about': typeof AboutRoute
   '/debug': typeof DebugRoute
+  '/hotkeys': typeof HotkeysRoute
+  '/hud': typeof HudRoute
+  '/region-picker': typeof RegionPickerRoute
+  '/scheduler': typeof SchedulerRoute
 }
 export interface FileRouteTypes {
   fileRoutesByFullPath: FileRoutesByFullPath
-  fullPaths: '/' | '/debug'
+  fullPaths: '/' | '/about' | '/debug' | '/hotkeys' | '/hud' | '/region-picker' | '/scheduler'
   fileRoutesByTo: FileRoutesByTo
-  to: '/' | '/debug'
-  id: '__root__' | '/' | '/debug'
+  to: '/' | '/about' | '/debug' | '/hotkeys' | '/hud' | '/region-picker' | '/scheduler'
+  id: '__root__' | '/' | '/about' | '/debug' | '/hotkeys' | '/hud' | '/region-picker' | '/scheduler'
   fileRoutesById: FileRoutesById
 }
 export interface RootRouteChildren {
   IndexRoute: typeof IndexRoute
+  AboutRoute: typeof AboutRoute
   DebugRoute: typeof DebugRoute
+  HotkeysRoute: typeof HotkeysRoute
+  HudRoute: typeof HudRoute
+  RegionPickerRoute: typeof RegionPickerRoute
+  SchedulerRoute: typeof SchedulerRoute
 }
 
 declare module '@tanstack/react-router' {
   interface FileRoutesByPath {
+    '/scheduler': {
+      id: '/scheduler'
+      path: '/scheduler'
+      fullPath: '/scheduler'
+      preLoaderRoute: typeof SchedulerRouteImport
+      parentRoute: typeof rootRouteImport
+    }
+    '/region-picker': {
+      id: '/region-picker'
+      path: '/region-picker'
+      fullPath: '/region-picker'
+      preLoaderRoute: typeof RegionPickerRouteImport
+      parentRoute: typeof rootRouteImport
+    }
+    '/hud': {
+      id: '/hud'
+      path: '/hud'
+      fullPath: '/hud'
+      preLoaderRoute: typeof HudRouteImport
+      parentRoute: typeof rootRouteImport
+    }
+    '/hotkeys': {
+      id: '/hotkeys'
+      path: '/hotkeys'
+      fullPath: '/hotkeys'
+      preLoaderRoute: typeof HotkeysRouteImport
+      parentRoute: typeof rootRouteImport
+    }
     '/debug': {
       id: '/debug'
       path: '/debug'
@@ -58,6 +136,13 @@ declare module '@tanstack/react-router' {
       preLoaderRoute: typeof DebugRouteImport
       parentRoute: typeof rootRouteImport
     }
+    '/about': {
+      id: '/about'
+      path: '/about'
+      fullPath: '/about'
+      preLoaderRoute: typeof AboutRouteImport
+      parentRoute: typeof rootRouteImport
+    }
     '/': {
       id: '/'
       path: '/'
@@ -70,7 +155,12 @@ declare module '@tanstack/react-router' {
 
 const rootRouteChildren: RootRouteChildren = {
   IndexRoute: IndexRoute,
+  AboutRoute: AboutRoute,
   DebugRoute: DebugRoute,
+  HotkeysRoute: HotkeysRoute,
+  HudRoute: HudRoute,
+  RegionPickerRoute: RegionPickerRoute,
+  SchedulerRoute: SchedulerRoute,
 }
 export const routeTree = rootRouteImport
   ._addFileChildren(rootRouteChildren)